@@ -2,7 +2,7 @@ use indexmap::IndexMap;
 use log::{debug, trace};
 use std::{fs, path::Path, str::FromStr};
 use crate::conf::{
-    conf_keywd::{ConfKeywd, ConfKind}, conf_tree::ConfTree, service_config::ServiceConfig
+    conf_keywd::{ConfKeywd, ConfKind}, conf_template::{ConfTemplate, ConfTemplates}, conf_tree::ConfTree, service_config::ServiceConfig
 };
 ///
 /// creates config from serde_yaml::Value of following format:
@@ -10,6 +10,11 @@ use crate::conf::{
 /// name: ApplicationName
 /// description: Short explanation / purpose etc.
 /// 
+/// template FaultDetection(input, limit):   # reusable subtree, instantiated in the Task by 'fault1 template FaultDetection:'
+///     fn ge:
+///         input1: $input
+///         input2: $limit
+/// 
 /// service ProfinetClient Ied01:          # device will be executed in the independent thread, must have unique name
 ///    in queue in-queue:
 ///        max-length: 10000
@@ -39,6 +44,7 @@ use crate::conf::{
 ///         input: const real 2.224
 ///     
 ///     fn ToMultiQueue:
+///         queue: /App/MultiQueue.in-queue
 ///         in1 point CraneMovement.BoomUp: 
 ///             type: 'Int'
 ///             comment: 'Some indication'
@@ -70,14 +76,21 @@ impl AppConfig {
         debug!("{}.new | name: {:?}", self_id, self_name);
         let description = self_conf.get_param_value("description").unwrap().as_str().unwrap().to_owned();
         debug!("{}.new | description: {:?}", self_id, description);
+        let mut templates = ConfTemplates::new();
+        for key in self_conf.keys.iter().filter(|key| ConfTemplate::is_definition(key)) {
+            let template_conf = self_conf.get(key).unwrap();
+            debug!("{}.new | template '{}'", self_id, key);
+            templates.insert(ConfTemplate::new(key, &template_conf.conf));
+        }
         let mut nodes = IndexMap::new();
         println!();
-        for key in &self_conf.keys {
+        for key in self_conf.keys.iter().filter(|key| !ConfTemplate::is_definition(key)) {
             let keyword = ConfKeywd::from_str(key).unwrap();
             match keyword.kind() {
                 ConfKind::Service | ConfKind::Task => {
                     let node_name = keyword.name();
-                    let node_conf = self_conf.get(key).unwrap();
+                    let mut node_conf = self_conf.get(key).unwrap();
+                    node_conf.conf = templates.expand(&self_id, &node_conf.conf);
                     if log::max_level() == log::LevelFilter::Debug {
                         let sufix = match keyword.sufix().is_empty() {
                             true => "".to_owned(),
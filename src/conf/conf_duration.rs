@@ -79,8 +79,8 @@ impl ConfDuration {
             ConfDurationUnit::Micros => Duration::from_micros(self.value),
             ConfDurationUnit::Millis => Duration::from_millis(self.value),
             ConfDurationUnit::Secs => Duration::from_secs(self.value),
-            ConfDurationUnit::Mins => Duration::from_secs(self.value),
-            ConfDurationUnit::Hours => Duration::from_secs(self.value),
+            ConfDurationUnit::Mins => Duration::from_secs(self.value * 60),
+            ConfDurationUnit::Hours => Duration::from_secs(self.value * 3600),
         }
     }
 }
@@ -0,0 +1,308 @@
+use indexmap::IndexMap;
+use log::{debug, trace};
+use regex::RegexBuilder;
+///
+/// The maximum depth of the nested templates,
+/// protects from the recursive template definitions
+const MAX_DEPTH: usize = 16;
+///
+/// Reusable subtree of the function configuration, defined in the root of the application config:
+/// ```yaml
+/// template FaultDetection(input, limit, alarm):
+///     fn ToMultiQueue:
+///         queue: /App/MultiQueue.in-queue
+///         in1 point $alarm:
+///             type: 'Bool'
+///             alarm: 1
+///             input fn latchSet:
+///                 set fn ge:
+///                     input1: $input
+///                     input2: $limit
+///                 reset: const bool false
+/// ```
+/// - the parameters are referred in the body as `$name`, in the keys & in the values
+/// - the body must have the single root node
+///
+/// Instantiated in the Task config with the concrete point names, constants or functions:
+/// ```yaml
+/// service Task Task1:
+///     fault1 template FaultDetection:
+///         input: point real '/App/Load'
+///         limit fn add:
+///             input1: point real '/App/Load.Nominal'
+///             input2: const real 0.5
+///         alarm: Load.Overload
+/// ```
+/// - the scalar argument substituted as is, the value exactly equal to `$name` keeps the type of the argument
+/// - the function argument (defined in the key) replaces the whole input, referring it by the `input: $name`
+/// - the instance expanded into the subtree before the Task config parsed,
+/// so the template can be used anywhere the function can be used
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConfTemplate {
+    name: String,
+    params: Vec<String>,
+    key: String,
+    body: serde_yaml::Value,
+}
+///
+///
+impl ConfTemplate {
+    ///
+    /// Creates new instance from the definition 'template Name(param1, param2, ...)'
+    pub fn new(key: &str, conf: &serde_yaml::Value) -> Self {
+        let self_id = format!("ConfTemplate({})", key);
+        let re = r#"^template[ \t]+(\w+)[ \t]*(?:\(([\w, \t]*)\))?$"#;
+        let re = RegexBuilder::new(re).build().unwrap();
+        let (name, params) = match re.captures(key.trim()) {
+            Some(caps) => {
+                let name = caps.get(1).unwrap().as_str().to_owned();
+                let params: Vec<String> = caps.get(2).map_or(vec![], |params| {
+                    params.as_str().split(',').map(|param| param.trim().to_owned()).filter(|param| !param.is_empty()).collect()
+                });
+                (name, params)
+            }
+            None => panic!("{}.new | Invalid template definition, 'template Name(param1, param2, ...)' expected", self_id),
+        };
+        let (key, body) = match conf.as_mapping() {
+            Some(mapping) if mapping.len() == 1 => {
+                let (key, body) = mapping.iter().next().unwrap();
+                match key.as_str() {
+                    Some(key) => (key.to_owned(), body.clone()),
+                    None => panic!("{}.new | Invalid root node of the template: {:?}", self_id, key),
+                }
+            }
+            _ => panic!("{}.new | Template must have the single root node, but found: {:?}", self_id, conf),
+        };
+        debug!("{}.new | name: {:?}, params: {:?}", self_id, name, params);
+        Self { name, params, key, body }
+    }
+    ///
+    /// Returns the name of the template
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    ///
+    /// Returns true if the key is the definition of the template
+    pub fn is_definition(key: &str) -> bool {
+        key.trim().starts_with("template ")
+    }
+    ///
+    /// Returns the root key & the body of the template with parameters substituted by the [args]
+    fn instantiate(&self, self_id: &str, args: &serde_yaml::Value) -> (String, serde_yaml::Value) {
+        let args = Args::new(self_id, &self.name, args);
+        for name in args.names() {
+            if !self.params.contains(name) {
+                panic!("{}.instantiate | Unknown argument '{}' of the template '{}', expected: {:?}", self_id, name, self.name, self.params);
+            }
+        }
+        for param in &self.params {
+            if !args.contains(param) {
+                panic!("{}.instantiate | Missing argument '{}' of the template '{}'", self_id, param, self.name);
+            }
+        }
+        let key = args.substitute_str(self_id, &self.key);
+        let body = args.substitute(self_id, &self.body);
+        trace!("{}.instantiate | '{}': {:?}", self_id, key, body);
+        (key, body)
+    }
+}
+///
+/// Holds all templates defined in the application config
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ConfTemplates {
+    templates: IndexMap<String, ConfTemplate>,
+}
+///
+///
+impl ConfTemplates {
+    ///
+    /// Creates new empty instance
+    pub fn new() -> Self {
+        Self { templates: IndexMap::new() }
+    }
+    ///
+    /// Adds the template definition
+    pub fn insert(&mut self, template: ConfTemplate) {
+        if self.templates.contains_key(template.name()) {
+            panic!("ConfTemplates.insert | Duplicated template name '{}'", template.name());
+        }
+        self.templates.insert(template.name().to_owned(), template);
+    }
+    ///
+    /// Returns the config with all template instances expanded
+    pub fn expand(&self, parent: &str, conf: &serde_yaml::Value) -> serde_yaml::Value {
+        let self_id = format!("{}/ConfTemplates", parent);
+        self.expand_value(&self_id, conf, 0)
+    }
+    ///
+    ///
+    fn expand_value(&self, self_id: &str, conf: &serde_yaml::Value, depth: usize) -> serde_yaml::Value {
+        match conf.as_mapping() {
+            Some(mapping) => {
+                let mut expanded = serde_yaml::Mapping::new();
+                for (key, value) in mapping {
+                    let (key, value) = match key.as_str() {
+                        Some(key) => {
+                            let (key, value) = self.expand_entry(self_id, key, value, depth);
+                            (serde_yaml::Value::String(key), value)
+                        }
+                        None => (key.clone(), self.expand_value(self_id, value, depth)),
+                    };
+                    if expanded.contains_key(&key) {
+                        panic!("{}.expand | Duplicated key {:?}, use unique input name for each template instance", self_id, key);
+                    }
+                    expanded.insert(key, value);
+                }
+                serde_yaml::Value::Mapping(expanded)
+            }
+            None => conf.clone(),
+        }
+    }
+    ///
+    /// Returns the key & the value of the mapping entry, expanded if it is the template instance
+    fn expand_entry(&self, self_id: &str, key: &str, value: &serde_yaml::Value, depth: usize) -> (String, serde_yaml::Value) {
+        match self.instance(key) {
+            Some((input, template)) => {
+                if depth >= MAX_DEPTH {
+                    panic!("{}.expand | Max depth {} of the nested templates exceeded in '{}', possible recursive definition", self_id, MAX_DEPTH, template.name());
+                }
+                let (template_key, body) = template.instantiate(self_id, value);
+                let key = match input {
+                    Some(input) => format!("{} {}", input, template_key),
+                    None => template_key,
+                };
+                debug!("{}.expand | template '{}' expanded into '{}'", self_id, template.name(), key);
+                self.expand_entry(self_id, &key, &body, depth + 1)
+            }
+            None => (key.to_owned(), self.expand_value(self_id, value, depth)),
+        }
+    }
+    ///
+    /// Returns the input name & the template, if the key is the template instance '[input] template Name'
+    fn instance(&self, key: &str) -> Option<(Option<String>, &ConfTemplate)> {
+        let re = r#"^(?:(\w+)[ \t]+)?template[ \t]+(\w+)$"#;
+        let re = RegexBuilder::new(re).build().unwrap();
+        re.captures(key.trim()).map(|caps| {
+            let input = caps.get(1).map(|input| input.as_str().to_owned());
+            let name = caps.get(2).unwrap().as_str();
+            match self.templates.get(name) {
+                Some(template) => (input, template),
+                None => panic!("ConfTemplates.instance | Template '{}' - not defined", name),
+            }
+        })
+    }
+}
+///
+/// The arguments of the template instance
+/// - scalar - 'name: value'
+/// - subtree - 'name fn functionName: ...', the keyword & the subtree stored
+struct Args {
+    scalars: IndexMap<String, serde_yaml::Value>,
+    subtrees: IndexMap<String, (String, serde_yaml::Value)>,
+}
+///
+///
+impl Args {
+    ///
+    ///
+    fn new(self_id: &str, template: &str, args: &serde_yaml::Value) -> Self {
+        let mut scalars = IndexMap::new();
+        let mut subtrees = IndexMap::new();
+        match args {
+            serde_yaml::Value::Mapping(args) => {
+                for (key, value) in args {
+                    let key = key.as_str().unwrap_or_else(|| {
+                        panic!("{}.instantiate | Invalid argument {:?} of the template '{}'", self_id, key, template);
+                    });
+                    match key.trim().split_once(char::is_whitespace) {
+                        Some((name, keyword)) => {
+                            subtrees.insert(name.to_owned(), (keyword.trim().to_owned(), value.clone()));
+                        }
+                        None => {
+                            if value.is_mapping() {
+                                panic!("{}.instantiate | Argument '{}' of the template '{}' must be scalar, or defined as 'name fn ...'", self_id, key, template);
+                            }
+                            scalars.insert(key.to_owned(), value.clone());
+                        }
+                    }
+                }
+            }
+            serde_yaml::Value::Null => {}
+            _ => panic!("{}.instantiate | Arguments of the template '{}' must be mapping, but found: {:?}", self_id, template, args),
+        }
+        Self { scalars, subtrees }
+    }
+    ///
+    ///
+    fn names(&self) -> impl Iterator<Item = &String> {
+        self.scalars.keys().chain(self.subtrees.keys())
+    }
+    ///
+    ///
+    fn contains(&self, name: &str) -> bool {
+        self.scalars.contains_key(name) || self.subtrees.contains_key(name)
+    }
+    ///
+    /// Returns the string with all '$name' replaced by the scalar arguments,
+    /// the longest names replaced first, so '$input1' isn't broken by '$input'
+    fn substitute_str(&self, self_id: &str, value: &str) -> String {
+        let mut names: Vec<&String> = self.names().collect();
+        names.sort_by_key(|name| std::cmp::Reverse(name.len()));
+        let mut result = value.to_owned();
+        for name in names {
+            let placeholder = format!("${}", name);
+            if result.contains(&placeholder) {
+                let arg = match self.scalars.get(name) {
+                    Some(arg) => Self::scalar_str(self_id, name, arg),
+                    None => panic!("{}.instantiate | Function argument '{}' can be used only as the whole input 'input: ${}', but found in: {:?}", self_id, name, name, value),
+                };
+                result = result.replace(&placeholder, &arg);
+            }
+        }
+        result
+    }
+    ///
+    /// Returns the body with all parameters substituted
+    fn substitute(&self, self_id: &str, body: &serde_yaml::Value) -> serde_yaml::Value {
+        match body {
+            serde_yaml::Value::Mapping(mapping) => {
+                let mut result = serde_yaml::Mapping::new();
+                for (key, value) in mapping {
+                    let key = match key.as_str() {
+                        Some(key) => self.substitute_str(self_id, key),
+                        None => panic!("{}.instantiate | Invalid key {:?}", self_id, key),
+                    };
+                    match value.as_str().and_then(|value| value.trim().strip_prefix('$')).and_then(|name| self.subtrees.get(name)) {
+                        Some((keyword, subtree)) => {
+                            result.insert(serde_yaml::Value::String(format!("{} {}", key, keyword)), subtree.clone());
+                        }
+                        None => {
+                            result.insert(serde_yaml::Value::String(key), self.substitute(self_id, value));
+                        }
+                    }
+                }
+                serde_yaml::Value::Mapping(result)
+            }
+            serde_yaml::Value::Sequence(sequence) => {
+                serde_yaml::Value::Sequence(sequence.iter().map(|value| self.substitute(self_id, value)).collect())
+            }
+            serde_yaml::Value::String(value) => {
+                match value.trim().strip_prefix('$').and_then(|name| self.scalars.get(name)) {
+                    Some(arg) => arg.clone(),
+                    None => serde_yaml::Value::String(self.substitute_str(self_id, value)),
+                }
+            }
+            _ => body.clone(),
+        }
+    }
+    ///
+    ///
+    fn scalar_str(self_id: &str, name: &str, arg: &serde_yaml::Value) -> String {
+        match arg {
+            serde_yaml::Value::String(arg) => arg.clone(),
+            serde_yaml::Value::Bool(arg) => arg.to_string(),
+            serde_yaml::Value::Number(arg) => arg.to_string(),
+            _ => panic!("{}.instantiate | Argument '{}' can't be substituted into the string: {:?}", self_id, name, arg),
+        }
+    }
+}
@@ -15,6 +15,11 @@ use crate::conf::{
 // }
 
 
+///
+/// The function parameters, declaring the task variable, 
+/// which can be used by the following functions as an ordinary variable:
+///  - elapsed - the elapsed time of the IEC 61131 timers (ton, tof, tp)
+const VAR_PARAMS: [&str; 1] = ["elapsed"];
 ///
 /// creates config read from yaml file of following format:
 /// ```yaml
@@ -88,10 +93,16 @@ impl FnConfig {
                             )        
                         }
                         FnConfKeywd::Fn(value) => {
+                            let inputs = Self::build_inputs(parent_id, parent_name, conf_tree, vars);
+                            for param in VAR_PARAMS {
+                                if let Some(FnConfKind::Param(var_name)) = inputs.get(param) {
+                                    vars.push(var_name.clone());
+                                }
+                            }
                             FnConfKind::Fn(
                                 FnConfig {
                                     name: value.data,
-                                    inputs: Self::build_inputs(parent_id, parent_name, conf_tree, vars),
+                                    inputs,
                                     type_: value.type_,
                                 }
                             )
@@ -113,7 +124,7 @@ impl FnConfig {
                             };
                             FnConfKind::PointConf(
                                 FnPointConfig {
-                                    conf: PointConfig::new(parent_name, conf_tree),
+                                    conf: PointConfig::new(parent_name, conf_tree).reject_limits("FnConfig"),
                                     input: Box::new(FnConfig::new(parent_id, parent_name, &input_conf, vars)),
                                 }
                             )
@@ -194,6 +205,13 @@ impl FnConfig {
                 debug!("FnConfig.new | Custom parameter declared: {:?}", conf_tree.conf);
                 let var_name = conf_tree.conf.as_f64().unwrap().to_string();
                 FnConfKind::Param(var_name)
+            } else if conf_tree.conf.is_sequence() {
+                // the sequence stored as JSON string, the function parses it by itself
+                debug!("FnConfig.new | Custom parameter declared: {:?}", conf_tree.conf);
+                let var_name = serde_json::to_string(&conf_tree.conf).unwrap_or_else(|err| {
+                    panic!("FnConfig.new | Custom parameter of sequence type can't be serialized: {:?}, error: {:?}", conf_tree.conf, err);
+                });
+                FnConfKind::Param(var_name)
             } else {
                 panic!("FnConfig.new | Custom parameter of unknown type declared, but : {:?}", conf_tree.conf);
             }
@@ -295,6 +313,11 @@ impl FnConfig {
         }
     }
     ///
+    /// returns optional custom parameter by it's name, None if not configured
+    pub fn param_opt(&self, name: &str) -> Option<&FnConfKind> {
+        self.inputs.get(name)
+    }
+    ///
     /// 
     fn get_param_by_keyword(conf: &ConfTree, input: &str, kind: u8) -> Result<ConfTree, String> {
         debug!("FnConfig.getParamByKeyword | conf: {:?}", conf);
@@ -4,10 +4,14 @@
 ///
 pub mod conf_tree;
 
+pub mod conf_template;
+
 pub mod fn_;
 
 pub mod task_config;
 
+pub mod task_mode;
+
 pub mod conf_duration;
 
 pub mod api_client_config;
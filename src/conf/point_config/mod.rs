@@ -8,4 +8,6 @@ pub mod point_config_filters;
 
 pub mod point_config_history;
 
-pub mod name;
\ No newline at end of file
+pub mod name;
+
+pub mod point_config_limits;
\ No newline at end of file
@@ -6,6 +6,7 @@ use crate::conf::{
         point_config_address::PointConfigAddress, 
         point_config_filters::PointConfigFilter, 
         point_config_type::PointConfigType, 
+        point_config_limits::PointConfigLimits,
         name::Name,
     }
 };
@@ -25,6 +26,10 @@ use super::point_config_history::PointConfigHistory;
 ///     - >0 - point contains alarm information of the corresponding alarm class
 ///  - address - protocol specific addres
 ///  - filters - threshold filters
+///  - limits - alarm limits hihi / hi / lo / lolo, see [PointConfigLimits],
+///     the Bool alarm point having the same alarm class is generated for the each configured limit,
+///     evaluated only for the points of the Profinet client DB, 
+///     the limits of the Task, ProducerService & diagnosis points are rejected, see [reject_limits](PointConfig::reject_limits)
 ///  - comment - description text
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct PointConfig {
@@ -44,6 +49,9 @@ pub struct PointConfig {
     pub address: Option<PointConfigAddress>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub filters: Option<PointConfigFilter>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limits: Option<PointConfigLimits>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub comment: Option<String>,
 }
@@ -69,6 +77,13 @@ impl PointConfig {
     ///     filter:                 # Filter conf, using such filter, point can be filtered immediately after input's parser
     ///         threshold: 0.5      #   absolute threshold delta
     ///         factor: 1.5         #   multiplier for absolute threshold delta - in this case the delta will be accumulated
+    ///     limits:                 # Alarm limits, the alarm points 'PointName.HiHi' ... 'PointName.LoLo' generated (Optional)
+    ///         hihi: 90.0          #   high-high limit (Optional)
+    ///         hi: 80.0            #   high limit (Optional)
+    ///         lo: 10.0            #   low limit (Optional)
+    ///         lolo: 5.0           #   low-low limit (Optional)
+    ///         deadband: 0.5       #   the alarm cleared when the value back from the limit more then deadband (Optional)
+    ///         delay: 500ms        #   the limit has to be violated during delay to raise the alarm (Optional)
     ///     comment: Test Point 
     /// ```
     pub fn new(parent_name: &Name, conf_tree: &ConfTree) -> Self {
@@ -117,6 +132,37 @@ impl PointConfig {
         }
     }
     ///
+    /// Returns configurations of the alarm points, one Bool point for the each configured limit
+    /// - named 'PointName.HiHi', 'PointName.Hi', 'PointName.Lo', 'PointName.LoLo'
+    /// - having the same alarm class & history as the point itself
+    pub fn alarm_points(&self) -> Vec<PointConfig> {
+        match &self.limits {
+            Some(limits) => limits.limits().into_iter().map(|(limit, _)| {
+                PointConfig {
+                    id: 0,
+                    name: limit.point_name(&self.name),
+                    _type: PointConfigType::Bool,
+                    history: self.history.clone(),
+                    alarm: self.alarm,
+                    address: None,
+                    filters: None,
+                    limits: None,
+                    comment: Some(format!("{:?} alarm of the {}", limit, self.name)),
+                }
+            }).collect(),
+            None => vec![],
+        }
+    }
+    ///
+    /// Panics if the alarm limits are configured,
+    /// used for the points of the components, which don't evaluate the limits
+    pub fn reject_limits(self, self_id: &str) -> Self {
+        if self.limits.is_some() {
+            panic!("{} | Point '{}' - alarm limits are evaluated only for the Profinet client DB points, remove 'limits' from: {:?}", self_id, self.name, self);
+        }
+        self
+    }
+    ///
     /// Returns json containing internally taggged PointConfig
     pub fn to_json(&self) -> serde_json::Value {
         let result: serde_json::Value = serde_json::to_value(self).unwrap();
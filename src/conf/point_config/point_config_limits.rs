@@ -0,0 +1,89 @@
+use std::{str::FromStr, time::Duration};
+use serde::{Serialize, Deserialize};
+use crate::conf::conf_duration::ConfDuration;
+
+///
+/// The alarm limits of the analog point
+///     - [hihi] - the high-high limit, alarm raised if the value is greater then the limit
+///     - [hi] - the high limit, alarm raised if the value is greater then the limit
+///     - [lo] - the low limit, alarm raised if the value is less then the limit
+///     - [lolo] - the low-low limit, alarm raised if the value is less then the limit
+///     - [deadband] - the raised alarm cleared only when the value is back from the limit more then deadband
+///     - [delay] - the limit has to be violated during delay before the alarm is raised
+/// ```
+///     point Point.Name:
+///         type: 'Real'
+///         alarm: 4
+///         limits:
+///             hihi: 90.0
+///             hi: 80.0
+///             lo: 10.0
+///             lolo: 5.0
+///             deadband: 0.5
+///             delay: 500ms
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PointConfigLimits {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hihi: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hi: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lo: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lolo: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deadband: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delay: Option<String>,
+}
+///
+///
+impl PointConfigLimits {
+    ///
+    /// Returns the configured delay, zero if omitted
+    pub fn delay(&self) -> Duration {
+        match &self.delay {
+            Some(delay) => ConfDuration::from_str(delay).unwrap_or_else(|err| {
+                panic!("PointConfigLimits.delay | Invalid 'delay' parameter: {:?}, error: {:?}", delay, err);
+            }).toDuration(),
+            None => Duration::ZERO,
+        }
+    }
+    ///
+    /// Returns the configured limits in the order: hihi, hi, lo, lolo
+    pub fn limits(&self) -> Vec<(PointConfigLimit, f64)> {
+        [
+            (PointConfigLimit::HiHi, self.hihi),
+            (PointConfigLimit::Hi, self.hi),
+            (PointConfigLimit::Lo, self.lo),
+            (PointConfigLimit::LoLo, self.lolo),
+        ]
+        .into_iter()
+        .filter_map(|(kind, limit)| limit.map(|limit| (kind, limit)))
+        .collect()
+    }
+}
+///
+/// The kind of the alarm limit
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PointConfigLimit {
+    HiHi,
+    Hi,
+    Lo,
+    LoLo,
+}
+///
+///
+impl PointConfigLimit {
+    ///
+    /// Returns true for the high limits, violated if the value is greater then the limit
+    pub fn is_high(&self) -> bool {
+        matches!(self, Self::HiHi | Self::Hi)
+    }
+    ///
+    /// Returns the name of the alarm point of the limit, configured for the point [parent]
+    pub fn point_name(&self, parent: &str) -> String {
+        format!("{}.{:?}", parent, self)
+    }
+}
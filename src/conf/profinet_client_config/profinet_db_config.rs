@@ -68,10 +68,12 @@ impl ProfinetDbConfig {
         }
     }    
     ///
-    /// Returns list of configurations of the defined points
+    /// Returns list of configurations of the defined points,
+    /// including alarm points generated from the configured limits
     pub fn points(&self) -> Vec<PointConfig> {
         self.points.iter().fold(vec![], |mut points, conf| {
             points.push(conf.clone());
+            points.extend(conf.alarm_points());
             points
         })
     }
@@ -201,7 +201,7 @@ impl ServiceConfig {
                         let point_name = Name::new(parent, keyword.data()).join();
                         let point_conf = diag_node_conf.get(key).unwrap();
                         trace!("{}.get_diagnosis | Point '{}'", self.id, point_name);
-                        let point = PointConfig::new(parent, &point_conf);
+                        let point = PointConfig::new(parent, &point_conf).reject_limits(&self.id);
                         let point_name_keywd = DiagKeywd::new(&point.name);
                         points.insert(point_name_keywd, point);
                     } else {
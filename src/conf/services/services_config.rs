@@ -39,6 +39,7 @@ use crate::conf::{
 ///         input: const real 2.224
 ///     
 ///     fn ToMultiQueue:
+///         queue: /App/MultiQueue.in-queue
 ///         in1 point CraneMovement.BoomUp: 
 ///             type: 'Int'
 ///             comment: 'Some indication'
@@ -1,6 +1,6 @@
 use indexmap::IndexMap;
 use log::{trace, debug};
-use std::{fs, time::Duration};
+use std::{fs, str::FromStr, time::Duration};
 use crate::conf::{
     fn_::{
         fn_config::FnConfig,
@@ -10,13 +10,16 @@ use crate::conf::{
     point_config::point_config::PointConfig,
 };
 
-use super::{conf_subscribe::ConfSubscribe, point_config::name::Name};
+use super::{conf_subscribe::ConfSubscribe, point_config::name::Name, task_mode::TaskMode};
 
 ///
 /// creates config from serde_yaml::Value of following format:
 /// ```yaml
 /// service Task operatingMetric:
 ///     cycle: 100 ms
+///     mode: on-event      # on-event (default) / cyclic / both
+///     retain: true        # true / false - enables storing the state of the functions on the disk
+///     retain-delay: 30 s  # time to wait before next store, store on exit unconditionally
 ///     metrics:
 ///         fn sqlUpdateMetric:
 ///             table: "TableName"
@@ -33,6 +36,9 @@ use super::{conf_subscribe::ConfSubscribe, point_config::name::Name};
 pub struct TaskConfig {
     pub(crate) name: Name,
     pub(crate) cycle: Option<Duration>,
+    pub(crate) mode: TaskMode,
+    pub(crate) retain: bool,
+    pub(crate) retain_delay: Duration,
     pub(crate) rx: String,
     pub(crate) rx_max_length: i64,
     pub(crate) subscribe: ConfSubscribe,
@@ -47,6 +53,9 @@ impl TaskConfig {
     /// ```yaml
     /// task taskName:
     ///     cycle: 100 ms
+    ///     mode: cyclic        # on-event (default) / cyclic / both, cycle required for cyclic & both
+    ///     retain: true        # true / false - retains the state of the all functions, can be overridden by the function 'retain' parameter
+    ///     retain-delay: 30 s  # time to wait before next store, default 30 s, store on exit unconditionally
     ///     fn sqlUpdateMetric:
     ///         table: "TableName"
     ///         sql: "UPDATE {table} SET kind = '{input1}' WHERE id = '{input2}';"
@@ -69,6 +78,24 @@ impl TaskConfig {
         debug!("{}.new | name: {:?}", self_id, self_name);
         let cycle = self_conf.get_duration("cycle");
         debug!("{}.new | cycle: {:?}", self_id, cycle);
+        let mode = match self_conf.get_param_value("mode") {
+            Ok(value) => match value.as_str().map(TaskMode::from_str) {
+                Some(Ok(mode)) => mode,
+                Some(Err(err)) => panic!("{}.new | Invalid 'mode' parameter: {:?}, error: {}", self_id, value, err),
+                None => panic!("{}.new | Invalid 'mode' parameter: {:?}, expected one of: on-event, cyclic, both", self_id, value),
+            }
+            Err(_) => TaskMode::OnEvent,
+        };
+        if mode.is_cyclic() && cycle.map_or(true, |cycle| cycle.is_zero()) {
+            panic!("{}.new | Mode '{:?}' requires non-zero 'cycle' parameter", self_id, mode);
+        }
+        debug!("{}.new | mode: {:?}", self_id, mode);
+        let retain = self_conf.get_param_value("retain").unwrap_or(serde_yaml::Value::Bool(false)).as_bool().unwrap_or_else(|| {
+            panic!("{}.new | Invalid 'retain' parameter, true / false expected", self_id)
+        });
+        debug!("{}.new | retain: {:?}", self_id, retain);
+        let retain_delay = self_conf.get_duration("retain-delay").unwrap_or(Duration::from_secs(30));
+        debug!("{}.new | retain-delay: {:?}", self_id, retain_delay);
         let (rx, rx_max_length) = self_conf.get_in_queue().unwrap();
         debug!("{}.new | RX: {},\tmax-length: {:?}", self_id, rx, rx_max_length);
         let subscribe = ConfSubscribe::new(self_conf.get_param_value("subscribe").unwrap_or(serde_yaml::Value::Null));
@@ -88,6 +115,9 @@ impl TaskConfig {
         TaskConfig {
             name: self_name,
             cycle,
+            mode,
+            retain,
+            retain_delay,
             rx,
             rx_max_length,
             subscribe,
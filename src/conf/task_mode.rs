@@ -0,0 +1,42 @@
+use std::str::FromStr;
+///
+/// The execution mode of the Task
+///     - on-event - the dependent nodes evaluated on each received point (default)
+///     - cyclic - the received points only applied to the inputs,
+/// all nodes evaluated every `cycle` even if no new points received
+///     - both - the dependent nodes evaluated on each received point
+/// & all nodes evaluated every `cycle`
+/// ```yaml
+/// service Task Task1:
+///     cycle: 100 ms
+///     mode: cyclic
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TaskMode {
+    #[default]
+    OnEvent,
+    Cyclic,
+    Both,
+}
+///
+///
+impl TaskMode {
+    ///
+    /// Returns true if all nodes has to be evaluated every cycle
+    pub fn is_cyclic(&self) -> bool {
+        matches!(self, Self::Cyclic | Self::Both)
+    }
+}
+///
+///
+impl FromStr for TaskMode {
+    type Err = String;
+    fn from_str(input: &str) -> Result<TaskMode, String> {
+        match input.trim().to_lowercase().as_str() {
+            "on-event" => Ok(Self::OnEvent),
+            "cyclic" => Ok(Self::Cyclic),
+            "both" => Ok(Self::Both),
+            _ => Err(format!("TaskMode.from_str | Unknown task mode: '{}', expected one of: on-event, cyclic, both", input)),
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use std::time::Duration;
+use chrono::{DateTime, Utc};
+use log::{debug, warn};
+use crate::{
+    conf::point_config::{point_config::PointConfig, point_config_limits::PointConfigLimit},
+    core_::{cot::cot::Cot, point::{point::Point, point_type::PointType}, types::bool::Bool},
+};
+
+///
+/// The state of the single limit
+#[derive(Debug)]
+struct Limit {
+    kind: PointConfigLimit,
+    limit: f64,
+    name: String,
+    state: Option<bool>,
+    violated: Option<DateTime<Utc>>,
+}
+///
+/// Evaluates the point against the alarm limits configured in the [PointConfig]
+/// - add(point) - applies the latest value of the point
+/// - next(timestamp) - returns alarm points changed since previous call:
+///     - true - alarm raised, the value is out of the limit during the configured delay
+///     - false - alarm cleared, the value is back from the limit more then the deadband
+/// - the alarm points named 'PointName.HiHi', 'PointName.Hi', 'PointName.Lo', 'PointName.LoLo',
+/// the alarm class of the point is configured by [PointConfig::alarm_points]
+/// - the initial cleared state is returned on the first evaluation
+#[derive(Debug)]
+pub struct AlarmLimits {
+    id: String,
+    tx_id: usize,
+    limits: Vec<Limit>,
+    deadband: f64,
+    delay: Duration,
+    point: Option<PointType>,
+}
+///
+///
+impl AlarmLimits {
+    ///
+    /// Creates new instance of the AlarmLimits
+    /// - tx_id - the id of the producer of the alarm points
+    /// - conf - the config of the point, having the alarm limits
+    pub fn new(parent: impl Into<String>, tx_id: usize, conf: &PointConfig) -> Self {
+        let id = format!("{}/AlarmLimits({})", parent.into(), conf.name);
+        let (limits, deadband, delay) = match &conf.limits {
+            Some(limits) => (
+                limits.limits().into_iter().map(|(kind, limit)| Limit {
+                    kind,
+                    limit,
+                    name: kind.point_name(&conf.name),
+                    state: None,
+                    violated: None,
+                }).collect(),
+                limits.deadband.unwrap_or(0.0).abs(),
+                limits.delay(),
+            ),
+            None => (vec![], 0.0, Duration::ZERO),
+        };
+        Self {
+            id,
+            tx_id,
+            limits,
+            deadband,
+            delay,
+            point: None,
+        }
+    }
+    ///
+    /// Applies the latest value of the point
+    pub fn add(&mut self, point: &PointType) {
+        self.point = Some(point.clone());
+    }
+    ///
+    /// Evaluates the latest value of the point at the [timestamp],
+    /// returns alarm points, which state has been changed
+    pub fn next(&mut self, timestamp: DateTime<Utc>) -> Vec<PointType> {
+        let point = match &self.point {
+            Some(point) => point,
+            None => return vec![],
+        };
+        let value = match point {
+            PointType::Bool(point) => if point.value.0 {1.0} else {0.0},
+            PointType::Int(point) => point.value as f64,
+            PointType::Real(point) => point.value as f64,
+            PointType::Double(point) => point.value,
+            PointType::String(point) => {
+                warn!("{}.next | String point can't be evaluated against limits: {:?}", self.id, point.name);
+                return vec![];
+            }
+        };
+        let delay = chrono::Duration::from_std(self.delay).unwrap_or(chrono::Duration::zero());
+        let mut alarms = vec![];
+        for limit in &mut self.limits {
+            let (violated, normal) = if limit.kind.is_high() {
+                (value > limit.limit, value < limit.limit - self.deadband)
+            } else {
+                (value < limit.limit, value > limit.limit + self.deadband)
+            };
+            let state = match limit.state {
+                Some(true) => {
+                    if normal {
+                        limit.violated = None;
+                        Some(false)
+                    } else {
+                        None
+                    }
+                }
+                state => {
+                    if violated {
+                        let since = *limit.violated.get_or_insert(point.timestamp());
+                        if timestamp - since >= delay {
+                            Some(true)
+                        } else {
+                            None
+                        }
+                    } else {
+                        limit.violated = None;
+                        state.map_or(Some(false), |_| None)
+                    }
+                }
+            };
+            if let Some(state) = state {
+                debug!("{}.next | {:?} alarm: {}, value: {}, limit: {}", self.id, limit.kind, state, value, limit.limit);
+                limit.state = Some(state);
+                alarms.push(PointType::Bool(Point::new(
+                    self.tx_id,
+                    &limit.name,
+                    Bool(state),
+                    point.status(),
+                    Cot::Inf,
+                    timestamp,
+                )));
+            }
+        }
+        alarms
+    }
+}
@@ -0,0 +1 @@
+pub mod alarm_limits;
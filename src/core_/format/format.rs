@@ -20,6 +20,11 @@ use crate::core_::point::point_type::PointType;
 ///     - input.timestamp
 ///     - input.status
 /// ````
+/// - the name & sufix consist of the letters, digits & '_' only,
+/// any other braces are kept as is, so JSON template is allowed: `{"load": {load}}`
+/// - the marker typed as json `{input:json}`, `{input.sufix:json}` rendered as the complete JSON value,
+/// must be placed without quotes: String value, name & timestamp - quoted & escaped string, numeric value & status - as is,
+/// so the String value containing `"` or `\` keeps the JSON valid: `{"message": {message:json}}`
 /// - formating string: "insert into {table} (id, value) values ({input1.status}, {input1.value})"
 /// - values can be added using insert method format.insert("input1", point)
 /// - values: table = "temperature"; point.status = 1; point.value = 19,7
@@ -35,7 +40,7 @@ impl Format {
     ///
     /// 
     pub fn new(input: &str) -> Self {
-        let re = r#"\{(.*?)\}"#;
+        let re = r#"\{(\w+(?:\.\w+)?)(?::json)?\}"#;
         let re = RegexBuilder::new(re).multi_line(true).build().unwrap();
         let names = re.captures_iter(input).map(|cap| {
             let fullName = cap.get(1).unwrap().as_str().to_string();
@@ -78,6 +83,10 @@ impl Format {
                         Self::pointValueToString(point)
                     }
                 };
+                let pattern = format!("{{{}:json}}", fullName);
+                let json = Self::jsonValue(point, sufix, &value);
+                trace!("Format.out | replacing pattern {:?} with value: {:?}", pattern, json);
+                input = input.replace(&pattern, &json);
                 let pattern = format!("{{{}}}", fullName);
                 trace!("Format.out | replacing pattern {:?} with value: {:?}", pattern, value);
                 input = input.replace(&pattern, &value);
@@ -92,6 +101,18 @@ impl Format {
         point.value().to_string()
     }
     ///
+    /// Returns the complete JSON value of the [raw] rendered by the marker with the [sufix]
+    ///  - numeric value & status - as is
+    ///  - String value, name & timestamp - quoted & escaped JSON string
+    fn jsonValue(point: &PointType, sufix: &Option<String>, raw: &str) -> String {
+        let numeric = matches!(sufix.as_deref(), Some("value") | Some("status") | None) && !matches!(point, PointType::String(_));
+        if numeric {
+            raw.to_owned()
+        } else {
+            serde_json::Value::String(raw.to_owned()).to_string()
+        }
+    }
+    ///
     /// Returns List of al names & sufixes in the following format:
     /// ```
     /// HashMap<fullName, (name, sufix)>
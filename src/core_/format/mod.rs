@@ -1 +1,5 @@
-pub mod format;
\ No newline at end of file
+pub mod format;
+
+pub mod sql_dialect;
+
+pub mod sql_format;
\ No newline at end of file
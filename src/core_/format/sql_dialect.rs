@@ -0,0 +1,60 @@
+use std::str::FromStr;
+///
+/// The SQL dialect, defines how the string values escaped in the SqlFormat
+///     - postgres - standard conforming strings, the quote doubled
+///     - mysql - backslash escapes, the quote, backslash & control characters escaped
+///     - sqlite - standard strings, the quote doubled
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SqlDialect {
+    #[default]
+    Postgres,
+    MySql,
+    Sqlite,
+}
+///
+///
+impl SqlDialect {
+    ///
+    /// Returns the [value] escaped to be placed inside of the quoted string literal
+    pub fn escape(&self, value: &str) -> String {
+        match self {
+            SqlDialect::Postgres | SqlDialect::Sqlite => {
+                value.chars().filter(|c| *c != '\0').collect::<String>().replace('\'', "''")
+            }
+            SqlDialect::MySql => {
+                let mut escaped = String::with_capacity(value.len());
+                for c in value.chars() {
+                    match c {
+                        '\\' => escaped.push_str("\\\\"),
+                        '\'' => escaped.push_str("\\'"),
+                        '"' => escaped.push_str("\\\""),
+                        '\0' => escaped.push_str("\\0"),
+                        '\n' => escaped.push_str("\\n"),
+                        '\r' => escaped.push_str("\\r"),
+                        '\x1a' => escaped.push_str("\\Z"),
+                        _ => escaped.push(c),
+                    }
+                }
+                escaped
+            }
+        }
+    }
+    ///
+    /// Returns the [value] as quoted & escaped string literal
+    pub fn quote(&self, value: &str) -> String {
+        format!("'{}'", self.escape(value))
+    }
+}
+///
+///
+impl FromStr for SqlDialect {
+    type Err = String;
+    fn from_str(input: &str) -> Result<SqlDialect, String> {
+        match input.trim().to_lowercase().as_str() {
+            "postgres" | "postgresql" => Ok(Self::Postgres),
+            "mysql" | "mariadb" => Ok(Self::MySql),
+            "sqlite" => Ok(Self::Sqlite),
+            _ => Err(format!("SqlDialect.from_str | Unknown SQL dialect: '{}', expected one of: postgres, mysql, sqlite", input)),
+        }
+    }
+}
@@ -0,0 +1,244 @@
+use std::collections::HashMap;
+use log::{trace, warn};
+use regex::{Regex, RegexBuilder};
+use crate::core_::{format::sql_dialect::SqlDialect, point::point_type::PointType};
+///
+/// The type of the typed marker, defines how the value rendered into the sql
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqlType {
+    Text,
+    Int,
+    Real,
+    Bool,
+    Timestamp,
+}
+///
+///
+impl SqlType {
+    ///
+    ///
+    fn from_str(self_id: &str, input: &str) -> Self {
+        match input.trim() {
+            "text" => Self::Text,
+            "int" => Self::Int,
+            "real" => Self::Real,
+            "bool" => Self::Bool,
+            "timestamp" => Self::Timestamp,
+            _ => panic!("{}.new | Unknown marker type: '{}', allowed: text / int / real / bool / timestamp", self_id, input),
+        }
+    }
+}
+///
+/// Replaces input markers {marker name} with the values escaped for the SQL dialect
+///
+/// input marker can be:
+/// ````
+///      input | sufix      | type
+///      name  |            |
+///     - input  - by defoult input.value will be used
+///     - input.name
+///     - input.value
+///     - input.timestamp
+///     - input.status
+///     - input:type, input.sufix:type
+/// ````
+/// - untyped marker inside of the quotes `'{input1}'` - the value escaped
+/// - untyped marker without quotes `{input1}` - numeric value rendered as is,
+/// String value, name & timestamp rendered as the quoted & escaped literal
+/// - identifier `{table}`, inserted by [insert_identifier], rendered as is, validated on insert
+/// - typed marker - the value rendered as the complete SQL literal, must be placed without quotes:
+///     - text - quoted & escaped string: `{input1.name:text}` => 'Point''s name'
+///     - int - integer, from int, bool, or integer string
+///     - real - finite number
+///     - bool - TRUE / FALSE, from bool, int, or 'true' / 'false' string
+///     - timestamp - quoted RFC 3339 timestamp of the point
+/// - the value, which can't be converted to the type of the marker, rendered as NULL
+/// - all markers replaced in the single pass, the marker inside of the inserted value is never expanded
+/// - formating string: "insert into {table} (name, value) values ({input1.name:text}, {input1.value:real})"
+/// - out   : "insert into temperature (name, value) values ('/App/Temp', 19.7)"
+pub struct SqlFormat {
+    dialect: SqlDialect,
+    input: String,
+    names: HashMap<String, (String, Option<String>)>,
+    types: HashMap<String, SqlType>,
+    values: HashMap<String, PointType>,
+    identifiers: HashMap<String, String>,
+}
+///
+///
+impl SqlFormat {
+    ///
+    ///
+    pub fn new(input: &str, dialect: SqlDialect) -> Self {
+        let self_id = "SqlFormat";
+        let re = Self::re();
+        let mut names = HashMap::new();
+        let mut types = HashMap::new();
+        for (cap, quoted) in Self::markers(&re, input) {
+            let full_name = cap.get(1).unwrap().as_str().to_string();
+            let (marker, type_) = match full_name.split_once(':') {
+                Some((marker, type_)) => (marker, Some(SqlType::from_str(self_id, type_))),
+                None => (full_name.as_str(), None),
+            };
+            if quoted && type_.is_some() {
+                panic!("{}.new | Typed marker {{{}}} rendered as the complete SQL literal, must be placed without quotes in: {:?}", self_id, full_name, input);
+            }
+            let mut parts = marker.split('.').map(|part| part.to_owned());
+            let name = parts.next().unwrap();
+            let sufix = parts.next();
+            if let Some(type_) = type_ {
+                types.insert(full_name.clone(), type_);
+            }
+            names.insert(full_name, (name, sufix));
+        }
+        trace!("{}.new | names {:?}, types: {:?}", self_id, &names, &types);
+        Self {
+            dialect,
+            input: input.into(),
+            names,
+            types,
+            values: HashMap::new(),
+            identifiers: HashMap::new(),
+        }
+    }
+    ///
+    ///
+    pub fn insert(&mut self, key: &str, value: PointType) {
+        self.values.insert(key.into(), value);
+    }
+    ///
+    /// Inserts the identifier (table / column name), rendered as is,
+    /// allowed the plain name of the letters, digits & '_', the quoted name "name" / `name`,
+    /// optionally prefixed with the schema: 'public.event', 'public."event"'
+    pub fn insert_identifier(&mut self, key: &str, identifier: &str) {
+        let part = r#"(?:[A-Za-z_][A-Za-z0-9_]*|"[^"]+"|`[^`]+`)"#;
+        let re = RegexBuilder::new(&format!(r#"^{}(?:\.{})?$"#, part, part)).build().unwrap();
+        if !re.is_match(identifier) {
+            panic!("SqlFormat.insert_identifier | Invalid SQL identifier {:?} of the marker {{{}}}", identifier, key);
+        }
+        self.identifiers.insert(key.into(), identifier.into());
+    }
+    ///
+    /// Returns the sql with all inserted values rendered,
+    /// and the list of errors for the values rendered as NULL
+    pub fn build(&self) -> (String, Vec<String>) {
+        let mut errors = vec![];
+        let mut out = String::with_capacity(self.input.len());
+        let mut last = 0;
+        // single pass, so the markers found in the rendered values are never replaced
+        for (cap, quoted) in Self::markers(&Self::re(), &self.input) {
+            let marker = cap.get(0).unwrap();
+            let full_name = cap.get(1).unwrap().as_str();
+            out.push_str(&self.input[last..marker.start()]);
+            last = marker.end();
+            let value = match (self.identifiers.get(full_name), self.names.get(full_name), self.values.get(full_name)) {
+                (Some(identifier), _, _) => identifier.clone(),
+                (None, Some((name, sufix)), Some(point)) => {
+                    let raw = match sufix.as_deref() {
+                        Some("name") => point.name(),
+                        Some("value") | None => point.value().to_string(),
+                        Some("timestamp") => point.timestamp().to_string(),
+                        Some("status") => point.status().to_string(),
+                        Some(sufix) => panic!("SqlFormat.out | Unknown input sufix '{}' in: {:?}, allowed: .name / .value / .timestamp / .status", sufix, &name),
+                    };
+                    match self.types.get(full_name) {
+                        Some(type_) => match self.literal(*type_, point, &raw) {
+                            Some(value) => value,
+                            None => {
+                                let error = format!("SqlFormat.out | Value '{}' of the marker {{{}}} can't be converted to {:?}", raw, full_name, type_);
+                                warn!("{}", error);
+                                errors.push(error);
+                                "NULL".to_owned()
+                            }
+                        }
+                        None => {
+                            let numeric = matches!(sufix.as_deref(), Some("value") | Some("status") | None) && !matches!(point, PointType::String(_));
+                            match (quoted, numeric) {
+                                (true, _) => self.dialect.escape(&raw),
+                                (false, true) => raw,
+                                (false, false) => self.dialect.quote(&raw),
+                            }
+                        }
+                    }
+                }
+                _ => marker.as_str().to_owned(),
+            };
+            trace!("SqlFormat.out | replacing marker {:?} with value: {:?}", full_name, value);
+            out.push_str(&value);
+        }
+        out.push_str(&self.input[last..]);
+        (out, errors)
+    }
+    ///
+    /// Returns all markers of the [input] with the flag, if the marker is inside of the single quotes
+    fn markers<'a>(re: &Regex, input: &'a str) -> Vec<(regex::Captures<'a>, bool)> {
+        let mut quoted = false;
+        let mut last = 0;
+        re.captures_iter(input).map(|cap| {
+            let marker = cap.get(0).unwrap();
+            if input[last..marker.start()].matches('\'').count() % 2 == 1 {
+                quoted = !quoted;
+            }
+            last = marker.end();
+            (cap, quoted)
+        }).collect()
+    }
+    ///
+    /// Returns the sql with all inserted values rendered
+    pub fn out(&self) -> String {
+        self.build().0
+    }
+    ///
+    /// The marker pattern '{name[.sufix][:type]}'
+    fn re() -> Regex {
+        RegexBuilder::new(r#"\{(.*?)\}"#).multi_line(true).build().unwrap()
+    }
+    ///
+    /// Returns the SQL literal of the [type_], or None if the value can't be converted
+    fn literal(&self, type_: SqlType, point: &PointType, raw: &str) -> Option<String> {
+        let raw = raw.trim();
+        match type_ {
+            SqlType::Text => Some(self.dialect.quote(raw)),
+            SqlType::Int => match raw {
+                "true" => Some("1".to_owned()),
+                "false" => Some("0".to_owned()),
+                _ => raw.parse::<i64>().ok()
+                    .or_else(|| raw.parse::<f64>().ok().filter(|v| v.is_finite() && v.fract() == 0.0 && v.abs() < i64::MAX as f64).map(|v| v as i64))
+                    .map(|v| v.to_string()),
+            }
+            SqlType::Real => match raw {
+                "true" => Some("1".to_owned()),
+                "false" => Some("0".to_owned()),
+                _ => raw.parse::<f64>().ok().filter(|v| v.is_finite()).map(|v| v.to_string()),
+            }
+            SqlType::Bool => match raw {
+                "true" => Some("TRUE".to_owned()),
+                "false" => Some("FALSE".to_owned()),
+                _ => raw.parse::<f64>().ok().filter(|v| v.is_finite()).map(|v| if v != 0.0 {"TRUE"} else {"FALSE"}.to_owned()),
+            }
+            SqlType::Timestamp => Some(self.dialect.quote(&point.timestamp().to_rfc3339())),
+        }
+    }
+    ///
+    /// Returns List of al names & sufixes in the following format:
+    /// ```
+    /// HashMap<fullName, (name, sufix)>
+    /// ```
+    pub fn names(&self) -> HashMap<String, (String, Option<String>)> {
+        self.names.clone()
+    }
+}
+///
+///
+impl std::fmt::Display for SqlFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.out())
+    }
+}
+///
+///
+impl std::fmt::Debug for SqlFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.out())
+    }
+}
@@ -28,4 +28,6 @@ pub mod cot;
 
 pub mod object;
 
-pub mod cli;
\ No newline at end of file
+pub mod cli;
+
+pub mod alarm;
\ No newline at end of file
@@ -119,59 +119,80 @@ impl Point<String> {
 impl<T: std::ops::Add<Output = T> + Clone> std::ops::Add for Point<T> {
     type Output = Point<T>;
     fn add(self, rhs: Self) -> Self::Output {
-        let status = match self.status.cmp(&rhs.status) {
-            std::cmp::Ordering::Less => rhs.status,
-            std::cmp::Ordering::Equal => self.status,
-            std::cmp::Ordering::Greater => self.status,
-        };
-        let (tx_id, timestamp) = match self.timestamp.cmp(&rhs.timestamp) {
-            std::cmp::Ordering::Less => (rhs.tx_id, rhs.timestamp),
-            std::cmp::Ordering::Equal => (self.tx_id, self.timestamp),
-            std::cmp::Ordering::Greater => (self.tx_id, self.timestamp),
-        };
-        let direction = if self.cot == rhs.cot {
-            self.cot
-        } else {
-            panic!("Point.add | Directions are not equals")
-        };
-        Point {
-            tx_id,
-            name: String::from("Point.Add"),
-            value: self.value + rhs.value,
-            status,
-            cot: direction,
-            timestamp,
-        }
+        binary_op(self, rhs, "Add", |lhs, rhs| lhs + rhs)
+    }
+}
+///
+/// Binary operation over two points:
+/// - the worst status of the both
+/// - tx_id & timestamp of the latest
+/// - [op] - the name of the operation, the result named 'Point.{op}'
+/// - panics if the Cot of the points are not equal
+fn binary_op<T>(lhs: Point<T>, rhs: Point<T>, op: &str, value: impl FnOnce(T, T) -> T) -> Point<T> {
+    if lhs.cot != rhs.cot {
+        panic!("Point.{} | Directions are not equals", op.to_lowercase())
+    }
+    combine(lhs, rhs, op, value)
+}
+///
+/// Binary operation over two points, same as the Point operators, but the Cot is not checked:
+/// - the worst status of the both
+/// - tx_id & timestamp of the latest
+/// - Cot of the [lhs]
+/// - [op] - the name of the operation, the result named 'Point.{op}'
+pub fn combine<T>(lhs: Point<T>, rhs: Point<T>, op: &str, value: impl FnOnce(T, T) -> T) -> Point<T> {
+    let status = lhs.status.max(rhs.status);
+    let (tx_id, timestamp) = match lhs.timestamp.cmp(&rhs.timestamp) {
+        std::cmp::Ordering::Less => (rhs.tx_id, rhs.timestamp),
+        std::cmp::Ordering::Equal => (lhs.tx_id, lhs.timestamp),
+        std::cmp::Ordering::Greater => (lhs.tx_id, lhs.timestamp),
+    };
+    Point {
+        tx_id,
+        name: format!("Point.{}", op),
+        value: value(lhs.value, rhs.value),
+        status,
+        cot: lhs.cot,
+        timestamp,
+    }
+}
+///
+/// 
+impl<T: std::ops::Sub<Output = T> + Clone> std::ops::Sub for Point<T> {
+    type Output = Point<T>;
+    fn sub(self, rhs: Self) -> Self::Output {
+        binary_op(self, rhs, "Sub", |lhs, rhs| lhs - rhs)
+    }
+}
+///
+/// 
+impl<T: std::ops::Mul<Output = T> + Clone> std::ops::Mul for Point<T> {
+    type Output = Point<T>;
+    fn mul(self, rhs: Self) -> Self::Output {
+        binary_op(self, rhs, "Mul", |lhs, rhs| lhs * rhs)
+    }
+}
+///
+/// 
+impl<T: std::ops::Div<Output = T> + Clone> std::ops::Div for Point<T> {
+    type Output = Point<T>;
+    fn div(self, rhs: Self) -> Self::Output {
+        binary_op(self, rhs, "Div", |lhs, rhs| lhs / rhs)
+    }
+}
+///
+/// 
+impl<T: std::ops::Rem<Output = T> + Clone> std::ops::Rem for Point<T> {
+    type Output = Point<T>;
+    fn rem(self, rhs: Self) -> Self::Output {
+        binary_op(self, rhs, "Rem", |lhs, rhs| lhs % rhs)
     }
 }
-
 
 impl<T: std::ops::BitOr<Output = T>> std::ops::BitOr for Point<T> {
     type Output = Point<T>;
     fn bitor(self, rhs: Self) -> Self::Output {
-        let status = match self.status.cmp(&rhs.status) {
-            std::cmp::Ordering::Less => rhs.status,
-            std::cmp::Ordering::Equal => self.status,
-            std::cmp::Ordering::Greater => self.status,
-        };
-        let (tx_id, timestamp) = match self.timestamp.cmp(&rhs.timestamp) {
-            std::cmp::Ordering::Less => (rhs.tx_id, rhs.timestamp),
-            std::cmp::Ordering::Equal => (self.tx_id, self.timestamp),
-            std::cmp::Ordering::Greater => (self.tx_id, self.timestamp),
-        };
-        let direction = if self.cot == rhs.cot {
-            self.cot
-        } else {
-            panic!("Point.add | Directions are not equals")
-        };
-        Point {
-            tx_id,
-            name: String::from("Point.BitOr"),
-            value: self.value | rhs.value,
-            status,
-            cot: direction,
-            timestamp,
-        }        
+        binary_op(self, rhs, "BitOr", |lhs, rhs| lhs | rhs)
     }
 }
 
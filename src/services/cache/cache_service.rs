@@ -66,25 +66,23 @@ impl CacheService {
         }
     }
     ///
-    ///
-    fn subscriptions(&mut self, conf: &CacheServiceConfig, points: &[PointConfig]) -> (String, Vec<SubscriptionCriteria>) {
+    /// Returns the list of the service names & the points to subscribe on
+    fn subscriptions(&mut self, conf: &CacheServiceConfig, points: &[PointConfig]) -> Vec<(String, Vec<SubscriptionCriteria>)> {
         if conf.subscribe.is_empty() {
             panic!("{}.subscribe | Error. Subscription can`t be empty: {:#?}", self.id, conf.subscribe);
         } else {
             debug!("{}.subscribe | conf.subscribe: {:#?}", self.id, conf.subscribe);
             let subscriptions = conf.subscribe.with(points);
             trace!("{}.subscribe | subscriptions: {:#?}", self.id, subscriptions);
-            if subscriptions.len() > 1 {
-                panic!("{}.run | Error. Task does not supports multiple subscriptions for now: {:#?}.\n\tTry to use single subscription.", self.id, subscriptions);
-            } else {
-                match subscriptions.clone().into_iter().next() {
-                    Some((service_name, Some(points))) => {
-                        (service_name, points)
-                    }
-                    Some((_, None)) => panic!("{}.run | Error. Subscription configuration error in: {:#?}", self.id, subscriptions),
+            if subscriptions.is_empty() {
+                panic!("{}.run | Error. Subscription configuration error in: {:#?}", self.id, conf.subscribe);
+            }
+            subscriptions.iter().map(|(service_name, points)| {
+                match points {
+                    Some(points) => (service_name.clone(), points.clone()),
                     None => panic!("{}.run | Error. Subscription configuration error in: {:#?}", self.id, subscriptions),
                 }
-            }
+            }).collect()
         }
     }
     ///
@@ -323,14 +321,10 @@ impl Service for CacheService {
         let services = self.services.clone();
         let cache = self.cache.clone();
         let point_configs = services.slock().points(&self_name.join());
-        let (service_name, points) = self.subscriptions(&conf, &point_configs);
-        debug!("{}.run | points: {:#?}", self_id, points.len());
-        trace!("{}.run | points: {:#?}", self_id, points);
-        let (_, rx_recv) = services.slock().subscribe(
-            &service_name,
-            &self.name.join(),
-            &points,
-        );
+        let subscriptions = self.subscriptions(&conf, &point_configs);
+        debug!("{}.run | subscriptions: {:#?}", self_id, subscriptions.len());
+        trace!("{}.run | subscriptions: {:#?}", self_id, subscriptions);
+        let rx_recv = services.slock().subscribe_all(&self.name.join(), &subscriptions);
         let mut dely_store = DelyStore::new(conf.retain_delay);
         info!("{}.run | Preparing thread...", self_id);
         let handle = thread::Builder::new().name(format!("{}.run", self_id)).spawn(move || {
@@ -372,7 +366,7 @@ impl Service for CacheService {
                     break;
                 }
             }
-            if let Err(err) = services.slock().unsubscribe(&service_name, &self_name.join(), &points) {
+            if let Err(err) = services.slock().unsubscribe_all(&self_name.join(), &subscriptions) {
                 error!("{}.run | Unsubscribe error: {:#?}", self_id, err);
             }
             info!("{}.run | Exit", self_id);
@@ -217,7 +217,8 @@ impl ProfinetClient {
                 let db = ProfinetDb::new(&self_id, tx_id, &db_conf);
                 dbs.insert(db_name.clone(), db);
                 info!("{}.write | configuring DB: {:?} - ok", self_id, db_name);
-                points.extend(db_conf.points());
+                // generated alarm points aren't writable, so only configured points subscribed
+                points.extend(db_conf.points.clone());
             }
             let points = points.iter().map(|point_conf| {
                 SubscriptionCriteria::new(&point_conf.name, Cot::Act)
@@ -9,6 +9,7 @@ use crate::{
         profinet_client_config::profinet_db_config::ProfinetDbConfig
     },
     core_::{
+        alarm::alarm_limits::AlarmLimits,
         filter::{filter::{Filter, FilterEmpty}, filter_threshold::FilterThreshold},
         point::point_type::PointType, status::status::Status
     },
@@ -34,6 +35,7 @@ pub struct ProfinetDb {
     pub size: u32,
     pub cycle: Option<Duration>,
     pub points: IndexMap<String, Box<dyn ParsePoint>>,
+    alarms: IndexMap<String, AlarmLimits>,
 }
 ///
 ///
@@ -54,6 +56,7 @@ impl ProfinetDb {
             size: conf.size as u32,
             cycle: conf.cycle,
             points: Self::configure_parse_points(&self_id, tx_id, conf),
+            alarms: Self::configure_alarms(&self_id, tx_id, conf),
         }
     }
     ///
@@ -128,9 +131,24 @@ impl ProfinetDb {
                             trace!("{}.read | bytes: {:?}", self.id, bytes);
                             let timestamp = Utc::now();
                             let mut message = String::new();
-                            for (_key, parse_point) in &mut self.points {
+                            for (key, parse_point) in &mut self.points {
                                 if let Some(point) = parse_point.next(&bytes, timestamp) {
                                     // debug!("{}.read | point: {:?}", self.id, point);
+                                    if let Some(alarm) = self.alarms.get_mut(key) {
+                                        alarm.add(&point);
+                                    }
+                                    match tx_send.send(point) {
+                                        Ok(_) => {}
+                                        Err(err) => {
+                                            message = format!("{}.read | send error: {}", self.id, err);
+                                            warn!("{}", message);
+                                        }
+                                    }
+                                }
+                            }
+                            // Limits evaluated on the each read, so the delayed alarm raised even if the value isn't changed
+                            for alarm in self.alarms.values_mut() {
+                                for point in alarm.next(timestamp) {
                                     match tx_send.send(point) {
                                         Ok(_) => {}
                                         Err(err) => {
@@ -247,6 +265,16 @@ impl ProfinetDb {
         }).collect()
     }
     ///
+    /// Configuring AlarmLimits for the points having limits in the configuration
+    fn configure_alarms(self_id: &str, tx_id: usize, conf: &ProfinetDbConfig) -> IndexMap<String, AlarmLimits> {
+        conf.points.iter()
+            .filter(|point_conf| point_conf.limits.is_some())
+            .map(|point_conf| {
+                (point_conf.name.clone(), AlarmLimits::new(self_id, tx_id, point_conf))
+            })
+            .collect()
+    }
+    ///
     ///
     fn box_bool(tx_id: usize, name: String, config: &PointConfig) -> Box<dyn ParsePoint> {
         Box::new(S7ParseBool::new(tx_id, name, config))
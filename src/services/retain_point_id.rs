@@ -77,6 +77,7 @@ impl RetainPointId {
                         alarm: point.alarm,
                         address: point.address,
                         filters: point.filters,
+                        limits: point.limits,
                         comment: point.comment,
                     }
                 );
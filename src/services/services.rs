@@ -1,5 +1,5 @@
-use std::{collections::HashMap, fmt::Debug, sync::{mpsc::{Receiver, Sender}, Arc, Mutex}};
-use log::{debug, trace};
+use std::{collections::HashMap, fmt::Debug, sync::{mpsc::{self, Receiver, Sender}, Arc, Mutex}, thread};
+use log::{debug, error, trace};
 use crate::{
     conf::point_config::point_config::PointConfig, core_::point::point_type::PointType,
     services::{
@@ -85,6 +85,38 @@ impl Services {
         }
     }
     ///
+    /// Subscribes the receiver on the points of the multiple services,
+    /// returns single Receiver, all subscriptions fanned into
+    /// - subscriptions - list of the service names & the points to subscribe on
+    /// - each subscription forwarded by its own thread,
+    /// the thread finished when the service unsubscribed or the returned Receiver dropped
+    pub fn subscribe_all(&mut self, receiver_name: &str, subscriptions: &[(String, Vec<SubscriptionCriteria>)]) -> Receiver<PointType> {
+        if let [(service, points)] = subscriptions {
+            let (_, recv) = self.subscribe(service, receiver_name, points);
+            return recv;
+        }
+        let (send, recv) = mpsc::channel();
+        for (service, points) in subscriptions {
+            let (_, service_recv) = self.subscribe(service, receiver_name, points);
+            let self_id = format!("{}/{}({})", self.id, receiver_name, service);
+            let send = send.clone();
+            let handle = thread::Builder::new().name(format!("{}.subscribe_all", self_id)).spawn(move || {
+                debug!("{}.subscribe_all | Forwarding...", self_id);
+                while let Ok(point) = service_recv.recv() {
+                    if let Err(err) = send.send(point) {
+                        debug!("{}.subscribe_all | Receiver dropped: {:?}", self_id, err);
+                        break;
+                    }
+                }
+                debug!("{}.subscribe_all | Forwarding - exit", self_id);
+            });
+            if let Err(err) = handle {
+                panic!("{}.subscribe_all | Forwarding of the subscription on '{}' start failed: {:#?}", self.id, service, err);
+            }
+        }
+        recv
+    }
+    ///
     /// Returns ok if subscription extended sucessfully
     /// - service - the name of the service to extend subscribtion on
     pub fn extend_subscription(&mut self, service: &str, receiver_name: &str, points: &[SubscriptionCriteria]) -> Result<(), String> {
@@ -114,6 +146,22 @@ impl Services {
         }
     }
     ///
+    /// Removes all subscriptions of the receiver, made by [subscribe_all](Self::subscribe_all),
+    /// returns ok if all subscriptions removed sucessfully, otherwise all errors
+    pub fn unsubscribe_all(&mut self, receiver_name: &str, subscriptions: &[(String, Vec<SubscriptionCriteria>)]) -> Result<(), String> {
+        let mut errors = vec![];
+        for (service, points) in subscriptions {
+            if let Err(err) = self.unsubscribe(service, receiver_name, points) {
+                error!("{}.unsubscribe_all | Unsubscribe from '{}' error: {:#?}", self.id, service, err);
+                errors.push(err);
+            }
+        }
+        match errors.is_empty() {
+            true => Ok(()),
+            false => Err(errors.join("\n")),
+        }
+    }
+    ///
     /// Returns list of point configurations over the all services
     ///  - requester_name - Service name !!!
     pub fn points(&mut self, requester_name: &str) -> Vec<PointConfig> {
@@ -13,3 +13,5 @@ pub mod task_test_receiver;
 pub mod task_test_producer;
 
 pub mod nested_function;
+
+pub mod retain_store;
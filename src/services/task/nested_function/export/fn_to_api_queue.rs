@@ -1,13 +1,15 @@
 use std::sync::{mpsc::Sender, atomic::{AtomicUsize, Ordering}};
 use log::{debug, error};
-use crate::{services::task::nested_function::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind}, core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef}};
+use crate::{services::task::nested_function::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_logic::to_bool}, core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef}};
 ///
 /// Exports data from the input into the associated queue
+///  - if optional [enable] input configured, data is sent only while it is true
 #[derive(Debug)]
 pub struct FnToApiQueue {
     id: String,
     kind: FnKind,
     input: FnInOutRef,
+    enable: Option<FnInOutRef>,
     tx_send: Sender<PointType>,
     state: String,
 }
@@ -21,11 +23,13 @@ impl FnToApiQueue {
     /// creates new instance of the FnToApiQueue
     /// - id - just for proper debugging
     /// - input - incoming points
-    pub fn new(parent: impl Into<String>, input: FnInOutRef, send: Sender<PointType>) -> Self {
+    /// - enable - optional input, data is sent only while it is true
+    pub fn new(parent: impl Into<String>, input: FnInOutRef, enable: Option<FnInOutRef>, send: Sender<PointType>) -> Self {
         Self {  
             id: format!("{}/FnToApiQueue{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
             kind: FnKind::Fn,
             input,
+            enable,
             tx_send: send,
             state: String::new(),
         }
@@ -52,13 +56,21 @@ impl FnOut for FnToApiQueue {
     }
     //
     fn inputs(&self) -> Vec<String> {
-        self.input.borrow().inputs()
+        let mut inputs = self.input.borrow().inputs();
+        if let Some(enable) = &self.enable {
+            inputs.extend(enable.borrow().inputs());
+        }
+        inputs
     }
     //
     fn out(&mut self) -> PointType {
         let point = self.input.borrow_mut().out();
+        let enabled = match &self.enable {
+            Some(enable) => to_bool(&self.id, &enable.borrow_mut().out()),
+            None => true,
+        };
         let sql = point.as_string().value;
-        if sql != self.state {
+        if enabled && sql != self.state {
             self.state = sql.clone();
             match self.tx_send.send(point.clone()) {
                 Ok(_) => {
@@ -74,6 +86,9 @@ impl FnOut for FnToApiQueue {
     //
     fn reset(&mut self) {
         self.input.borrow_mut().reset();
+        if let Some(enable) = &self.enable {
+            enable.borrow_mut().reset();
+        }
     }
 }
 ///
@@ -0,0 +1,111 @@
+use std::sync::{mpsc::Sender, atomic::{AtomicUsize, Ordering}};
+use log::{debug, error};
+use crate::{services::task::nested_function::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_logic::to_bool}, core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef}};
+///
+/// Exports points from the inputs into the associated queue (usually MultiQueue)
+///  - each input usually is the generated point (FnPoint) having configured name, type & Cot
+///  - without [enable] input only the changed points are sent,
+///  the point is compared with the last sent one from the same input
+///  - if optional [enable] input configured, all points are sent only while it is true,
+///  so exports can be triggered by the edge functions (risingEdge, fallingEdge, anyEdge)
+#[derive(Debug)]
+pub struct FnToMultiQueue {
+    id: String,
+    kind: FnKind,
+    inputs: Vec<FnInOutRef>,
+    enable: Option<FnInOutRef>,
+    tx_send: Sender<PointType>,
+    sent: Vec<Option<PointType>>,
+}
+///
+/// 
+static COUNT: AtomicUsize = AtomicUsize::new(1);
+///
+/// 
+impl FnToMultiQueue {
+    ///
+    /// creates new instance of the FnToMultiQueue
+    /// - id - just for proper debugging
+    /// - inputs - the points to be exported, at least one required
+    /// - enable - optional input, points are sent only while it is true
+    /// - send - the queue to export points into
+    pub fn new(parent: impl Into<String>, inputs: Vec<FnInOutRef>, enable: Option<FnInOutRef>, send: Sender<PointType>) -> Self {
+        let id = format!("{}/FnToMultiQueue{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed));
+        if inputs.is_empty() {
+            panic!("{}.new | At least one input required", id);
+        }
+        Self {  
+            id,
+            kind: FnKind::Fn,
+            sent: vec![None; inputs.len()],
+            inputs,
+            enable,
+            tx_send: send,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnToMultiQueue {
+    //
+    fn add(&mut self, _: PointType) {
+        panic!("{}.add | method is not used", self.id);
+    }
+}
+///
+/// 
+impl FnOut for FnToMultiQueue {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs: Vec<String> = self.inputs.iter().flat_map(|input| input.borrow().inputs()).collect();
+        if let Some(enable) = &self.enable {
+            inputs.extend(enable.borrow().inputs());
+        }
+        inputs
+    }
+    //
+    // Sends the input points into the queue, returns the last one
+    fn out(&mut self) -> PointType {
+        let enabled = match &self.enable {
+            Some(enable) => to_bool(&self.id, &enable.borrow_mut().out()),
+            None => true,
+        };
+        let points: Vec<PointType> = self.inputs.iter().map(|input| input.borrow_mut().out()).collect();
+        for (point, sent) in points.iter().zip(self.sent.iter_mut()) {
+            let changed = sent.as_ref() != Some(point);
+            if enabled && (changed || self.enable.is_some()) {
+                match self.tx_send.send(point.clone()) {
+                    Ok(_) => {
+                        debug!("{}.out | Sent point: {:?}", self.id, point);
+                        *sent = Some(point.clone());
+                    }
+                    Err(err) => {
+                        error!("{}.out | Send error: {:?}\n\tpoint: {:?}", self.id, err, point);
+                    }
+                };
+            }
+        }
+        points.into_iter().last().unwrap_or_else(|| panic!("{}.out | At least one input required", self.id))
+    }
+    //
+    fn reset(&mut self) {
+        self.sent.fill(None);
+        for input in &self.inputs {
+            input.borrow_mut().reset();
+        }
+        if let Some(enable) = &self.enable {
+            enable.borrow_mut().reset();
+        }
+    }
+}
+///
+/// 
+impl FnInOut for FnToMultiQueue {}
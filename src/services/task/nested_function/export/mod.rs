@@ -1 +1,2 @@
 pub mod fn_to_api_queue;
+pub mod fn_to_multi_queue;
@@ -28,6 +28,13 @@ pub trait FnOut: std::fmt::Debug {
     /// Returns names of inputs it depending on
     fn inputs(&self) -> Vec<String>;
     ///
+    /// Returns true while the out value is the initial placeholder of the input,
+    /// no value received yet or the input was reset
+    /// - used by the functions consuming the samples, to skip the placeholder
+    fn is_initial(&self) -> bool {
+        false
+    }
+    ///
     /// used only for FnVar
     /// evaluate calculations
     fn eval(&mut self) {
@@ -40,6 +47,15 @@ pub trait FnOut: std::fmt::Debug {
     ///
     /// resets self state to the initial, calls reset method of all inputs 
     fn reset(&mut self);
+    ///
+    /// Returns the current state to be stored on the disk,
+    /// None if the function has no state to be retained
+    fn retained(&self) -> Option<PointType> {
+        None
+    }
+    ///
+    /// Restores the state, previously returned by [retained](FnOut::retained)
+    fn restore(&mut self, _state: PointType) {}
 }
 ///
 /// Interface for nested function
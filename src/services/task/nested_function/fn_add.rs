@@ -1,14 +1,20 @@
 use log::{debug, trace};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use crate::{
-    core_::{point::point_type::PointType, types::{type_of::DebugTypeOf, fn_in_out_ref::FnInOutRef}},
+    core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef},
     services::task::nested_function::{
         fn_::{FnInOut, FnIn, FnOut},
         fn_kind::FnKind,
+        type_promotion::{apply, promote, Promoted},
     },
 };
 ///
 /// Function do Add of input1 and input2
+/// - Bool & Bool returns Bool, logical OR
+/// - other input types promoted to the common type Int -> Real -> Double, see [promote]
+/// - Bool input treated as Int
+/// - the inputs having different Cot returns 0 with Status::Invalid
+/// - Int overflow or not finite Real / Double result returns 0 with Status::Invalid
 #[derive(Debug)]
 pub struct FnAdd {
     id: String,
@@ -54,25 +60,25 @@ impl FnOut for FnAdd {
     //
     //
     fn out(&mut self) -> PointType {
-        // TODO Add overflow check
         let value1 = self.input1.borrow_mut().out();
         debug!("{}.out | value1: {:?}", self.id, &value1);
         let value2 = self.input2.borrow_mut().out();
         debug!("{}.out | value2: {:?}", self.id, &value2);
-        let out = match value1 {
-            PointType::Bool(value1) => {
-                PointType::Bool(value1 + value2.as_bool())
+        let out = match (value1, value2) {
+            (PointType::Bool(value1), PointType::Bool(value2)) => {
+                PointType::Bool(apply(&self.id, "Add", value1, value2, |value1, value2| Some(value1 + value2)))
             }
-            PointType::Int(value1) => {
-                PointType::Int(value1 + value2.as_int())
+            (value1, value2) => match promote(&self.id, value1, value2) {
+                Promoted::Int(value1, value2) => {
+                    PointType::Int(apply(&self.id, "Add", value1, value2, |value1, value2| value1.checked_add(value2)))
+                }
+                Promoted::Real(value1, value2) => {
+                    PointType::Real(apply(&self.id, "Add", value1, value2, |value1, value2| Some(value1 + value2).filter(|value| value.is_finite())))
+                }
+                Promoted::Double(value1, value2) => {
+                    PointType::Double(apply(&self.id, "Add", value1, value2, |value1, value2| Some(value1 + value2).filter(|value| value.is_finite())))
+                }
             }
-            PointType::Real(value1) => {
-                PointType::Real(value1 + value2.as_real())
-            }
-            PointType::Double(value1) => {
-                PointType::Double(value1 + value2.as_double())
-            }
-            _ => panic!("{}.out | {:?} type is not supported: {:?}", self.id, value1.print_type_of(), value1),
         };
         trace!("{}.out | out: {:?}", self.id, &out);
         out
@@ -0,0 +1,73 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_logic::{out_point, to_bool}};
+///
+/// Function | Logical AND
+/// FnAnd ( input1, input2, ... inputN ) === input1 && input2 && ... inputN
+/// - Int / Real / Double inputs coerced to Bool as non-zero
+#[derive(Debug)]
+pub struct FnAnd {
+    id: String,
+    kind: FnKind,
+    inputs: Vec<FnInOutRef>,
+}
+///
+/// 
+impl FnAnd {
+    ///
+    /// Creates new instance of the FnAnd
+    /// - inputs - at least one input required
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, inputs: Vec<FnInOutRef>) -> Self {
+        let id = format!("{}/FnAnd{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed));
+        if inputs.is_empty() {
+            panic!("{}.new | At least one input required", id);
+        }
+        Self { 
+            id,
+            kind: FnKind::Fn,
+            inputs,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnAnd {}
+///
+///
+impl FnOut for FnAnd {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.inputs.iter().flat_map(|input| input.borrow().inputs()).collect()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let points: Vec<PointType> = self.inputs.iter().map(|input| input.borrow_mut().out()).collect();
+        let value = points.iter().all(|point| to_bool(&self.id, point));
+        debug!("{}.out | value: {:?}", self.id, &value);
+        out_point(&self.id, &points, value)
+    }
+    //
+    //
+    fn reset(&mut self) {
+        for input in &self.inputs {
+            input.borrow_mut().reset();
+        }
+    }
+}
+///
+/// 
+impl FnInOut for FnAnd {}
+///
+/// Global static counter of FnAnd instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
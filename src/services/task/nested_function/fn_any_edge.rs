@@ -0,0 +1,87 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{
+    cot::cot::Cot, point::{point::Point, point_type::PointType}, types::{bool::Bool, fn_in_out_ref::FnInOutRef},
+};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_logic::to_bool};
+///
+/// Function | Any edge detection
+/// Returns true for the single evaluation when the input value changes
+/// - Bool - both rising & falling edges
+/// - Int / Real / Double - any change of the value, so mode switches can be detected
+/// - before the first change, the input assumed to be false / 0
+#[derive(Debug)]
+pub struct FnAnyEdge {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+    prev: Option<PointType>,
+}
+///
+/// 
+impl FnAnyEdge {
+    ///
+    /// Creates new instance of the FnAnyEdge
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef) -> Self {
+        Self { 
+            id: format!("{}/FnAnyEdge{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            input,
+            prev: None,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnAnyEdge {}
+///
+///
+impl FnOut for FnAnyEdge {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        let value = match &self.prev {
+            Some(prev) => prev.value() != point.value(),
+            None => to_bool(&self.id, &point),
+        };
+        debug!("{}.out | prev: {:?}   |   value: {:?}", self.id, self.prev, value);
+        let out = PointType::Bool(
+            Point::<Bool> {
+                tx_id: *point.tx_id(),
+                name: format!("{}.out", self.id),
+                value: Bool(value),
+                status: point.status(),
+                cot: Cot::Inf,
+                timestamp: point.timestamp(),
+            }
+        );
+        self.prev = Some(point);
+        out
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.prev = None;
+        self.input.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnAnyEdge {}
+///
+/// Global static counter of FnAnyEdge instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,128 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{point::point_type::PointType, types::{fn_in_out_ref::FnInOutRef, type_of::DebugTypeOf}};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_compare::to_double, fn_kind::FnKind, fn_status::propagate};
+///
+/// Function | Limits the input into the [min, max] range
+/// - [min] & [max] are optional inputs, so the one-sided limit is possible
+/// - Int / Real / Double input, the output has the type of the input
+/// - for Int input the fractional limits are rounded into the range: min - up, max - down
+/// - status - the worst of the all inputs
+#[derive(Debug)]
+pub struct FnClamp {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+    min: Option<FnInOutRef>,
+    max: Option<FnInOutRef>,
+}
+///
+/// 
+impl FnClamp {
+    ///
+    /// Creates new instance of the FnClamp
+    /// - input - the value to be limited
+    /// - min - optional low limit
+    /// - max - optional high limit
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef, min: Option<FnInOutRef>, max: Option<FnInOutRef>) -> Self {
+        Self { 
+            id: format!("{}/FnClamp{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            input,
+            min,
+            max,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnClamp {}
+///
+///
+impl FnOut for FnClamp {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = self.input.borrow().inputs();
+        if let Some(min) = &self.min {
+            inputs.extend(min.borrow().inputs());
+        }
+        if let Some(max) = &self.max {
+            inputs.extend(max.borrow().inputs());
+        }
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        let mut limit = None;
+        let input = to_double(&self.id, &point);
+        let min_point = self.min.as_ref().map(|min| min.borrow_mut().out());
+        if let Some(min) = &min_point {
+            let min = to_double(&self.id, min);
+            if input < min {
+                limit = Some(min);
+            }
+        }
+        let max_point = self.max.as_ref().map(|max| max.borrow_mut().out());
+        if let Some(max) = &max_point {
+            let max = to_double(&self.id, max);
+            if input > max {
+                limit = Some(max);
+            }
+        }
+        debug!("{}.out | input: {:?}   |   limit: {:?}", self.id, input, limit);
+        let out = match point {
+            PointType::Int(mut point) => {
+                if let Some(limit) = limit {
+                    // the nearest integer inside the range, ceil of the min limit, floor of the max limit
+                    point.value = (if limit > input {limit.ceil()} else {limit.floor()}) as i64;
+                }
+                point.name = format!("{}.out", self.id);
+                PointType::Int(point)
+            }
+            PointType::Real(mut point) => {
+                if let Some(limit) = limit {
+                    point.value = limit as f32;
+                }
+                point.name = format!("{}.out", self.id);
+                PointType::Real(point)
+            }
+            PointType::Double(mut point) => {
+                if let Some(limit) = limit {
+                    point.value = limit;
+                }
+                point.name = format!("{}.out", self.id);
+                PointType::Double(point)
+            }
+            _ => panic!("{}.out | {:?} type is not supported: {:?}", self.id, point.print_type_of(), point),
+        };
+        propagate(out, min_point.iter().chain(&max_point))
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.input.borrow_mut().reset();
+        if let Some(min) = &self.min {
+            min.borrow_mut().reset();
+        }
+        if let Some(max) = &self.max {
+            max.borrow_mut().reset();
+        }
+    }
+}
+///
+/// 
+impl FnInOut for FnClamp {}
+///
+/// Global static counter of FnClamp instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
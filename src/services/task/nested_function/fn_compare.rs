@@ -0,0 +1,59 @@
+use crate::core_::{
+    aprox_eq::aprox_eq::AproxEq, cot::cot::Cot, point::{point::Point, point_type::PointType}, status::status::Status, types::{bool::Bool, type_of::DebugTypeOf},
+};
+use super::fn_status::propagate;
+///
+/// Common part of the comparison functions (gt, ge, lt, le, eq, ne)
+///  - String can be compared with String only, lexicographically
+///  - Bool / Int / Real / Double compared as Double
+/// - [self_id] - the id of the caller, just for proper debugging
+pub fn compare(self_id: &str, point1: &PointType, point2: &PointType) -> Option<std::cmp::Ordering> {
+    match (point1, point2) {
+        (PointType::String(p1), PointType::String(p2)) => Some(p1.value.cmp(&p2.value)),
+        (PointType::String(_), _) | (_, PointType::String(_)) => {
+            panic!("{}.out | String can be compared with String only, but found: {:?} & {:?}", self_id, point1, point2)
+        }
+        _ => to_double(self_id, point1).partial_cmp(&to_double(self_id, point2)),
+    }
+}
+///
+/// Equality of the two points
+///  - [decimals] - if specified, Real / Double values are compared approximately
+///  up to the given number of decimals, using [AproxEq]
+pub fn equals(self_id: &str, point1: &PointType, point2: &PointType, decimals: Option<usize>) -> bool {
+    match (point1, point2, decimals) {
+        (PointType::Real(p1), PointType::Real(p2), Some(decimals)) => p1.value.aprox_eq(p2.value, decimals),
+        (PointType::Real(_) | PointType::Double(_), _, Some(decimals)) | (_, PointType::Real(_) | PointType::Double(_), Some(decimals)) => {
+            to_double(self_id, point1).aprox_eq(to_double(self_id, point2), decimals)
+        }
+        _ => compare(self_id, point1, point2) == Some(std::cmp::Ordering::Equal),
+    }
+}
+///
+/// Returns Bool point holding the result of the comparison
+///  - status, tx_id & timestamp - propagated from the both inputs, see [propagate]
+pub fn out_point(self_id: &str, point1: &PointType, point2: &PointType, value: bool) -> PointType {
+    let out = PointType::Bool(
+        Point::new(*point1.tx_id(), &format!("{}.out", self_id), Bool(value), Status::Ok, Cot::Inf, point1.timestamp())
+    );
+    propagate(out, [point1, point2])
+}
+///
+/// Returns the value of the point as Double, Bool treated as 0.0 / 1.0
+pub fn to_double(self_id: &str, point: &PointType) -> f64 {
+    match point {
+        PointType::Bool(point) => {
+            if point.value.0 {1.0} else {0.0}
+        }
+        PointType::Int(point) => {
+            point.value as f64
+        }
+        PointType::Real(point) => {
+            point.value as f64
+        }
+        PointType::Double(point) => {
+            point.value
+        }
+        _ => panic!("{}.out | {:?} type is not supported: {:?}", self_id, point.print_type_of(), point),
+    }
+}
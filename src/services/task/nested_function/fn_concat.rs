@@ -0,0 +1,97 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{cot::cot::Cot, point::{point::Point, point_type::PointType}, status::status::Status, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_status::propagate};
+///
+/// Function | Concatenates the inputs into the String
+/// FnConcat ( input1, input2, ... inputN ) === input1 + separator + input2 + ... + inputN
+/// - Bool / Int / Real / Double inputs converted into the String
+/// - status - the worst of the all inputs
+/// - tx_id & timestamp - from the latest of the all inputs
+#[derive(Debug)]
+pub struct FnConcat {
+    id: String,
+    kind: FnKind,
+    inputs: Vec<FnInOutRef>,
+    separator: String,
+}
+///
+/// 
+impl FnConcat {
+    ///
+    /// Creates new instance of the FnConcat
+    /// - inputs - at least one input required
+    /// - separator - inserted between the inputs
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, inputs: Vec<FnInOutRef>, separator: impl Into<String>) -> Self {
+        let id = format!("{}/FnConcat{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed));
+        if inputs.is_empty() {
+            panic!("{}.new | At least one input required", id);
+        }
+        Self { 
+            id,
+            kind: FnKind::Fn,
+            inputs,
+            separator: separator.into(),
+        }
+    }
+    ///
+    /// Returns the value of the point as String
+    fn to_string(point: &PointType) -> String {
+        match point {
+            PointType::Bool(point) => point.value.0.to_string(),
+            PointType::Int(point) => point.value.to_string(),
+            PointType::Real(point) => point.value.to_string(),
+            PointType::Double(point) => point.value.to_string(),
+            PointType::String(point) => point.value.clone(),
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnConcat {}
+///
+///
+impl FnOut for FnConcat {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = vec![];
+        for input in &self.inputs {
+            inputs.extend(input.borrow().inputs());
+        }
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let points: Vec<PointType> = self.inputs.iter().map(|input| input.borrow_mut().out()).collect();
+        let value = points.iter().map(Self::to_string).collect::<Vec<String>>().join(&self.separator);
+        debug!("{}.out | value: {:?}", self.id, value);
+        let first = &points[0];
+        let out = PointType::String(
+            Point::new(*first.tx_id(), &format!("{}.out", self.id), value, Status::Ok, Cot::Inf, first.timestamp())
+        );
+        propagate(out, &points)
+    }
+    //
+    //
+    fn reset(&mut self) {
+        for input in &self.inputs {
+            input.borrow_mut().reset();
+        }
+    }
+}
+///
+/// 
+impl FnInOut for FnConcat {}
+///
+/// Global static counter of FnConcat instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,74 @@
+use log::error;
+use std::{fmt::Debug, str::FromStr};
+use crate::core_::status::status::Status;
+///
+/// Common part of the type conversion functions (ToInt, ToReal, ToDouble, ToBool, ToString)
+/// Parses String value into the T
+///  - numeric String is also accepted for Bool: non-zero -> true
+///  - numeric String with fraction is also accepted for Int: truncated
+///  - if parsing failed, the default with Status::Invalid returned, error logged
+/// - [self_id] - the id of the caller, just for proper debugging
+pub fn parse<T: FromStr + Default + FromDouble>(self_id: &str, value: &str, status: Status) -> (T, Status) where T::Err: Debug {
+    let value = value.trim();
+    match value.parse::<T>() {
+        Ok(value) => (value, status),
+        Err(err) => match value.parse::<f64>() {
+            Ok(double) if double.is_finite() => match T::from_double(double) {
+                Some(value) => (value, status),
+                None => {
+                    error!("{}.out | Error parsing {:?} into {}: {:?}", self_id, value, std::any::type_name::<T>(), err);
+                    (T::default(), Status::Invalid)
+                }
+            }
+            _ => {
+                error!("{}.out | Error parsing {:?} into {}: {:?}", self_id, value, std::any::type_name::<T>(), err);
+                (T::default(), Status::Invalid)
+            }
+        }
+    }
+}
+///
+/// Converts Real / Double value into Int, the common rule of all the functions converting into Int
+///  - the fraction is truncated: 2.9 -> 2, -2.9 -> -2
+///  - out of the Int range saturated, NaN -> 0
+pub fn to_int(value: f64) -> i64 {
+    value.trunc() as i64
+}
+///
+/// Fallback conversion of the numeric String, used by [parse]
+pub trait FromDouble: Sized {
+    fn from_double(value: f64) -> Option<Self>;
+}
+///
+/// 
+impl FromDouble for bool {
+    fn from_double(value: f64) -> Option<Self> {
+        Some(value != 0.0)
+    }
+}
+///
+/// 
+impl FromDouble for i64 {
+    fn from_double(value: f64) -> Option<Self> {
+        let value = value.trunc();
+        if value >= i64::MIN as f64 && value < i64::MAX as f64 {
+            Some(value as i64)
+        } else {
+            None
+        }
+    }
+}
+///
+/// 
+impl FromDouble for f32 {
+    fn from_double(value: f64) -> Option<Self> {
+        Some(value as f32)
+    }
+}
+///
+/// 
+impl FromDouble for f64 {
+    fn from_double(value: f64) -> Option<Self> {
+        Some(value)
+    }
+}
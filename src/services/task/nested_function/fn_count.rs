@@ -1,5 +1,5 @@
 use std::sync::atomic::{AtomicUsize, Ordering};
-use log::trace;
+use log::{error, trace};
 use crate::core_::{
     cot::cot::Cot, point::{point::Point, point_type::PointType}, types::{fn_in_out_ref::FnInOutRef, type_of::DebugTypeOf}
 };
@@ -76,6 +76,17 @@ impl FnOut for FnCount {
         self.count = self.initial;
         self.input.borrow_mut().reset();
     }
+    //
+    fn retained(&self) -> Option<PointType> {
+        Some(PointType::Double(Point::new_double(0, &format!("{}.out", self.id), self.count)))
+    }
+    //
+    fn restore(&mut self, state: PointType) {
+        match state {
+            PointType::Double(state) => self.count = state.value,
+            _ => error!("{}.restore | Double state expected, but found: {:?}", self.id, state),
+        }
+    }
 }
 ///
 /// 
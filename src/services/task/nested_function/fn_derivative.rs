@@ -0,0 +1,101 @@
+use chrono::{DateTime, Utc};
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{cot::cot::Cot, point::{point::Point, point_type::PointType}, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_compare::to_double, fn_kind::FnKind};
+///
+/// Function | Rate of change of the input, per second
+/// - time measured by the Point.timestamp, not by the wall-clock,
+/// so the result is correct if points arrive in bursts
+/// - the rate calculated between the two latest samples, the first sample gives 0.0
+/// - samples with timestamp not newer then the previous sample are ignored, the previous rate returned
+/// - Bool / Int / Real / Double input calculated as Double
+/// - the initial placeholder of the input is not used as a sample
+#[derive(Debug)]
+pub struct FnDerivative {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+    prev: Option<(f64, DateTime<Utc>)>,
+    rate: f64,
+}
+///
+/// 
+impl FnDerivative {
+    ///
+    /// Creates new instance of the FnDerivative
+    /// - input - the value to be differentiated
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef) -> Self {
+        Self { 
+            id: format!("{}/FnDerivative{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            input,
+            prev: None,
+            rate: 0.0,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnDerivative {}
+///
+///
+impl FnOut for FnDerivative {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        let received = !self.input.borrow().is_initial();
+        let value = to_double(&self.id, &point);
+        let timestamp = point.timestamp();
+        if received {
+            match self.prev {
+                Some((prev_value, prev_timestamp)) => {
+                    if timestamp > prev_timestamp {
+                        let dt = timestamp.signed_duration_since(prev_timestamp).to_std().unwrap_or_default().as_secs_f64();
+                        self.rate = (value - prev_value) / dt;
+                        self.prev = Some((value, timestamp));
+                    }
+                }
+                None => self.prev = Some((value, timestamp)),
+            }
+        }
+        debug!("{}.out | input: {:?}   |   rate: {:?}", self.id, value, self.rate);
+        PointType::Double(
+            Point {
+                tx_id: *point.tx_id(),
+                name: format!("{}.out", self.id),
+                value: self.rate,
+                status: point.status(),
+                cot: Cot::Inf,
+                timestamp,
+            }
+        )
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.rate = 0.0;
+        self.prev = None;
+        self.input.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnDerivative {}
+///
+/// Global static counter of FnDerivative instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,47 @@
+use crate::core_::state::switch_state::{Switch, SwitchCondition, SwitchState};
+///
+/// States of the edge detection (risingEdge, fallingEdge)
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
+pub enum EdgeState {
+    Low,
+    Rising,
+    High,
+    Falling,
+}
+///
+/// Returns edge detection state machine:
+///  - Low -> Rising -> High -> Falling -> Low
+///  - Rising / Falling state holds for the single evaluation only
+pub fn edge_state() -> SwitchState<EdgeState, bool> {
+    SwitchState::new(
+        EdgeState::Low,
+        vec![
+            Switch {
+                state: EdgeState::Low,
+                conditions: vec![
+                    SwitchCondition { condition: Box::new(|value| {value}), target: EdgeState::Rising },
+                ],
+            },
+            Switch {
+                state: EdgeState::Rising,
+                conditions: vec![
+                    SwitchCondition { condition: Box::new(|value| {value}), target: EdgeState::High },
+                    SwitchCondition { condition: Box::new(|value: bool| {!value}), target: EdgeState::Falling },
+                ],
+            },
+            Switch {
+                state: EdgeState::High,
+                conditions: vec![
+                    SwitchCondition { condition: Box::new(|value: bool| {!value}), target: EdgeState::Falling },
+                ],
+            },
+            Switch {
+                state: EdgeState::Falling,
+                conditions: vec![
+                    SwitchCondition { condition: Box::new(|value| {value}), target: EdgeState::Rising },
+                    SwitchCondition { condition: Box::new(|value: bool| {!value}), target: EdgeState::Low },
+                ],
+            },
+        ],
+    )
+}
@@ -0,0 +1,75 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_compare::{equals, out_point}, fn_kind::FnKind};
+///
+/// Function | Equal to
+/// FnEq ( input1, input2 ) === input1.value == input2.value
+/// - Real / Double compared approximately if [decimals] specified
+#[derive(Debug)]
+pub struct FnEq {
+    id: String,
+    kind: FnKind,
+    input1: FnInOutRef,
+    input2: FnInOutRef,
+    decimals: Option<usize>,
+}
+///
+/// 
+impl FnEq {
+    ///
+    /// Creates new instance of the FnEq
+    /// - decimals - optional precision (epsilon) in decimal digits for Real / Double comparison
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef, decimals: Option<usize>) -> Self {
+        Self { 
+            id: format!("{}/FnEq{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            input1,
+            input2,
+            decimals,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnEq {}
+///
+///
+impl FnOut for FnEq {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = self.input1.borrow().inputs();
+        inputs.extend(self.input2.borrow().inputs());
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point1 = self.input1.borrow_mut().out();     
+        let point2 = self.input2.borrow_mut().out();    
+        let value = equals(&self.id, &point1, &point2, self.decimals);
+        debug!("{}.out | input.out: {:?}", self.id, &value);
+        out_point(&self.id, &point1, &point2, value)
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.input1.borrow_mut().reset();
+        self.input2.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnEq {}
+///
+/// Global static counter of FnEq instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,412 @@
+use indexmap::IndexMap;
+use log::{debug, warn};
+use std::{cell::RefCell, rc::Rc};
+use crate::{
+    conf::fn_::fn_conf_keywd::FnConfPointType,
+    core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef},
+};
+use super::{
+    fn_add::FnAdd, fn_and::FnAnd, fn_const::FnConst, fn_div::FnDiv, fn_eq::FnEq, fn_ge::FnGe, fn_gt::FnGt,
+    fn_le::FnLe, fn_lt::FnLt, fn_mod::FnMod, fn_mul::FnMul, fn_ne::FnNe, fn_not::FnNot, fn_or::FnOr, fn_pow::FnPow, fn_sub::FnSub,
+};
+///
+/// Unary operator of the [Expr]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnaryOp {
+    /// '-' - negation, built as 0 - x
+    Neg,
+    /// '!' - logical not
+    Not,
+}
+///
+/// Binary operator of the [Expr]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BinaryOp {
+    Add, Sub, Mul, Div, Mod, Pow,
+    Gt, Ge, Lt, Le, Eq, Ne,
+    And, Or,
+}
+///
+///
+impl BinaryOp {
+    ///
+    /// Returns true if operator is arithmetic
+    fn is_arithmetic(&self) -> bool {
+        matches!(self, BinaryOp::Add | BinaryOp::Sub | BinaryOp::Mul | BinaryOp::Div | BinaryOp::Mod | BinaryOp::Pow)
+    }
+    ///
+    /// Returns true if operator is logical
+    fn is_logical(&self) -> bool {
+        matches!(self, BinaryOp::And | BinaryOp::Or)
+    }
+}
+///
+/// Formula of the 'expr' function, parsed into the tree
+/// ```
+/// "(a * 2.5 + b) / c > limit && !blocked"
+/// ```
+/// Operators by the priority, from the lowest:
+///  - `||`
+///  - `&&`
+///  - `==`, `!=`, `<`, `<=`, `>`, `>=` - can't be chained
+///  - `+`, `-`
+///  - `*`, `/`, `%`
+///  - unary `-`, `!`
+///  - `^` - power, right associative
+///
+/// Operands:
+///  - Int literal: `12`, Double literal: `2.5`, `1e-3`, Bool literal: `true`, `false`
+///  - variable: `[A-Za-z_][A-Za-z0-9_]*` - the name of the function input
+///  - `( ... )`
+#[derive(Debug, Clone, PartialEq)]
+pub enum Expr {
+    Int(i64),
+    Double(f64),
+    Bool(bool),
+    Var(String),
+    Unary(UnaryOp, Box<Expr>),
+    Binary(BinaryOp, Box<Expr>, Box<Expr>),
+}
+///
+/// The type of the [Expr] node, known at startup
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ExprType {
+    String,
+    Numeric,
+    Unknown,
+}
+///
+///
+impl Expr {
+    ///
+    /// Parses the formula, returns the error containing the position of the wrong token
+    pub fn parse(formula: &str) -> Result<Expr, String> {
+        let tokens = tokenize(formula)?;
+        let mut parser = Parser { tokens, pos: 0, len: formula.len() };
+        let expr = parser.or()?;
+        match parser.peek() {
+            Some((token, pos)) => Err(format!("unexpected {:?} at {}", token, pos)),
+            None => Ok(expr),
+        }
+    }
+    ///
+    /// Returns the names of the all variables used in the formula
+    pub fn vars(&self) -> Vec<String> {
+        match self {
+            Expr::Int(_) | Expr::Double(_) | Expr::Bool(_) => vec![],
+            Expr::Var(name) => vec![name.clone()],
+            Expr::Unary(_, expr) => expr.vars(),
+            Expr::Binary(_, left, right) => {
+                let mut vars = left.vars();
+                vars.extend(right.vars());
+                vars
+            }
+        }
+    }
+    ///
+    /// Checks the all variables are defined & the types of the operands are allowed
+    ///  - [types] - the types of the variables, Any / Unknown if the type is defined at runtime only
+    pub fn check(&self, types: &IndexMap<String, FnConfPointType>) -> Result<(), String> {
+        self.type_of(types).map(|_| ())
+    }
+    //
+    //
+    fn type_of(&self, types: &IndexMap<String, FnConfPointType>) -> Result<ExprType, String> {
+        match self {
+            Expr::Int(_) | Expr::Double(_) | Expr::Bool(_) => Ok(ExprType::Numeric),
+            Expr::Var(name) => match types.get(name) {
+                Some(FnConfPointType::String) => Ok(ExprType::String),
+                Some(FnConfPointType::Any | FnConfPointType::Unknown) => Ok(ExprType::Unknown),
+                Some(_) => Ok(ExprType::Numeric),
+                None => Err(format!("variable '{}' - not found in the inputs: {:?}", name, types.keys().collect::<Vec<_>>())),
+            },
+            Expr::Unary(op, expr) => match expr.type_of(types)? {
+                ExprType::String => Err(format!("operator {:?} can't be applied to String '{}'", op, expr)),
+                _ => Ok(ExprType::Numeric),
+            },
+            Expr::Binary(op, left, right) => {
+                let left_type = left.type_of(types)?;
+                let right_type = right.type_of(types)?;
+                if op.is_arithmetic() || op.is_logical() {
+                    for (expr, type_) in [(left, left_type), (right, right_type)] {
+                        if type_ == ExprType::String {
+                            return Err(format!("operator {:?} can't be applied to String '{}'", op, expr));
+                        }
+                    }
+                } else if matches!((left_type, right_type), (ExprType::String, ExprType::Numeric) | (ExprType::Numeric, ExprType::String)) {
+                    return Err(format!("String can be compared with String only, but found '{}' {:?} '{}'", left, op, right));
+                }
+                Ok(ExprType::Numeric)
+            }
+        }
+    }
+    ///
+    /// Builds the nested functions tree of the formula
+    ///  - [inputs] - the nodes of the variables, the variable used multiple times shares the same node
+    pub fn build(&self, parent: &str, tx_id: usize, inputs: &IndexMap<String, FnInOutRef>) -> FnInOutRef {
+        for name in inputs.keys() {
+            if !self.vars().contains(name) {
+                warn!("{}.build | Input '{}' - not used in the formula '{}'", parent, name, self);
+            }
+        }
+        let expr = self.build_node(parent, tx_id, inputs);
+        debug!("{}.build | formula '{}' - done", parent, self);
+        expr
+    }
+    //
+    //
+    fn build_node(&self, parent: &str, tx_id: usize, inputs: &IndexMap<String, FnInOutRef>) -> FnInOutRef {
+        match self {
+            Expr::Int(value) => node(FnConst::new(parent, value.to_point(tx_id, &format!("const int '{}'", value)))),
+            Expr::Double(value) => node(FnConst::new(parent, value.to_point(tx_id, &format!("const double '{}'", value)))),
+            Expr::Bool(value) => node(FnConst::new(parent, value.to_point(tx_id, &format!("const bool '{}'", value)))),
+            Expr::Var(name) => match inputs.get(name) {
+                Some(input) => input.clone(),
+                None => panic!("{}.build | Input '{}' - not found", parent, name),
+            },
+            Expr::Unary(op, expr) => {
+                let input = expr.build_node(parent, tx_id, inputs);
+                match op {
+                    UnaryOp::Neg => {
+                        let zero: PointType = 0.to_point(tx_id, "const int '0'");
+                        node(FnSub::new(parent, node(FnConst::new(parent, zero)), input))
+                    }
+                    UnaryOp::Not => node(FnNot::new(parent, input)),
+                }
+            }
+            Expr::Binary(op, left, right) => {
+                let input1 = left.build_node(parent, tx_id, inputs);
+                let input2 = right.build_node(parent, tx_id, inputs);
+                match op {
+                    BinaryOp::Add => node(FnAdd::new(parent, input1, input2)),
+                    BinaryOp::Sub => node(FnSub::new(parent, input1, input2)),
+                    BinaryOp::Mul => node(FnMul::new(parent, input1, input2)),
+                    BinaryOp::Div => node(FnDiv::new(parent, input1, input2)),
+                    BinaryOp::Mod => node(FnMod::new(parent, input1, input2)),
+                    BinaryOp::Pow => node(FnPow::new(parent, input1, input2)),
+                    BinaryOp::Gt => node(FnGt::new(parent, input1, input2)),
+                    BinaryOp::Ge => node(FnGe::new(parent, input1, input2)),
+                    BinaryOp::Lt => node(FnLt::new(parent, input1, input2)),
+                    BinaryOp::Le => node(FnLe::new(parent, input1, input2)),
+                    BinaryOp::Eq => node(FnEq::new(parent, input1, input2, None)),
+                    BinaryOp::Ne => node(FnNe::new(parent, input1, input2, None)),
+                    BinaryOp::And => node(FnAnd::new(parent, vec![input1, input2])),
+                    BinaryOp::Or => node(FnOr::new(parent, vec![input1, input2])),
+                }
+            }
+        }
+    }
+}
+///
+///
+impl std::fmt::Display for Expr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Expr::Int(value) => write!(f, "{}", value),
+            Expr::Double(value) => write!(f, "{:?}", value),
+            Expr::Bool(value) => write!(f, "{}", value),
+            Expr::Var(name) => write!(f, "{}", name),
+            Expr::Unary(UnaryOp::Neg, expr) => write!(f, "-{}", expr),
+            Expr::Unary(UnaryOp::Not, expr) => write!(f, "!{}", expr),
+            Expr::Binary(op, left, right) => {
+                let op = match op {
+                    BinaryOp::Add => "+", BinaryOp::Sub => "-", BinaryOp::Mul => "*", BinaryOp::Div => "/", BinaryOp::Mod => "%", BinaryOp::Pow => "^",
+                    BinaryOp::Gt => ">", BinaryOp::Ge => ">=", BinaryOp::Lt => "<", BinaryOp::Le => "<=", BinaryOp::Eq => "==", BinaryOp::Ne => "!=",
+                    BinaryOp::And => "&&", BinaryOp::Or => "||",
+                };
+                write!(f, "({} {} {})", left, op, right)
+            }
+        }
+    }
+}
+///
+/// Wraps the function into the FnInOutRef
+fn node(function: impl super::fn_::FnInOut + 'static) -> FnInOutRef {
+    Rc::new(RefCell::new(Box::new(function)))
+}
+///
+/// Token of the formula
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Int(i64),
+    Double(f64),
+    Ident(String),
+    Op(&'static str),
+    Open,
+    Close,
+}
+///
+/// Splits the formula into the tokens & it's positions
+fn tokenize(formula: &str) -> Result<Vec<(Token, usize)>, String> {
+    const OPS: [&str; 17] = ["||", "&&", "==", "!=", "<=", ">=", "<", ">", "+", "-", "*", "/", "%", "^", "!", "(", ")"];
+    let mut tokens = vec![];
+    let chars: Vec<(usize, char)> = formula.char_indices().collect();
+    let mut i = 0;
+    while i < chars.len() {
+        let (pos, c) = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+        } else if c.is_ascii_digit() || (c == '.' && chars.get(i + 1).map_or(false, |(_, c)| c.is_ascii_digit())) {
+            let start = i;
+            while i < chars.len() && (chars[i].1.is_ascii_alphanumeric() || chars[i].1 == '.'
+                || ((chars[i].1 == '-' || chars[i].1 == '+') && matches!(chars[i - 1].1, 'e' | 'E'))) {
+                i += 1;
+            }
+            let end = chars.get(i).map_or(formula.len(), |(pos, _)| *pos);
+            let literal = &formula[pos..end];
+            let token = match literal.parse::<i64>() {
+                Ok(value) => Token::Int(value),
+                Err(_) => match literal.parse::<f64>() {
+                    Ok(value) => Token::Double(value),
+                    Err(_) => return Err(format!("invalid number '{}' at {}", literal, chars[start].0)),
+                },
+            };
+            tokens.push((token, pos));
+        } else if c.is_ascii_alphabetic() || c == '_' {
+            while i < chars.len() && (chars[i].1.is_ascii_alphanumeric() || chars[i].1 == '_') {
+                i += 1;
+            }
+            let end = chars.get(i).map_or(formula.len(), |(pos, _)| *pos);
+            tokens.push((Token::Ident(formula[pos..end].to_owned()), pos));
+        } else {
+            let op = match OPS.iter().find(|op| formula[pos..].starts_with(*op)) {
+                Some(op) => *op,
+                None => return Err(format!("unexpected '{}' at {}", c, pos)),
+            };
+            match op {
+                "(" => tokens.push((Token::Open, pos)),
+                ")" => tokens.push((Token::Close, pos)),
+                _ => tokens.push((Token::Op(op), pos)),
+            }
+            i += op.len();
+        }
+    }
+    Ok(tokens)
+}
+///
+/// Recursive descent parser of the formula
+struct Parser {
+    tokens: Vec<(Token, usize)>,
+    pos: usize,
+    len: usize,
+}
+///
+///
+impl Parser {
+    //
+    fn peek(&self) -> Option<&(Token, usize)> {
+        self.tokens.get(self.pos)
+    }
+    //
+    fn next_op(&mut self, ops: &[&'static str]) -> Option<&'static str> {
+        match self.peek() {
+            Some((Token::Op(op), _)) if ops.contains(op) => {
+                let op = *op;
+                self.pos += 1;
+                Some(op)
+            }
+            _ => None,
+        }
+    }
+    //
+    fn or(&mut self) -> Result<Expr, String> {
+        let mut left = self.and()?;
+        while self.next_op(&["||"]).is_some() {
+            left = Expr::Binary(BinaryOp::Or, Box::new(left), Box::new(self.and()?));
+        }
+        Ok(left)
+    }
+    //
+    fn and(&mut self) -> Result<Expr, String> {
+        let mut left = self.cmp()?;
+        while self.next_op(&["&&"]).is_some() {
+            left = Expr::Binary(BinaryOp::And, Box::new(left), Box::new(self.cmp()?));
+        }
+        Ok(left)
+    }
+    //
+    fn cmp(&mut self) -> Result<Expr, String> {
+        const OPS: [&str; 6] = ["==", "!=", "<=", ">=", "<", ">"];
+        let left = self.add()?;
+        match self.next_op(&OPS) {
+            Some(op) => {
+                let op = match op {
+                    "==" => BinaryOp::Eq, "!=" => BinaryOp::Ne, "<=" => BinaryOp::Le,
+                    ">=" => BinaryOp::Ge, "<" => BinaryOp::Lt, _ => BinaryOp::Gt,
+                };
+                let expr = Expr::Binary(op, Box::new(left), Box::new(self.add()?));
+                if let Some((Token::Op(op), pos)) = self.peek() {
+                    if OPS.contains(op) {
+                        return Err(format!("comparison can't be chained, unexpected '{}' at {}", op, pos));
+                    }
+                }
+                Ok(expr)
+            }
+            None => Ok(left),
+        }
+    }
+    //
+    fn add(&mut self) -> Result<Expr, String> {
+        let mut left = self.mul()?;
+        while let Some(op) = self.next_op(&["+", "-"]) {
+            let op = if op == "+" {BinaryOp::Add} else {BinaryOp::Sub};
+            left = Expr::Binary(op, Box::new(left), Box::new(self.mul()?));
+        }
+        Ok(left)
+    }
+    //
+    fn mul(&mut self) -> Result<Expr, String> {
+        let mut left = self.unary()?;
+        while let Some(op) = self.next_op(&["*", "/", "%"]) {
+            let op = match op {"*" => BinaryOp::Mul, "/" => BinaryOp::Div, _ => BinaryOp::Mod};
+            left = Expr::Binary(op, Box::new(left), Box::new(self.unary()?));
+        }
+        Ok(left)
+    }
+    //
+    fn unary(&mut self) -> Result<Expr, String> {
+        match self.next_op(&["-", "!"]) {
+            Some("-") => Ok(Expr::Unary(UnaryOp::Neg, Box::new(self.unary()?))),
+            Some(_) => Ok(Expr::Unary(UnaryOp::Not, Box::new(self.unary()?))),
+            None => self.pow(),
+        }
+    }
+    //
+    fn pow(&mut self) -> Result<Expr, String> {
+        let base = self.primary()?;
+        match self.next_op(&["^"]) {
+            Some(_) => Ok(Expr::Binary(BinaryOp::Pow, Box::new(base), Box::new(self.unary()?))),
+            None => Ok(base),
+        }
+    }
+    //
+    fn primary(&mut self) -> Result<Expr, String> {
+        let (token, pos) = match self.peek() {
+            Some(token) => token.clone(),
+            None => return Err(format!("unexpected end of the formula at {}", self.len)),
+        };
+        self.pos += 1;
+        match token {
+            Token::Int(value) => Ok(Expr::Int(value)),
+            Token::Double(value) => Ok(Expr::Double(value)),
+            Token::Ident(name) => match name.as_str() {
+                "true" => Ok(Expr::Bool(true)),
+                "false" => Ok(Expr::Bool(false)),
+                _ => Ok(Expr::Var(name)),
+            },
+            Token::Open => {
+                let expr = self.or()?;
+                match self.peek() {
+                    Some((Token::Close, _)) => {
+                        self.pos += 1;
+                        Ok(expr)
+                    }
+                    Some((token, pos)) => Err(format!("expected ')', but found {:?} at {}", token, pos)),
+                    None => Err(format!("expected ')' at {}", self.len)),
+                }
+            }
+            Token::Close => Err(format!("unexpected ')' at {}", pos)),
+            Token::Op(op) => Err(format!("unexpected '{}' at {}", op, pos)),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{
+    cot::cot::Cot, point::{point::Point, point_type::PointType}, state::switch_state::SwitchState, types::{bool::Bool, fn_in_out_ref::FnInOutRef},
+};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_edge::{edge_state, EdgeState}, fn_kind::FnKind, fn_logic::to_bool};
+///
+/// Function | Falling edge detection
+/// Returns true for the single evaluation when the input changes from true to false
+/// - Int / Real / Double input coerced to Bool as non-zero
+#[derive(Debug)]
+pub struct FnFallingEdge {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+    state: SwitchState<EdgeState, bool>,
+}
+///
+/// 
+impl FnFallingEdge {
+    ///
+    /// Creates new instance of the FnFallingEdge
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef) -> Self {
+        Self { 
+            id: format!("{}/FnFallingEdge{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            input,
+            state: edge_state(),
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnFallingEdge {}
+///
+///
+impl FnOut for FnFallingEdge {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        self.state.add(to_bool(&self.id, &point));
+        let state = self.state.state();
+        let value = state == EdgeState::Falling;
+        debug!("{}.out | state: {:?}   |   value: {:?}", self.id, state, value);
+        PointType::Bool(
+            Point::<Bool> {
+                tx_id: *point.tx_id(),
+                name: format!("{}.out", self.id),
+                value: Bool(value),
+                status: point.status(),
+                cot: Cot::Inf,
+                timestamp: point.timestamp(),
+            }
+        )
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.state.reset();
+        self.input.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnFallingEdge {}
+///
+/// Global static counter of FnFallingEdge instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,220 @@
+use chrono::{DateTime, Utc};
+use indexmap::IndexMap;
+use log::{debug, error, info};
+use std::{collections::VecDeque, fs, path::PathBuf, sync::atomic::{AtomicUsize, Ordering}, time::{Duration, Instant}};
+use crate::core_::{point::{point::Point, point_type::PointType}, status::status::Status, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_logic::to_bool, fn_status::worst_status};
+///
+/// Function | Fault disturbance recorder
+/// - keeps the rolling in-memory buffer of the all [inputs], not older then [pre] before the latest point,
+/// the latest sample before the window is also kept, as the initial value of the input
+/// - on the rising edge of the [trigger] the capture started,
+/// the capture completed when the point not older then [post] after the trigger received,
+/// or when [post] elapsed on the clock since the trigger received,
+/// so the capture is completed even if the inputs stopped changing after the trip,
+/// the function has to be evaluated for that, for example in the 'cyclic' Task mode
+/// - the initial placeholders of the inputs are not recorded
+/// - the snapshot of the window [trigger - pre, trigger + post] is stored into the [dir],
+/// the file named by the trigger timestamp, the trigger name & the recorder id,
+/// so the snapshots of the different recorders don't overwrite each other,
+/// and returned as JSON String:
+/// ```json
+/// {
+///     "trigger": "/App/Task/Winch.Overload",
+///     "timestamp": "2024-04-08T08:52:32.656576549+00:00",
+///     "preTrigger": 10.0,
+///     "postTrigger": 5.0,
+///     "inputs": {
+///         "load": [
+///             {"type": "Real","value": 12.5,"name": "/App/path/Winch.Load","status": 0,"cot": "Inf","timestamp": "2024-04-08T08:52:22.656576549+00:00"},
+///             ...
+///         ]
+///     }
+/// }
+/// ```
+/// - the output holds the latest snapshot, empty String until the first capture completed
+/// - the [dir] is the only persistence of the snapshots, if [dir] is not configured, the snapshot is available on the output only;
+/// the JSON snapshot can't be exported by 'ToApiQueue', it sends the String points to the API as SQL
+/// - the window measured by the Point.timestamp, the retrigger during the capture is ignored
+/// - the snapshot has the worst status of the trigger & the recorded samples & the timestamp of the trigger
+#[derive(Debug)]
+pub struct FnFaultRecorder {
+    id: String,
+    kind: FnKind,
+    trigger: FnInOutRef,
+    inputs: IndexMap<String, FnInOutRef>,
+    pre: Duration,
+    post: Duration,
+    dir: Option<PathBuf>,
+    buffers: IndexMap<String, VecDeque<PointType>>,
+    prev: bool,
+    capture: Option<(PointType, Instant)>,
+    snapshot: Point<String>,
+}
+///
+///
+impl FnFaultRecorder {
+    ///
+    /// Creates new instance of the FnFaultRecorder
+    /// - trigger - the capture started on the rising edge
+    /// - inputs - the recorded points, by name
+    /// - pre - the length of the pre-trigger window
+    /// - post - the length of the post-trigger window
+    /// - dir - optional directory, the snapshots stored into
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, trigger: FnInOutRef, inputs: IndexMap<String, FnInOutRef>, pre: Duration, post: Duration, dir: Option<PathBuf>) -> Self {
+        let id = format!("{}/FnFaultRecorder{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed));
+        if inputs.is_empty() {
+            panic!("{}.new | At least one recorded input required", id);
+        }
+        let buffers = inputs.keys().map(|name| (name.clone(), VecDeque::new())).collect();
+        let snapshot = Point::new_string(0, &format!("{}.out", id), "");
+        Self {
+            id,
+            kind: FnKind::Fn,
+            trigger,
+            inputs,
+            pre,
+            post,
+            dir,
+            buffers,
+            prev: false,
+            capture: None,
+            snapshot,
+        }
+    }
+    ///
+    /// Removes the samples out of the window starting from [cutoff],
+    /// the latest sample before the [cutoff] is kept as the initial value
+    fn trim(&mut self, cutoff: DateTime<Utc>) {
+        for buffer in self.buffers.values_mut() {
+            while buffer.len() > 1 && buffer[1].timestamp() <= cutoff {
+                buffer.pop_front();
+            }
+        }
+    }
+    ///
+    /// Returns the snapshot of the window [trigger - pre, trigger + post] in JSON
+    /// & the worst status of the trigger & the recorded samples
+    fn snapshot(&self, trigger: &PointType) -> (String, Status) {
+        let stop = trigger.timestamp() + chrono::Duration::from_std(self.post).unwrap_or(chrono::Duration::zero());
+        let mut status = trigger.status();
+        let inputs: serde_json::Map<String, serde_json::Value> = self.buffers.iter().map(|(name, buffer)| {
+            let samples: Vec<&PointType> = buffer.iter()
+                .filter(|point| point.timestamp() <= stop)
+                .collect();
+            status = status.max(worst_status(samples.iter().copied()));
+            let samples = samples.into_iter().map(|point| serde_json::json!(point)).collect();
+            (name.clone(), serde_json::Value::Array(samples))
+        }).collect();
+        let snapshot = serde_json::json!({
+            "trigger": trigger.name(),
+            "timestamp": trigger.timestamp().to_rfc3339(),
+            "preTrigger": self.pre.as_secs_f64(),
+            "postTrigger": self.post.as_secs_f64(),
+            "inputs": inputs,
+        }).to_string();
+        (snapshot, status)
+    }
+    ///
+    /// Writes the snapshot into the [dir], named by the trigger timestamp, the trigger name & the recorder id,
+    /// for example '2024-04-08T08-52-32.656576_Winch.Overload_FnFaultRecorder1.json'
+    fn store(&self, trigger: &PointType, snapshot: &str) {
+        if let Some(dir) = &self.dir {
+            let trigger_name = trigger.name();
+            let trigger_name = trigger_name.rsplit('/').next().unwrap_or_default();
+            let recorder = self.id.rsplit('/').next().unwrap_or_default();
+            let timestamp = trigger.timestamp().format("%Y-%m-%dT%H-%M-%S%.6f");
+            let path = dir.join(format!("{}_{}_{}.json", timestamp, trigger_name, recorder));
+            let result = fs::create_dir_all(dir).and_then(|_| fs::write(&path, snapshot));
+            match result {
+                Ok(_) => info!("{}.store | Snapshot stored in: {:?}", self.id, path),
+                Err(err) => error!("{}.store | Error writing snapshot to: {:?}\n\terror: {:?}", self.id, path, err),
+            }
+        }
+    }
+}
+///
+///
+impl FnIn for FnFaultRecorder {}
+///
+///
+impl FnOut for FnFaultRecorder {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = self.trigger.borrow().inputs();
+        for input in self.inputs.values() {
+            inputs.extend(input.borrow().inputs());
+        }
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let trigger = self.trigger.borrow_mut().out();
+        let mut latest = (!self.trigger.borrow().is_initial()).then(|| trigger.timestamp());
+        for (name, input) in &self.inputs {
+            let point = input.borrow_mut().out();
+            if input.borrow().is_initial() {
+                continue;
+            }
+            latest = latest.max(Some(point.timestamp()));
+            let buffer = self.buffers.get_mut(name).unwrap();
+            if buffer.back().map_or(true, |last| last.timestamp() != point.timestamp()) {
+                buffer.push_back(point);
+            }
+        }
+        let value = to_bool(&self.id, &trigger);
+        if value && !self.prev && self.capture.is_none() {
+            debug!("{}.out | Capture started by {:?} at {:?}", self.id, trigger.name(), trigger.timestamp());
+            self.capture = Some((trigger.clone(), Instant::now()));
+        }
+        self.prev = value;
+        let pre = chrono::Duration::from_std(self.pre).unwrap_or(chrono::Duration::zero());
+        let post = chrono::Duration::from_std(self.post).unwrap_or(chrono::Duration::zero());
+        if let Some((capture, started)) = self.capture.clone() {
+            if latest.is_some_and(|latest| latest >= capture.timestamp() + post) || started.elapsed() >= self.post {
+                let (snapshot, status) = self.snapshot(&capture);
+                debug!("{}.out | Capture completed: {}", self.id, snapshot);
+                self.store(&capture, &snapshot);
+                self.snapshot = Point::new(*capture.tx_id(), &self.snapshot.name, snapshot, status, capture.cot(), capture.timestamp());
+                self.capture = None;
+            }
+        }
+        let cutoff = match &self.capture {
+            Some((capture, _)) => Some(capture.timestamp() - pre),
+            None => latest.map(|latest| latest - pre),
+        };
+        if let Some(cutoff) = cutoff {
+            self.trim(cutoff);
+        }
+        PointType::String(self.snapshot.clone())
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.capture = None;
+        self.prev = false;
+        for buffer in self.buffers.values_mut() {
+            buffer.clear();
+        }
+        self.trigger.borrow_mut().reset();
+        for input in self.inputs.values() {
+            input.borrow_mut().reset();
+        }
+    }
+}
+///
+///
+impl FnInOut for FnFaultRecorder {}
+///
+/// Global static counter of FnFaultRecorder instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
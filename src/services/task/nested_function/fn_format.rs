@@ -0,0 +1,107 @@
+use indexmap::IndexMap;
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{cot::cot::Cot, format::format::Format, point::{point::Point, point_type::PointType}, status::status::Status, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_status::propagate};
+///
+/// Function | Formats the String from the template & named inputs
+/// - template markers replaced with the values of the inputs, see [Format]:
+///     - {input} / {input.value} - the value
+///     - {input.name}, {input.timestamp}, {input.status}
+///     - other braces kept as is, so the JSON template can be used: `{"winch": {winch}, "load": {load}}`
+///     - {input:json}, {input.sufix:json} - the complete JSON value, String escaped: `{"message": {message:json}}`
+/// ```yaml
+/// fn format:
+///     template: "Winch {winch} overload at {load.value} t"
+///     winch: const int 2
+///     load: point real '/path/Winch2.Load'
+/// ```
+/// - status - the worst of the all inputs
+/// - tx_id & timestamp - from the latest of the all inputs
+#[derive(Debug)]
+pub struct FnFormat {
+    id: String,
+    kind: FnKind,
+    inputs: IndexMap<String, FnInOutRef>,
+    format: Format,
+}
+///
+/// 
+impl FnFormat {
+    ///
+    /// Creates new instance of the FnFormat
+    /// - template - the String containing {input.sufix} markers
+    /// - inputs - the named inputs, each marker of the template must have an input
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, template: &str, inputs: IndexMap<String, FnInOutRef>) -> Self {
+        let id = format!("{}/FnFormat{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed));
+        let format = Format::new(template);
+        for (full_name, (name, _)) in format.names() {
+            if !inputs.contains_key(&name) {
+                panic!("{}.new | Input {:?} of the template marker {{{}}} - not found", id, name, full_name);
+            }
+        }
+        Self { 
+            id,
+            kind: FnKind::Fn,
+            inputs,
+            format,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnFormat {}
+///
+///
+impl FnOut for FnFormat {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = vec![];
+        for input in self.inputs.values() {
+            inputs.extend(input.borrow().inputs());
+        }
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let points: IndexMap<String, PointType> = self.inputs.iter().map(|(name, input)| {
+            (name.clone(), input.borrow_mut().out())
+        }).collect();
+        for (full_name, (name, _)) in self.format.names() {
+            self.format.insert(&full_name, points[&name].clone());
+        }
+        let value = self.format.out();
+        debug!("{}.out | value: {:?}", self.id, value);
+        let (tx_id, timestamp) = match points.values().next() {
+            Some(first) => (*first.tx_id(), first.timestamp()),
+            None => (0, chrono::offset::Utc::now()),
+        };
+        let out = PointType::String(
+            Point::new(tx_id, &format!("{}.out", self.id), value, Status::Ok, Cot::Inf, timestamp)
+        );
+        propagate(out, points.values())
+    }
+    //
+    //
+    fn reset(&mut self) {
+        for input in self.inputs.values() {
+            input.borrow_mut().reset();
+        }
+    }
+}
+///
+/// 
+impl FnInOut for FnFormat {}
+///
+/// Global static counter of FnFormat instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
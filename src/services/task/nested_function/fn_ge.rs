@@ -1,10 +1,11 @@
 use log::debug;
 use std::sync::atomic::{AtomicUsize, Ordering};
-use crate::core_::{cot::cot::Cot, point::{point::Point, point_type::PointType}, types::{bool::Bool, fn_in_out_ref::FnInOutRef, type_of::DebugTypeOf}};
-use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind};
+use crate::core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_compare::{compare, out_point}, fn_kind::FnKind};
 ///
 /// Function | Greater than or equal to
 /// FnGe ( input1, input2 ) === input1.value >= input2.value
+/// - String can be compared with String only
 #[derive(Debug)]
 pub struct FnGe {
     id: String,
@@ -24,25 +25,6 @@ impl FnGe {
             input2,
         }
     }
-    ///
-    /// 
-    fn to_double(&self, point: &PointType) -> f64 {
-        match point {
-            PointType::Bool(point) => {
-                if point.value.0 {1.0} else {0.0}
-            }
-            PointType::Int(point) => {
-                point.value as f64
-            }
-            PointType::Real(point) => {
-                point.value as f64
-            }
-            PointType::Double(point) => {
-                point.value
-            }
-            _ => panic!("{}.out | {:?} type is not supported: {:?}", self.id, point.print_type_of(), point),
-        }
-    }
 }
 ///
 /// 
@@ -70,28 +52,9 @@ impl FnOut for FnGe {
         // debug!("FnTrip.out | input: {:?}", self.input.print());
         let point1 = self.input1.borrow_mut().out();     
         let point2 = self.input2.borrow_mut().out();    
-        let value = self.to_double(&point1) >= self.to_double(&point2);
+        let value = matches!(compare(&self.id, &point1, &point2), Some(std::cmp::Ordering::Greater | std::cmp::Ordering::Equal));
         debug!("{}.out | input.out: {:?}", self.id, &value);
-        let status = match point1.status().cmp(&point2.status()) {
-            std::cmp::Ordering::Less => point2.status(),
-            std::cmp::Ordering::Equal => point1.status(),
-            std::cmp::Ordering::Greater => point1.status(),
-        };
-        let (tx_id, timestamp) = match point1.timestamp().cmp(&point2.timestamp()) {
-            std::cmp::Ordering::Less => (point2.tx_id(), point2.timestamp()),
-            std::cmp::Ordering::Equal => (point1.tx_id(), point1.timestamp()),
-            std::cmp::Ordering::Greater => (point1.tx_id(), point1.timestamp()),
-        };
-        PointType::Bool(
-            Point::<Bool> {
-                tx_id: *tx_id,
-                name: format!("{}.out", self.id),
-                value: Bool(value),
-                status,
-                cot: Cot::Inf,
-                timestamp,
-            }
-        )
+        out_point(&self.id, &point1, &point2, value)
     }
     //
     //
@@ -0,0 +1,72 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_compare::{compare, out_point}, fn_kind::FnKind};
+///
+/// Function | Greater than
+/// FnGt ( input1, input2 ) === input1.value > input2.value
+/// - String can be compared with String only
+#[derive(Debug)]
+pub struct FnGt {
+    id: String,
+    kind: FnKind,
+    input1: FnInOutRef,
+    input2: FnInOutRef,
+}
+///
+/// 
+impl FnGt {
+    ///
+    /// Creates new instance of the FnGt
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef) -> Self {
+        Self { 
+            id: format!("{}/FnGt{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            input1,
+            input2,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnGt {}
+///
+///
+impl FnOut for FnGt {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = self.input1.borrow().inputs();
+        inputs.extend(self.input2.borrow().inputs());
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point1 = self.input1.borrow_mut().out();     
+        let point2 = self.input2.borrow_mut().out();    
+        let value = compare(&self.id, &point1, &point2) == Some(std::cmp::Ordering::Greater);
+        debug!("{}.out | input.out: {:?}", self.id, &value);
+        out_point(&self.id, &point1, &point2, value)
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.input1.borrow_mut().reset();
+        self.input2.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnGt {}
+///
+/// Global static counter of FnGt instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,89 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_compare::compare, fn_kind::FnKind, fn_logic::out_point};
+///
+/// Function | Hysteresis comparator
+/// - the state becomes true when [input] > [high]
+/// - the state becomes false only when [input] < [low]
+/// - between [low] and [high] the state is kept from the previous evaluation
+/// - Bool / Int / Real / Double inputs compared as Double
+#[derive(Debug)]
+pub struct FnHysteresis {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+    high: FnInOutRef,
+    low: FnInOutRef,
+    state: bool,
+}
+///
+/// 
+impl FnHysteresis {
+    ///
+    /// Creates new instance of the FnHysteresis
+    /// - input - the value to be compared
+    /// - high - the state is switched on above this limit
+    /// - low - the state is switched off below this limit
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef, high: FnInOutRef, low: FnInOutRef) -> Self {
+        Self { 
+            id: format!("{}/FnHysteresis{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            input,
+            high,
+            low,
+            state: false,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnHysteresis {}
+///
+///
+impl FnOut for FnHysteresis {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = self.input.borrow().inputs();
+        inputs.extend(self.high.borrow().inputs());
+        inputs.extend(self.low.borrow().inputs());
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let input = self.input.borrow_mut().out();
+        let high = self.high.borrow_mut().out();
+        let low = self.low.borrow_mut().out();
+        if compare(&self.id, &input, &high) == Some(std::cmp::Ordering::Greater) {
+            self.state = true;
+        } else if compare(&self.id, &input, &low) == Some(std::cmp::Ordering::Less) {
+            self.state = false;
+        }
+        debug!("{}.out | input: {:?}   |   high: {:?}   |   low: {:?}   |   state: {:?}", self.id, input.value(), high.value(), low.value(), self.state);
+        out_point(&self.id, &[input, high, low], self.state)
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.state = false;
+        self.input.borrow_mut().reset();
+        self.high.borrow_mut().reset();
+        self.low.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnHysteresis {}
+///
+/// Global static counter of FnHysteresis instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,128 @@
+use std::{cell::RefCell, rc::Rc, str::FromStr, sync::atomic::{AtomicUsize, Ordering}, time::Duration};
+use crate::core_::{cot::cot::Cot, point::{point::Point, point_type::PointType}, types::bool::Bool};
+use super::{fn_::{FnIn, FnInOut, FnOut}, fn_kind::FnKind};
+///
+/// Output of the IEC 61131 timers (ton, tof, tp)
+///  - Q - Bool output of the timer (default)
+///  - Elapsed - elapsed time in seconds, Double
+#[derive(Debug, Clone, PartialEq)]
+pub enum IecTimerOut {
+    Q,
+    Elapsed,
+}
+///
+/// 
+impl FromStr for IecTimerOut {
+    type Err = String;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.trim().to_lowercase().as_str() {
+            "q" => Ok(Self::Q),
+            "elapsed" | "et" => Ok(Self::Elapsed),
+            _ => Err(format!("IecTimerOut.from_str | Unknown timer output: '{}', 'q' or 'elapsed' expected", input)),
+        }
+    }
+}
+///
+/// The latest elapsed time of the timer, shared with the [FnIecElapsed]
+pub type IecTimerElapsed = Rc<RefCell<Option<PointType>>>;
+///
+/// Returns the configured output of the timer
+///  - tx_id, status & timestamp taken from the input point
+///  - the elapsed point stored into the [shared], so Q & elapsed of the same evaluation are available
+pub fn out_point(self_id: &str, point: &PointType, out: &IecTimerOut, q: bool, elapsed: Duration, shared: &IecTimerElapsed) -> PointType {
+    let elapsed = PointType::Double(
+        Point {
+            tx_id: *point.tx_id(),
+            name: format!("{}.elapsed", self_id),
+            value: elapsed.as_secs_f64(),
+            status: point.status(),
+            cot: Cot::Inf,
+            timestamp: point.timestamp(),
+        }
+    );
+    shared.replace(Some(elapsed.clone()));
+    match out {
+        IecTimerOut::Q => PointType::Bool(
+            Point {
+                tx_id: *point.tx_id(),
+                name: format!("{}.out", self_id),
+                value: Bool(q),
+                status: point.status(),
+                cot: Cot::Inf,
+                timestamp: point.timestamp(),
+            }
+        ),
+        IecTimerOut::Elapsed => elapsed,
+    }
+}
+///
+/// Function | The elapsed time of the ton / tof / tp timer
+/// - declared as the task variable by the timer parameter `elapsed: VarName`
+/// - returns the elapsed seconds (Double) stored by the latest evaluation of the same timer instance,
+/// so Q & elapsed are always taken from the same timer state
+/// - like any variable, use it after the timer, the timer itself isn't evaluated here
+/// - returns 0.0 until the timer is evaluated first time
+#[derive(Debug)]
+pub struct FnIecElapsed {
+    id: String,
+    kind: FnKind,
+    inputs: Vec<String>,
+    elapsed: IecTimerElapsed,
+}
+///
+/// 
+impl FnIecElapsed {
+    ///
+    /// Creates new instance of the FnIecElapsed
+    /// - inputs - the inputs of the timer
+    /// - elapsed - the elapsed time shared by the timer
+    pub fn new(parent: impl Into<String>, inputs: Vec<String>, elapsed: IecTimerElapsed) -> Self {
+        Self {
+            id: format!("{}/FnIecElapsed{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Var,
+            inputs,
+            elapsed,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnIecElapsed {}
+///
+/// 
+impl FnOut for FnIecElapsed {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.inputs.clone()
+    }
+    //
+    // The timer evaluated by it self, nothing to evaluate here
+    fn eval(&mut self) {}
+    //
+    //
+    fn out(&mut self) -> PointType {
+        match self.elapsed.borrow().as_ref() {
+            Some(elapsed) => elapsed.clone(),
+            None => PointType::Double(Point::new_double(0, &format!("{}.out", self.id), 0.0)),
+        }
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.elapsed.replace(None);
+    }
+}
+///
+/// 
+impl FnInOut for FnIecElapsed {}
+///
+/// Global static counter of FnIecElapsed instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,79 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_logic::to_bool, fn_status::propagate};
+///
+/// Function | Conditional select
+/// FnIf ( condition, then, else ) === if condition {then} else {else}
+/// - Int / Real / Double condition coerced to Bool as non-zero
+/// - all inputs are evaluated on each call, so stateful functions in the both branches stay consistent
+/// - the output has the worst status & the latest timestamp of the condition & the selected branch
+#[derive(Debug)]
+pub struct FnIf {
+    id: String,
+    kind: FnKind,
+    condition: FnInOutRef,
+    then: FnInOutRef,
+    else_: FnInOutRef,
+}
+///
+/// 
+impl FnIf {
+    ///
+    /// Creates new instance of the FnIf
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, condition: FnInOutRef, then: FnInOutRef, else_: FnInOutRef) -> Self {
+        Self { 
+            id: format!("{}/FnIf{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            condition,
+            then,
+            else_,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnIf {}
+///
+///
+impl FnOut for FnIf {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = self.condition.borrow().inputs();
+        inputs.extend(self.then.borrow().inputs());
+        inputs.extend(self.else_.borrow().inputs());
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let condition = self.condition.borrow_mut().out();
+        let then = self.then.borrow_mut().out();
+        let else_ = self.else_.borrow_mut().out();
+        let value = to_bool(&self.id, &condition);
+        debug!("{}.out | condition: {:?}", self.id, value);
+        propagate(if value {then} else {else_}, [&condition])
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.condition.borrow_mut().reset();
+        self.then.borrow_mut().reset();
+        self.else_.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnIf {}
+///
+/// Global static counter of FnIf instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -11,6 +11,7 @@ pub struct FnInput {
     type_: FnConfPointType,
     point: PointType,
     initial: PointType,
+    is_initial: bool,
 }
 ///
 /// 
@@ -21,7 +22,8 @@ impl FnInput {
             kind: FnKind::Input,
             type_,
             point: initial.clone(), 
-            initial
+            initial,
+            is_initial: true,
         }
     }
 }
@@ -145,6 +147,7 @@ impl FnIn for FnInput {
                 panic!("{}.add | Error. FnInput does not supports unknown type, but configured in: {:#?}", self.id, self);
             }
         };
+        self.is_initial = false;
     }
 }
 ///
@@ -163,6 +166,10 @@ impl FnOut for FnInput {
         vec![self.point.name()]
     }
     //
+    fn is_initial(&self) -> bool {
+        self.is_initial
+    }
+    //
     fn out(&mut self) -> PointType {
         trace!("{}.out | value: {:?}", self.id, &self.point);
         self.point.clone()
@@ -170,6 +177,7 @@ impl FnOut for FnInput {
     //
     fn reset(&mut self) {
         self.point = self.initial.clone();
+        self.is_initial = true;
     }
 }
 ///
@@ -0,0 +1,120 @@
+use chrono::{DateTime, Utc};
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{cot::cot::Cot, point::{point::Point, point_type::PointType}, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_compare::to_double, fn_kind::FnKind, fn_logic::to_bool, fn_status::propagate};
+///
+/// Function | Time integral of the input, trapezoidal rule
+/// - time measured in seconds by the Point.timestamp, not by the wall-clock,
+/// so the result is correct if points arrive in bursts
+/// - samples with timestamp not newer then the previous sample are ignored
+/// - Bool / Int / Real / Double input calculated as Double
+/// - the initial placeholder of the input is not used as a sample
+/// - if optional [reset] input is true, the integral returns to the [initial]
+#[derive(Debug)]
+pub struct FnIntegral {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+    reset: Option<FnInOutRef>,
+    prev: Option<(f64, DateTime<Utc>)>,
+    value: f64,
+    initial: f64,
+}
+///
+/// 
+impl FnIntegral {
+    ///
+    /// Creates new instance of the FnIntegral
+    /// - initial - the integral value at start & after reset
+    /// - input - the value to be integrated
+    /// - reset - optional input, resets the integral to the initial while true
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, initial: f64, input: FnInOutRef, reset: Option<FnInOutRef>) -> Self {
+        Self { 
+            id: format!("{}/FnIntegral{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            input,
+            reset,
+            prev: None,
+            value: initial,
+            initial,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnIntegral {}
+///
+///
+impl FnOut for FnIntegral {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = self.input.borrow().inputs();
+        if let Some(reset) = &self.reset {
+            inputs.extend(reset.borrow().inputs());
+        }
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        let reset_point = self.reset.as_ref().map(|reset| reset.borrow_mut().out());
+        let reset = reset_point.as_ref().map_or(false, |reset| to_bool(&self.id, reset));
+        let received = !self.input.borrow().is_initial();
+        let value = to_double(&self.id, &point);
+        let timestamp = point.timestamp();
+        if reset {
+            self.value = self.initial;
+            self.prev = if received {Some((value, timestamp))} else {None};
+        } else if received {
+            match self.prev {
+                Some((prev_value, prev_timestamp)) => {
+                    if timestamp > prev_timestamp {
+                        let dt = timestamp.signed_duration_since(prev_timestamp).to_std().unwrap_or_default().as_secs_f64();
+                        self.value += (prev_value + value) / 2.0 * dt;
+                        self.prev = Some((value, timestamp));
+                    }
+                }
+                None => self.prev = Some((value, timestamp)),
+            }
+        }
+        debug!("{}.out | input: {:?}   |   integral: {:?}   |   reset: {:?}", self.id, value, self.value, reset);
+        let out = PointType::Double(
+            Point {
+                tx_id: *point.tx_id(),
+                name: format!("{}.out", self.id),
+                value: self.value,
+                status: point.status(),
+                cot: Cot::Inf,
+                timestamp,
+            }
+        );
+        propagate(out, &reset_point)
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.value = self.initial;
+        self.prev = None;
+        self.input.borrow_mut().reset();
+        if let Some(reset) = &self.reset {
+            reset.borrow_mut().reset();
+        }
+    }
+}
+///
+/// 
+impl FnInOut for FnIntegral {}
+///
+/// Global static counter of FnIntegral instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
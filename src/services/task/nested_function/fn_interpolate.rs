@@ -0,0 +1,140 @@
+use log::debug;
+use std::{str::FromStr, sync::atomic::{AtomicUsize, Ordering}};
+use crate::core_::{cot::cot::Cot, point::{point::Point, point_type::PointType}, status::status::Status, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_compare::to_double, fn_kind::FnKind, fn_status::propagate};
+///
+/// Behavior of the FnInterpolate out of the table range
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Extrapolation {
+    /// The y of the nearest table point returned
+    Hold,
+    /// The nearest table segment extended
+    Linear,
+    /// The y of the nearest table point returned with Status::Invalid
+    Invalid,
+}
+///
+/// 
+impl FromStr for Extrapolation {
+    type Err = String;
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        match input.to_lowercase().as_str() {
+            "hold" => Ok(Self::Hold),
+            "linear" => Ok(Self::Linear),
+            "invalid" => Ok(Self::Invalid),
+            _ => Err(format!("Extrapolation.from_str | Unknown extrapolation: '{}', expected one of: hold / linear / invalid", input)),
+        }
+    }
+}
+///
+/// Function | Piecewise-linear lookup table
+/// - [table] - the [x, y] points of the calibration curve, at least two points with the unique x required
+/// - out of the table range behaves according to the [extrapolation]
+/// - Int / Real / Double input, Double output
+#[derive(Debug)]
+pub struct FnInterpolate {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+    table: Vec<(f64, f64)>,
+    extrapolation: Extrapolation,
+}
+///
+/// 
+impl FnInterpolate {
+    ///
+    /// Creates new instance of the FnInterpolate
+    /// - input - the x value
+    /// - table - the [x, y] points, sorted by x on creation
+    /// - extrapolation - behavior out of the table range
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef, mut table: Vec<(f64, f64)>, extrapolation: Extrapolation) -> Self {
+        let id = format!("{}/FnInterpolate{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed));
+        table.sort_by(|(x1, _), (x2, _)| x1.total_cmp(x2));
+        if table.len() < 2 {
+            panic!("{}.new | At least two table points required, but found: {:?}", id, table);
+        }
+        if table.windows(2).any(|pair| pair[0].0 == pair[1].0) {
+            panic!("{}.new | Table points must have the unique x, but found: {:?}", id, table);
+        }
+        Self { 
+            id,
+            kind: FnKind::Fn,
+            input,
+            table,
+            extrapolation,
+        }
+    }
+    ///
+    /// Returns y of the segment [p1, p2] for the given x
+    fn segment(x: f64, (x1, y1): (f64, f64), (x2, y2): (f64, f64)) -> f64 {
+        y1 + (x - x1) * (y2 - y1) / (x2 - x1)
+    }
+    ///
+    /// Returns y for the given x & true if x is out of the table range
+    fn lookup(&self, x: f64) -> (f64, bool) {
+        let first = self.table[0];
+        let last = self.table[self.table.len() - 1];
+        if x < first.0 {
+            match self.extrapolation {
+                Extrapolation::Linear => (Self::segment(x, first, self.table[1]), true),
+                _ => (first.1, true),
+            }
+        } else if x > last.0 {
+            match self.extrapolation {
+                Extrapolation::Linear => (Self::segment(x, self.table[self.table.len() - 2], last), true),
+                _ => (last.1, true),
+            }
+        } else {
+            let index = self.table.partition_point(|(x_, _)| *x_ < x).max(1);
+            (Self::segment(x, self.table[index - 1], self.table[index]), false)
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnInterpolate {}
+///
+///
+impl FnOut for FnInterpolate {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        let x = to_double(&self.id, &point);
+        let (value, out_of_range) = self.lookup(x);
+        let status = if out_of_range && self.extrapolation == Extrapolation::Invalid {
+            Status::Invalid
+        } else {
+            Status::Ok
+        };
+        debug!("{}.out | x: {:?}   |   y: {:?}   |   out of range: {:?}", self.id, x, value, out_of_range);
+        let out = PointType::Double(
+            Point::new(*point.tx_id(), &format!("{}.out", self.id), value, status, Cot::Inf, point.timestamp())
+        );
+        propagate(out, [&point])
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.input.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnInterpolate {}
+///
+/// Global static counter of FnInterpolate instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,116 @@
+use log::{debug, error};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::{
+    core_::{cot::cot::Cot, point::{point::Point, point_type::PointType}, status::status::Status, types::{bool::Bool, fn_in_out_ref::FnInOutRef}},
+};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_logic::to_bool, fn_status::propagate};
+///
+/// Dominant input of the latch, used if both set & reset are true
+#[derive(Debug, Clone, PartialEq)]
+pub enum LatchDominance {
+    /// SR latch, set dominant
+    Set,
+    /// RS latch, reset dominant
+    Reset,
+}
+///
+/// Function | SR / RS latch (flip-flop)
+/// - the state becomes true when [set] input is true
+/// - the state becomes false when [reset] input is true
+/// - if both inputs are true, the state depends on [dominance]
+/// - Int / Real / Double inputs coerced to Bool as non-zero
+/// - the latched state can be retained across restarts by the 'retain' parameter, see [TaskNodes]
+#[derive(Debug)]
+pub struct FnLatch {
+    id: String,
+    kind: FnKind,
+    set: FnInOutRef,
+    reset: FnInOutRef,
+    dominance: LatchDominance,
+    state: bool,
+}
+///
+///
+impl FnLatch {
+    ///
+    /// Creates new instance of the FnLatch
+    /// - set - sets the state to true
+    /// - reset - resets the state to false
+    /// - dominance - which input wins if both are true
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, set: FnInOutRef, reset: FnInOutRef, dominance: LatchDominance) -> Self {
+        Self {
+            id: format!("{}/FnLatch{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            set,
+            reset,
+            dominance,
+            state: false,
+        }
+    }
+}
+///
+///
+impl FnIn for FnLatch {}
+///
+///
+impl FnOut for FnLatch {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = self.set.borrow().inputs();
+        inputs.extend(self.reset.borrow().inputs());
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let set_point = self.set.borrow_mut().out();
+        let reset_point = self.reset.borrow_mut().out();
+        let set = to_bool(&self.id, &set_point);
+        let reset = to_bool(&self.id, &reset_point);
+        let state = match (set, reset) {
+            (true, true) => self.dominance == LatchDominance::Set,
+            (true, false) => true,
+            (false, true) => false,
+            (false, false) => self.state,
+        };
+        debug!("{}.out | set: {:?}   |   reset: {:?}   |   state: {:?}", self.id, set, reset, state);
+        let out = PointType::Bool(
+            Point::new(*set_point.tx_id(), &format!("{}.out", self.id), Bool(state), Status::Ok, Cot::Inf, set_point.timestamp())
+        );
+        self.state = state;
+        propagate(out, [&set_point, &reset_point])
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.state = false;
+        self.set.borrow_mut().reset();
+        self.reset.borrow_mut().reset();
+    }
+    //
+    fn retained(&self) -> Option<PointType> {
+        Some(PointType::Bool(Point::new_bool(0, &format!("{}.out", self.id), self.state)))
+    }
+    //
+    fn restore(&mut self, state: PointType) {
+        match state {
+            PointType::Bool(state) => self.state = state.value.0,
+            _ => error!("{}.restore | Bool state expected, but found: {:?}", self.id, state),
+        }
+    }
+}
+///
+///
+impl FnInOut for FnLatch {}
+///
+/// Global static counter of FnLatch instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,31 @@
+use crate::core_::{
+    cot::cot::Cot, point::{point::Point, point_type::PointType}, status::status::Status, types::{bool::Bool, type_of::DebugTypeOf},
+};
+use super::fn_status::propagate;
+///
+/// Common part of the logical functions (and, or, xor, not)
+/// Coerces the input point into the bool
+///  - Bool - as is
+///  - Int / Real / Double - true if non-zero
+/// - [self_id] - the id of the caller, just for proper debugging
+pub fn to_bool(self_id: &str, point: &PointType) -> bool {
+    match point {
+        PointType::Bool(point) => point.value.0,
+        PointType::Int(point) => point.value != 0,
+        PointType::Real(point) => point.value != 0.0,
+        PointType::Double(point) => point.value != 0.0,
+        _ => panic!("{}.out | {:?} type is not supported: {:?}", self_id, point.print_type_of(), point),
+    }
+}
+///
+/// Returns Bool point holding the result of the logical function
+///  - status, tx_id & timestamp - propagated from the all inputs, see [propagate]
+pub fn out_point(self_id: &str, points: &[PointType], value: bool) -> PointType {
+    let first = points.first().unwrap_or_else(|| {
+        panic!("{}.out | At least one input required", self_id)
+    });
+    let out = PointType::Bool(
+        Point::new(*first.tx_id(), &format!("{}.out", self_id), Bool(value), Status::Ok, Cot::Inf, first.timestamp())
+    );
+    propagate(out, points)
+}
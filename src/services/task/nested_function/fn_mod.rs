@@ -0,0 +1,92 @@
+use log::{debug, trace};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::{
+    core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef},
+    services::task::nested_function::{
+        fn_::{FnInOut, FnIn, FnOut},
+        fn_kind::FnKind,
+        type_promotion::{apply, promote, Promoted},
+    },
+};
+///
+/// Function do Mod (remainder) of input1 divided by input2
+/// - input types promoted to the common type Int -> Real -> Double
+/// - Bool input treated as Int
+/// - the inputs having different Cot returns 0 with Status::Invalid
+/// - division by zero or Int overflow (i64::MIN by -1) returns 0 with Status::Invalid
+#[derive(Debug)]
+pub struct FnMod {
+    id: String,
+    kind: FnKind,
+    input1: FnInOutRef,
+    input2: FnInOutRef,
+}
+///
+/// 
+impl FnMod {
+    ///
+    /// Creates new instance of the FnMod
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef) -> Self {
+        Self { 
+            id: format!("{}/FnMod{}", parent.into(), COUNT.fetch_add(1, Ordering::SeqCst)),
+            kind: FnKind::Fn,
+            input1,
+            input2,
+        }
+    }    
+}
+///
+/// 
+impl FnIn for FnMod {}
+///
+/// 
+impl FnOut for FnMod { 
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = self.input1.borrow().inputs();
+        inputs.extend(self.input2.borrow().inputs());
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let value1 = self.input1.borrow_mut().out();
+        debug!("{}.out | value1: {:?}", self.id, &value1);
+        let value2 = self.input2.borrow_mut().out();
+        debug!("{}.out | value2: {:?}", self.id, &value2);
+        let out = match promote(&self.id, value1, value2) {
+            Promoted::Int(value1, value2) => {
+                PointType::Int(apply(&self.id, "Rem", value1, value2, |value1, value2| value1.checked_rem(value2)))
+            }
+            Promoted::Real(value1, value2) => {
+                PointType::Real(apply(&self.id, "Rem", value1, value2, |value1, value2| (value2 != 0.0).then(|| value1 % value2)))
+            }
+            Promoted::Double(value1, value2) => {
+                PointType::Double(apply(&self.id, "Rem", value1, value2, |value1, value2| (value2 != 0.0).then(|| value1 % value2)))
+            }
+        };
+        trace!("{}.out | out: {:?}", self.id, &out);
+        out
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.input1.borrow_mut().reset();
+        self.input2.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnMod {}
+///
+/// Global static counter of FnMod instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
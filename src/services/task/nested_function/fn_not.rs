@@ -0,0 +1,66 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_logic::{out_point, to_bool}};
+///
+/// Function | Logical NOT
+/// FnNot ( input ) === !input
+/// - Int / Real / Double input coerced to Bool as non-zero
+#[derive(Debug)]
+pub struct FnNot {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+}
+///
+/// 
+impl FnNot {
+    ///
+    /// Creates new instance of the FnNot
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef) -> Self {
+        Self { 
+            id: format!("{}/FnNot{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            input,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnNot {}
+///
+///
+impl FnOut for FnNot {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        let value = !to_bool(&self.id, &point);
+        debug!("{}.out | value: {:?}", self.id, &value);
+        out_point(&self.id, &[point], value)
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.input.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnNot {}
+///
+/// Global static counter of FnNot instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,209 @@
+use chrono::{DateTime, Utc};
+use log::debug;
+use std::{sync::atomic::{AtomicUsize, Ordering}, time::Duration};
+use crate::core_::{
+    cot::cot::Cot, format::format::Format,
+    point::{point::Point, point_type::{PointType, ToPoint}},
+    types::fn_in_out_ref::FnInOutRef,
+};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_compare::to_double, fn_kind::FnKind, fn_logic::to_bool, fn_status::worst_status};
+///
+/// Statistics of the active operating cycle
+#[derive(Debug)]
+struct Cycle {
+    start: DateTime<Utc>,
+    alarm_class: i64,
+    load_sum: f64,
+    load_max: f64,
+    samples: usize,
+    last_sample: Option<DateTime<Utc>>,
+}
+///
+///
+impl Cycle {
+    ///
+    /// Adds the load sample, the sample with the same timestamp as the last one is ignored
+    fn add(&mut self, load: f64, timestamp: DateTime<Utc>, alarm_class: i64) {
+        self.alarm_class = self.alarm_class.max(alarm_class);
+        if self.last_sample != Some(timestamp) {
+            self.last_sample = Some(timestamp);
+            self.load_sum += load;
+            self.load_max = if self.samples == 0 {load} else {self.load_max.max(load)};
+            self.samples += 1;
+        }
+    }
+}
+///
+/// Function | Operating cycle detector
+/// - the cycle opened when [start] input becomes true & closed when it becomes false
+/// - while the cycle is active, the [load] statistics & the max [alarmClass] are tracked
+/// - on close, the cycle record is built, the cycles shorter then [min_duration] are ignored
+/// - the record is JSON by default:
+/// ```json
+/// {"start": "2024-01-01T00:00:00Z", "stop": "2024-01-01T00:01:00Z", "duration": 60.0, "alarmClass": 0, "averageLoad": 12.5, "maxLoad": 20.0}
+/// ```
+/// - if [template] is configured, the record is formatted by the template, see [Format]
+/// with the same markers: {start}, {stop}, {duration}, {alarmClass}, {averageLoad}, {maxLoad}
+/// - the output holds the latest record, empty String until the first cycle closed
+/// - to be exported by 'ToApiQueue' the SQL producing [template] is required, the default JSON record can't be exported,
+/// 'ToApiQueue' sends the String points to the API as SQL:
+/// ```yaml
+/// template: "insert into operating_cycle (start, stop, alarm_class, average_load, max_load) values ('{start}', '{stop}', {alarmClass}, {averageLoad}, {maxLoad});"
+/// ```
+/// - time measured by the Point.timestamp of the [start] input
+/// - the record has the worst status of the inputs & the timestamp of the cycle stop
+#[derive(Debug)]
+pub struct FnOperatingCycle {
+    id: String,
+    kind: FnKind,
+    start: FnInOutRef,
+    load: FnInOutRef,
+    alarm_class: Option<FnInOutRef>,
+    min_duration: Duration,
+    template: Option<String>,
+    cycle: Option<Cycle>,
+    record: Point<String>,
+}
+///
+///
+impl FnOperatingCycle {
+    ///
+    /// Creates new instance of the FnOperatingCycle
+    /// - start - the cycle is active while true
+    /// - load - the load tracked during the cycle
+    /// - alarm_class - optional input, the max value during the cycle stored into the record
+    /// - min_duration - the cycles shorter then this are ignored
+    /// - template - optional template of the record, JSON used if not specified
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, start: FnInOutRef, load: FnInOutRef, alarm_class: Option<FnInOutRef>, min_duration: Duration, template: Option<String>) -> Self {
+        let id = format!("{}/FnOperatingCycle{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed));
+        let record = Point::new_string(0, &format!("{}.out", id), "");
+        Self {
+            id,
+            kind: FnKind::Fn,
+            start,
+            load,
+            alarm_class,
+            min_duration,
+            template,
+            cycle: None,
+            record,
+        }
+    }
+    ///
+    /// Returns the record of the closed cycle
+    fn record(&self, cycle: &Cycle, stop: DateTime<Utc>, duration: Duration) -> String {
+        let average_load = if cycle.samples > 0 {cycle.load_sum / cycle.samples as f64} else {0.0};
+        let start = cycle.start.to_rfc3339();
+        let stop = stop.to_rfc3339();
+        match &self.template {
+            Some(template) => {
+                let mut format = Format::new(template);
+                format.insert("start", start.to_point(0, "start"));
+                format.insert("stop", stop.to_point(0, "stop"));
+                format.insert("duration", duration.as_secs_f64().to_point(0, "duration"));
+                format.insert("alarmClass", cycle.alarm_class.to_point(0, "alarmClass"));
+                format.insert("averageLoad", average_load.to_point(0, "averageLoad"));
+                format.insert("maxLoad", cycle.load_max.to_point(0, "maxLoad"));
+                format.out()
+            }
+            None => serde_json::json!({
+                "start": start,
+                "stop": stop,
+                "duration": duration.as_secs_f64(),
+                "alarmClass": cycle.alarm_class,
+                "averageLoad": average_load,
+                "maxLoad": cycle.load_max,
+            }).to_string(),
+        }
+    }
+}
+///
+///
+impl FnIn for FnOperatingCycle {}
+///
+///
+impl FnOut for FnOperatingCycle {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = self.start.borrow().inputs();
+        inputs.extend(self.load.borrow().inputs());
+        if let Some(alarm_class) = &self.alarm_class {
+            inputs.extend(alarm_class.borrow().inputs());
+        }
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let start_point = self.start.borrow_mut().out();
+        let load_point = self.load.borrow_mut().out();
+        let alarm_class_point = self.alarm_class.as_ref().map(|alarm_class| alarm_class.borrow_mut().out());
+        let alarm_class = alarm_class_point.as_ref().map_or(0, |alarm_class| to_double(&self.id, alarm_class).round() as i64);
+        let active = to_bool(&self.id, &start_point);
+        let load = to_double(&self.id, &load_point);
+        match (active, self.cycle.take()) {
+            (true, None) => {
+                debug!("{}.out | Cycle started at {:?}", self.id, start_point.timestamp());
+                let mut cycle = Cycle {
+                    start: start_point.timestamp(),
+                    alarm_class,
+                    load_sum: 0.0,
+                    load_max: 0.0,
+                    samples: 0,
+                    last_sample: None,
+                };
+                cycle.add(load, load_point.timestamp(), alarm_class);
+                self.cycle = Some(cycle);
+            }
+            (true, Some(mut cycle)) => {
+                cycle.add(load, load_point.timestamp(), alarm_class);
+                self.cycle = Some(cycle);
+            }
+            (false, Some(cycle)) => {
+                let stop = start_point.timestamp();
+                let duration = stop.signed_duration_since(cycle.start).to_std().unwrap_or_default();
+                if duration < self.min_duration {
+                    debug!("{}.out | Cycle {:?} ignored, duration {:?} is less then min {:?}", self.id, cycle.start, duration, self.min_duration);
+                } else {
+                    let record = self.record(&cycle, stop, duration);
+                    debug!("{}.out | Cycle closed: {}", self.id, record);
+                    self.record = Point::new(
+                        *start_point.tx_id(),
+                        &format!("{}.out", self.id),
+                        record,
+                        worst_status([&start_point, &load_point].into_iter().chain(&alarm_class_point)),
+                        Cot::Inf,
+                        stop,
+                    );
+                }
+            }
+            (false, None) => {}
+        }
+        PointType::String(self.record.clone())
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.cycle = None;
+        self.start.borrow_mut().reset();
+        self.load.borrow_mut().reset();
+        if let Some(alarm_class) = &self.alarm_class {
+            alarm_class.borrow_mut().reset();
+        }
+    }
+}
+///
+///
+impl FnInOut for FnOperatingCycle {}
+///
+/// Global static counter of FnOperatingCycle instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
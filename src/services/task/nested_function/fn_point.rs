@@ -0,0 +1,141 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::{
+    conf::point_config::{point_config::PointConfig, point_config_type::PointConfigType},
+    core_::{cot::cot::Cot, point::{point::Point, point_type::PointType}, status::status::Status, types::{bool::Bool, fn_in_out_ref::FnInOutRef}},
+};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_conversion::{parse, to_int}, fn_kind::FnKind};
+///
+/// Function | Generated point
+/// Gives the input value configured name, type & Cot, 
+/// makes it ready to be exported, for example into the MultiQueue
+///  - name - the full name from the [PointConfig]
+///  - value - input value converted into the type from the [PointConfig], Real / Double into Int truncated, see [to_int]
+///  - tx_id - the tx_id of the owner Task
+///  - if String input can't be parsed into the configured type, the default value with Status::Invalid returned
+#[derive(Debug)]
+pub struct FnPoint {
+    id: String,
+    kind: FnKind,
+    tx_id: usize,
+    conf: PointConfig,
+    cot: Cot,
+    input: FnInOutRef,
+}
+///
+/// 
+impl FnPoint {
+    ///
+    /// Creates new instance of the FnPoint
+    /// - tx_id - the tx_id of the owner Task
+    /// - conf - configuration of the generated point
+    /// - cot - cause of transmission of the generated point
+    /// - input - the source of the point value
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, tx_id: usize, conf: PointConfig, cot: Cot, input: FnInOutRef) -> Self {
+        Self {
+            id: format!("{}/FnPoint{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            tx_id,
+            conf,
+            cot,
+            input,
+        }
+    }
+    ///
+    /// Returns generated point holding the given value
+    fn point<T>(&self, value: T, status: Status, timestamp: chrono::DateTime<chrono::Utc>) -> Point<T> {
+        Point::new(self.tx_id, &self.conf.name, value, status, self.cot, timestamp)
+    }
+}
+///
+/// 
+impl FnIn for FnPoint {}
+///
+/// 
+impl FnOut for FnPoint {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let input = self.input.borrow_mut().out();
+        let status = input.status();
+        let timestamp = input.timestamp();
+        let out = match self.conf._type {
+            PointConfigType::Bool => {
+                let (value, status) = match &input {
+                    PointType::Bool(p) => (p.value.0, status),
+                    PointType::Int(p) => (p.value != 0, status),
+                    PointType::Real(p) => (p.value != 0.0, status),
+                    PointType::Double(p) => (p.value != 0.0, status),
+                    PointType::String(p) => parse(&self.id, &p.value, status),
+                };
+                PointType::Bool(self.point(Bool(value), status, timestamp))
+            }
+            PointConfigType::Int => {
+                let (value, status) = match &input {
+                    PointType::Bool(p) => (if p.value.0 {1} else {0}, status),
+                    PointType::Int(p) => (p.value, status),
+                    PointType::Real(p) => (to_int(p.value as f64), status),
+                    PointType::Double(p) => (to_int(p.value), status),
+                    PointType::String(p) => parse(&self.id, &p.value, status),
+                };
+                PointType::Int(self.point(value, status, timestamp))
+            }
+            PointConfigType::Real => {
+                let (value, status) = match &input {
+                    PointType::Bool(p) => (if p.value.0 {1.0} else {0.0}, status),
+                    PointType::Int(p) => (p.value as f32, status),
+                    PointType::Real(p) => (p.value, status),
+                    PointType::Double(p) => (p.value as f32, status),
+                    PointType::String(p) => parse(&self.id, &p.value, status),
+                };
+                PointType::Real(self.point(value, status, timestamp))
+            }
+            PointConfigType::Double => {
+                let (value, status) = match &input {
+                    PointType::Bool(p) => (if p.value.0 {1.0} else {0.0}, status),
+                    PointType::Int(p) => (p.value as f64, status),
+                    PointType::Real(p) => (p.value as f64, status),
+                    PointType::Double(p) => (p.value, status),
+                    PointType::String(p) => parse(&self.id, &p.value, status),
+                };
+                PointType::Double(self.point(value, status, timestamp))
+            }
+            PointConfigType::String | PointConfigType::Json => {
+                let value = match &input {
+                    PointType::Bool(p) => p.value.0.to_string(),
+                    PointType::Int(p) => p.value.to_string(),
+                    PointType::Real(p) => p.value.to_string(),
+                    PointType::Double(p) => p.value.to_string(),
+                    PointType::String(p) => p.value.clone(),
+                };
+                PointType::String(self.point(value, status, timestamp))
+            }
+        };
+        debug!("{}.out | out: {:?}", self.id, out);
+        out
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.input.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnPoint {}
+///
+/// Global static counter of FnPoint instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -1,18 +1,22 @@
-use log::trace;
+use log::{error, trace};
 use std::sync::atomic::{AtomicUsize, Ordering};
 use crate::core_::{
+    cot::cot::Cot,
     types::{type_of::DebugTypeOf, fn_in_out_ref::FnInOutRef},
-    state::switch_state::{SwitchState, Switch, SwitchCondition}, 
-    point::{point_type::PointType, point::Point}, 
+    state::switch_state::{SwitchState, Switch, SwitchCondition},
+    point::{point_type::PointType, point::Point},
 };
-use super::{fn_::{FnInOut, FnOut, FnIn}, fn_kind::FnKind};
+use super::{fn_::{FnInOut, FnOut, FnIn}, fn_kind::FnKind, fn_status::propagate};
 ///
 /// Counts number of raised fronts of boolean input
+/// - Int / Real / Double input treated as true if > 0
+/// - if optional [reset] input is true, count returns to the [initial]
 #[derive(Debug)]
 pub struct FnPulseCount {
     id: String,
     kind: FnKind,
     input: FnInOutRef,
+    reset: Option<FnInOutRef>,
     state: SwitchState<bool, bool>,
     count: i64,
     initial: i64,
@@ -22,14 +26,18 @@ pub struct FnPulseCount {
 impl FnPulseCount {
     ///
     /// Creates new instance of the FnPulseCount
+    /// - initial - the count value at start & after reset
+    /// - input - the pulses to be counted
+    /// - reset - optional input, resets the count to the initial while true
     #[allow(dead_code)]
-    pub fn new(parent: impl Into<String>, initial: i64, input: FnInOutRef) -> Self {
-        Self { 
-            id: format!("{}/FnPulseCount{}", parent, COUNT.fetch_add(1, Ordering::Relaxed)),
+    pub fn new(parent: impl Into<String>, initial: i64, input: FnInOutRef, reset: Option<FnInOutRef>) -> Self {
+        Self {
+            id: format!("{}/FnPulseCount{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
             kind:FnKind::Fn,
             input,
+            reset,
             state: SwitchState::new(
-                false, 
+                false,
                 vec![
                     Switch {
                         state: false,
@@ -41,22 +49,33 @@ impl FnPulseCount {
                     Switch {
                         state: true,
                         conditions: vec![SwitchCondition {
-                            condition: Box::new(|_| {true}),
+                            condition: Box::new(|value: bool| {!value}),
                             target: false,
                         }],
                     },
                 ]
             ),
-            count: initial.clone(),
-            initial: initial,
+            count: initial,
+            initial,
+        }
+    }
+    ///
+    /// Returns bool value of the point, numeric > 0 treated as true
+    fn to_bool(&self, point: &PointType) -> bool {
+        match point {
+            PointType::Bool(point) => point.value.0,
+            PointType::Int(point) => point.value > 0,
+            PointType::Real(point) => point.value > 0.0,
+            PointType::Double(point) => point.value > 0.0,
+            _ => panic!("{}.out | {:?} type is not supported: {:?}", self.id, point.print_type_of(), point),
         }
     }
 }
 ///
-/// 
+///
 impl FnIn for FnPulseCount {}
 ///
-/// 
+///
 impl FnOut for FnPulseCount {
     //
     fn id(&self) -> String {
@@ -68,44 +87,62 @@ impl FnOut for FnPulseCount {
     }
     //
     fn inputs(&self) -> Vec<String> {
-        self.input.borrow().inputs()
+        let mut inputs = self.input.borrow().inputs();
+        if let Some(reset) = &self.reset {
+            inputs.extend(reset.borrow().inputs());
+        }
+        inputs
     }
     ///
     fn out(&mut self) -> PointType {
-        // trace!("{}.out | input: {:?}", self.id, self.input.print());
         let point = self.input.borrow_mut().out();
-        let value = match &point {
-            PointType::Bool(point) => point.value.0,
-            PointType::Int(point) => point.value > 0,
-            PointType::Real(point) => point.value > 0.0,
-            PointType::Double(point) => point.value > 0.0,
-            _ => panic!("{}.out | {:?} type is not supported: {:?}", self.id, point.typeOf(), point),
-        };
+        let value = self.to_bool(&point);
+        let reset_point = self.reset.as_ref().map(|reset| reset.borrow_mut().out());
+        let reset = reset_point.as_ref().map_or(false, |reset| self.to_bool(reset));
+        let prev = self.state.state();
         self.state.add(value);
         let state = self.state.state();
-        trace!("{}.out | input.out: {:?}   | state: {:?}", self.id, &value, state);
-        if state {
+        trace!("{}.out | input.out: {:?}   | state: {:?}   | reset: {:?}", self.id, &value, state, reset);
+        if reset {
+            self.count = self.initial;
+        } else if state && !prev {
             self.count += 1;
         }
-        PointType::Int(
+        let out = PointType::Int(
             Point {
+                tx_id: *point.tx_id(),
                 name: format!("{}.out", self.id),
                 value: self.count,
                 status: point.status(),
+                cot: Cot::Inf,
                 timestamp: point.timestamp(),
             }
-        )
+        );
+        propagate(out, &reset_point)
     }
     fn reset(&mut self) {
         self.count = self.initial;
         self.state.reset();
         self.input.borrow_mut().reset();
+        if let Some(reset) = &self.reset {
+            reset.borrow_mut().reset();
+        }
+    }
+    //
+    fn retained(&self) -> Option<PointType> {
+        Some(PointType::Int(Point::new_int(0, &format!("{}.out", self.id), self.count)))
+    }
+    //
+    fn restore(&mut self, state: PointType) {
+        match state {
+            PointType::Int(state) => self.count = state.value,
+            _ => error!("{}.restore | Int state expected, but found: {:?}", self.id, state),
+        }
     }
 }
 ///
-/// 
+///
 impl FnInOut for FnPulseCount {}
 ///
 /// Global static counter of FnOut instances
 static COUNT: AtomicUsize = AtomicUsize::new(1);
-
@@ -0,0 +1,94 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{cot::cot::Cot, point::{point::Point, point_type::PointType}, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_compare::to_double, fn_kind::FnKind};
+///
+/// Function | Linear scaling from the raw range into the engineering range
+/// FnScale ( input ) === outMin + (input - inMin) * (outMax - outMin) / (inMax - inMin)
+/// - Int / Real / Double input, the output is always Double, regardless of the input type,
+/// because the engineering value of the integer raw input is fractional in general,
+/// use 'ToInt' / 'ToReal' function to convert it if required
+/// - the input out of the raw range is extrapolated, use 'clamp' function to limit it
+#[derive(Debug)]
+pub struct FnScale {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+    in_min: f64,
+    in_max: f64,
+    out_min: f64,
+    out_max: f64,
+}
+///
+/// 
+impl FnScale {
+    ///
+    /// Creates new instance of the FnScale
+    /// - input - the raw value
+    /// - in_min, in_max - the raw range, must not be empty
+    /// - out_min, out_max - the engineering range
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef, in_min: f64, in_max: f64, out_min: f64, out_max: f64) -> Self {
+        let id = format!("{}/FnScale{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed));
+        if in_min == in_max {
+            panic!("{}.new | Raw range can't be empty, inMin: {}, inMax: {}", id, in_min, in_max);
+        }
+        Self { 
+            id,
+            kind: FnKind::Fn,
+            input,
+            in_min,
+            in_max,
+            out_min,
+            out_max,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnScale {}
+///
+///
+impl FnOut for FnScale {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        let input = to_double(&self.id, &point);
+        let value = self.out_min + (input - self.in_min) * (self.out_max - self.out_min) / (self.in_max - self.in_min);
+        debug!("{}.out | input: {:?}   |   value: {:?}", self.id, input, value);
+        PointType::Double(
+            Point {
+                tx_id: *point.tx_id(),
+                name: format!("{}.out", self.id),
+                value,
+                status: point.status(),
+                cot: Cot::Inf,
+                timestamp: point.timestamp(),
+            }
+        )
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.input.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnScale {}
+///
+/// Global static counter of FnScale instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
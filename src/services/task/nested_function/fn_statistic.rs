@@ -0,0 +1,182 @@
+use chrono::{DateTime, Utc};
+use log::debug;
+use std::{collections::VecDeque, sync::atomic::{AtomicUsize, Ordering}, time::Duration};
+use crate::core_::{cot::cot::Cot, point::{point::Point, point_type::PointType}, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_compare::to_double, fn_kind::FnKind, fn_logic::to_bool, fn_status::propagate};
+///
+/// The statistic calculated over the window
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StatisticOp {
+    Average,
+    Min,
+    Max,
+    /// Population standard deviation
+    StdDev,
+}
+///
+/// The size of the sliding window
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WindowSize {
+    /// The last N samples
+    Samples(usize),
+    /// The samples not older then the given duration relative to the latest sample,
+    /// the age of the samples measured by the Point.timestamp
+    Duration(Duration),
+}
+///
+/// Function | Sliding window statistic
+/// - Average / Min / Max / StdDev of the [input] over the [window]
+/// - Bool / Int / Real / Double input calculated as Double
+/// - new sample added to the window only if it's timestamp is newer then the latest consumed sample,
+/// so evaluation caused by the other inputs or by the Task cycle doesn't repeat the same sample,
+/// the same sample is not repeated after reset as well
+/// - the initial placeholder of the input is not used as a sample
+/// - 0.0 returned while the window is empty
+/// - if optional [reset] input is true, the window cleared and started from the next new sample
+#[derive(Debug)]
+pub struct FnStatistic {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+    reset: Option<FnInOutRef>,
+    op: StatisticOp,
+    size: WindowSize,
+    window: VecDeque<(f64, DateTime<Utc>)>,
+    latest: Option<DateTime<Utc>>,
+}
+///
+/// 
+impl FnStatistic {
+    ///
+    /// Creates new instance of the FnStatistic
+    /// - input - the samples to be calculated
+    /// - op - the statistic to be calculated
+    /// - size - the size of the sliding window
+    /// - reset - optional input, clears the window while true
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef, op: StatisticOp, size: WindowSize, reset: Option<FnInOutRef>) -> Self {
+        let id = format!("{}/FnStatistic{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed));
+        if size == WindowSize::Samples(0) {
+            panic!("{}.new | Window size must be greater then 0", id);
+        }
+        Self { 
+            id,
+            kind: FnKind::Fn,
+            input,
+            reset,
+            op,
+            size,
+            window: VecDeque::new(),
+            latest: None,
+        }
+    }
+    ///
+    /// Adds the sample into the window, removes samples out of the window
+    /// - the sample not newer then the latest consumed one is ignored
+    fn push(&mut self, value: f64, timestamp: DateTime<Utc>) {
+        if self.latest.is_some_and(|latest| timestamp <= latest) {
+            return;
+        }
+        self.latest = Some(timestamp);
+        self.window.push_back((value, timestamp));
+        match self.size {
+            WindowSize::Samples(samples) => {
+                while self.window.len() > samples {
+                    self.window.pop_front();
+                }
+            }
+            WindowSize::Duration(duration) => {
+                while let Some((_, oldest)) = self.window.front() {
+                    let age = timestamp.signed_duration_since(*oldest).to_std().unwrap_or_default();
+                    if age > duration {
+                        self.window.pop_front();
+                    } else {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+    ///
+    /// Returns the statistic over the current window
+    fn value(&self) -> f64 {
+        if self.window.is_empty() {
+            return 0.0;
+        }
+        let values = self.window.iter().map(|(value, _)| *value);
+        let len = self.window.len() as f64;
+        match self.op {
+            StatisticOp::Average => values.sum::<f64>() / len,
+            StatisticOp::Min => values.fold(f64::INFINITY, f64::min),
+            StatisticOp::Max => values.fold(f64::NEG_INFINITY, f64::max),
+            StatisticOp::StdDev => {
+                let average = self.window.iter().map(|(value, _)| *value).sum::<f64>() / len;
+                (values.map(|value| (value - average).powi(2)).sum::<f64>() / len).sqrt()
+            }
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnStatistic {}
+///
+///
+impl FnOut for FnStatistic {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = self.input.borrow().inputs();
+        if let Some(reset) = &self.reset {
+            inputs.extend(reset.borrow().inputs());
+        }
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        let reset_point = self.reset.as_ref().map(|reset| reset.borrow_mut().out());
+        let reset = reset_point.as_ref().map_or(false, |reset| to_bool(&self.id, reset));
+        if reset {
+            self.window.clear();
+        }
+        if !self.input.borrow().is_initial() {
+            self.push(to_double(&self.id, &point), point.timestamp());
+        }
+        let value = self.value();
+        debug!("{}.out | {:?}: {:?}   |   samples: {:?}   |   reset: {:?}", self.id, self.op, value, self.window.len(), reset);
+        let out = PointType::Double(
+            Point {
+                tx_id: *point.tx_id(),
+                name: format!("{}.out", self.id),
+                value,
+                status: point.status(),
+                cot: Cot::Inf,
+                timestamp: point.timestamp(),
+            }
+        );
+        propagate(out, &reset_point)
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.window.clear();
+        self.input.borrow_mut().reset();
+        if let Some(reset) = &self.reset {
+            reset.borrow_mut().reset();
+        }
+    }
+}
+///
+/// 
+impl FnInOut for FnStatistic {}
+///
+/// Global static counter of FnStatistic instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,36 @@
+//!
+//! Status & timestamp propagation policy of the nested functions
+//! - the output has the worst status of the inputs: Invalid > TimeInvalid > Obsolete > Ok
+//! - the output has the timestamp & tx_id of the latest input
+//! - the policy can be disabled for the function by the `status: ignore` parameter,
+//! see [FnStatusIgnore](super::fn_status_ignore::FnStatusIgnore)
+use crate::core_::{point::point_type::PointType, status::status::Status};
+///
+/// Returns the worst status of the points, Status::Ok if there is no points
+pub fn worst_status<'a>(points: impl IntoIterator<Item = &'a PointType>) -> Status {
+    points.into_iter().map(|point| point.status()).max().unwrap_or(Status::Ok)
+}
+///
+/// Returns the point having the latest timestamp, None if there is no points
+pub fn latest<'a>(points: impl IntoIterator<Item = &'a PointType>) -> Option<&'a PointType> {
+    points.into_iter().reduce(|latest, point| {
+        if point.timestamp() > latest.timestamp() {point} else {latest}
+    })
+}
+///
+/// Returns the [out] point having the worst status & the latest timestamp of the [out] itself & the [inputs]
+pub fn propagate<'a>(out: PointType, inputs: impl IntoIterator<Item = &'a PointType>) -> PointType {
+    let inputs: Vec<&PointType> = inputs.into_iter().collect();
+    let status = out.status().max(worst_status(inputs.iter().copied()));
+    let (tx_id, timestamp) = match latest(inputs.iter().copied()) {
+        Some(latest) if latest.timestamp() > out.timestamp() => (*latest.tx_id(), latest.timestamp()),
+        _ => (*out.tx_id(), out.timestamp()),
+    };
+    match out {
+        PointType::Bool(mut point) => {point.status = status; point.tx_id = tx_id; point.timestamp = timestamp; PointType::Bool(point)}
+        PointType::Int(mut point) => {point.status = status; point.tx_id = tx_id; point.timestamp = timestamp; PointType::Int(point)}
+        PointType::Real(mut point) => {point.status = status; point.tx_id = tx_id; point.timestamp = timestamp; PointType::Real(point)}
+        PointType::Double(mut point) => {point.status = status; point.tx_id = tx_id; point.timestamp = timestamp; PointType::Double(point)}
+        PointType::String(mut point) => {point.status = status; point.tx_id = tx_id; point.timestamp = timestamp; PointType::String(point)}
+    }
+}
@@ -0,0 +1,78 @@
+use log::trace;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{point::point_type::PointType, status::status::Status, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind};
+///
+/// Function | Disables the status propagation of the [input],
+/// the output of the [input] returned with Status::Ok
+/// - created by the NestedFn for each input of the function configured with `status: ignore`,
+/// so the own failures of the function (Status::Invalid) are still reported:
+/// ```yaml
+/// fn add:
+///     status: ignore
+///     input1: point real '/App/Load.Nominal'
+///     input2: const real 0.5
+/// ```
+#[derive(Debug)]
+pub struct FnStatusIgnore {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+}
+///
+///
+impl FnStatusIgnore {
+    ///
+    /// Creates new instance of the FnStatusIgnore
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef) -> Self {
+        Self {
+            id: format!("{}/FnStatusIgnore{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            input,
+        }
+    }
+}
+///
+///
+impl FnIn for FnStatusIgnore {}
+///
+///
+impl FnOut for FnStatusIgnore {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        trace!("{}.out | input: {:?}", self.id, point);
+        match point {
+            PointType::Bool(mut point) => {point.status = Status::Ok; PointType::Bool(point)}
+            PointType::Int(mut point) => {point.status = Status::Ok; PointType::Int(point)}
+            PointType::Real(mut point) => {point.status = Status::Ok; PointType::Real(point)}
+            PointType::Double(mut point) => {point.status = Status::Ok; PointType::Double(point)}
+            PointType::String(mut point) => {point.status = Status::Ok; PointType::String(point)}
+        }
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.input.borrow_mut().reset();
+    }
+}
+///
+///
+impl FnInOut for FnStatusIgnore {}
+///
+/// Global static counter of FnStatusIgnore instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,131 @@
+use indexmap::IndexMap;
+use log::{debug, warn};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{point::point_type::PointType, status::status::Status, types::{fn_in_out_ref::FnInOutRef, type_of::DebugTypeOf}};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_status::propagate};
+///
+/// Function | Multiplexer
+/// FnSwitch ( selector, case0, case1, ... caseN, default ) === caseX, where X = selector
+/// - Bool selector treated as 0 / 1, Real / Double selector rounded
+/// - if there is no case for the selector, the [default] returned,
+/// if [default] isn't configured - the first case with Status::Invalid returned
+/// - all inputs are evaluated on each call, so stateful functions in all the cases stay consistent
+/// - the output has the worst status & the latest timestamp of the selector & the selected case
+#[derive(Debug)]
+pub struct FnSwitch {
+    id: String,
+    kind: FnKind,
+    selector: FnInOutRef,
+    cases: IndexMap<i64, FnInOutRef>,
+    default: Option<FnInOutRef>,
+}
+///
+/// 
+impl FnSwitch {
+    ///
+    /// Creates new instance of the FnSwitch
+    /// - selector - the index of the case to be returned
+    /// - cases - the index & the input of the each case, at least one required
+    /// - default - optional input, returned if there is no case for the selector
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, selector: FnInOutRef, cases: IndexMap<i64, FnInOutRef>, default: Option<FnInOutRef>) -> Self {
+        let id = format!("{}/FnSwitch{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed));
+        if cases.is_empty() {
+            panic!("{}.new | At least one case required", id);
+        }
+        Self { 
+            id,
+            kind: FnKind::Fn,
+            selector,
+            cases,
+            default,
+        }
+    }
+    ///
+    /// Returns the selector value as the case index
+    fn to_index(&self, point: &PointType) -> i64 {
+        match point {
+            PointType::Bool(point) => if point.value.0 {1} else {0},
+            PointType::Int(point) => point.value,
+            PointType::Real(point) => point.value.round() as i64,
+            PointType::Double(point) => point.value.round() as i64,
+            _ => panic!("{}.out | {:?} type is not supported: {:?}", self.id, point.print_type_of(), point),
+        }
+    }
+    ///
+    /// Returns the point with Status::Invalid
+    fn invalid(point: PointType) -> PointType {
+        match point {
+            PointType::Bool(mut point) => {point.status = Status::Invalid; PointType::Bool(point)}
+            PointType::Int(mut point) => {point.status = Status::Invalid; PointType::Int(point)}
+            PointType::Real(mut point) => {point.status = Status::Invalid; PointType::Real(point)}
+            PointType::Double(mut point) => {point.status = Status::Invalid; PointType::Double(point)}
+            PointType::String(mut point) => {point.status = Status::Invalid; PointType::String(point)}
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnSwitch {}
+///
+///
+impl FnOut for FnSwitch {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        let mut inputs = self.selector.borrow().inputs();
+        for case in self.cases.values() {
+            inputs.extend(case.borrow().inputs());
+        }
+        if let Some(default) = &self.default {
+            inputs.extend(default.borrow().inputs());
+        }
+        inputs
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let selector = self.selector.borrow_mut().out();
+        let index = self.to_index(&selector);
+        let mut cases: IndexMap<i64, PointType> = self.cases.iter().map(|(index, case)| {
+            (*index, case.borrow_mut().out())
+        }).collect();
+        let default = self.default.as_ref().map(|default| default.borrow_mut().out());
+        debug!("{}.out | selector: {:?}", self.id, index);
+        let out = match cases.swap_remove(&index) {
+            Some(point) => point,
+            None => match default {
+                Some(default) => default,
+                None => {
+                    warn!("{}.out | There is no case for the selector {:?} and the default isn't configured", self.id, index);
+                    Self::invalid(cases.swap_remove_index(0).unwrap().1)
+                }
+            }
+        };
+        propagate(out, [&selector])
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.selector.borrow_mut().reset();
+        for case in self.cases.values() {
+            case.borrow_mut().reset();
+        }
+        if let Some(default) = &self.default {
+            default.borrow_mut().reset();
+        }
+    }
+}
+///
+/// 
+impl FnInOut for FnSwitch {}
+///
+/// Global static counter of FnSwitch instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -1,13 +1,13 @@
 #![allow(non_snake_case)]
 
-use log::debug;
+use log::{debug, error};
 use std::{time::Instant, sync::atomic::{AtomicUsize, Ordering}};
 
 use crate::core_::{
     cot::cot::Cot, point::{point::Point, point_type::PointType}, state::switch_state::{Switch, SwitchCondition, SwitchState}, types::{fn_in_out_ref::FnInOutRef, type_of::DebugTypeOf} 
 };
 
-use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_status::propagate};
 
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
@@ -23,11 +23,14 @@ enum FnTimerState {
 /// Counts elapsed time from raised input (>0) to dropped (<=0)
 /// - if repeat = true, then elapsed is total secods of 
 /// multiple periods
+/// - if optional [reset] input is true, elapsed returns to the [initial]
+/// and the timer returns to the initial state, so it counts again even if repeat = false
 #[derive(Debug)]
 pub struct FnTimer {
     id: String,
     kind: FnKind,
     input: FnInOutRef,
+    reset: Option<FnInOutRef>,
     state: SwitchState<FnTimerState, bool>,
     sessionElapsed: f64,
     initial: f64,
@@ -37,8 +40,14 @@ pub struct FnTimer {
 ///
 /// 
 impl FnTimer {
+    ///
+    /// Creates new instance of the FnTimer
+    /// - initial - elapsed seconds at start & after reset
+    /// - input - the timer is running while input is true (> 0)
+    /// - repeat - if false, the timer stops after the first period
+    /// - reset - optional input, resets elapsed to the initial while true
     #[allow(dead_code)]
-    pub fn new(parent: impl Into<String>, initial: impl Into<f64> + Clone, input: FnInOutRef, repeat: bool) -> Self {
+    pub fn new(parent: impl Into<String>, initial: impl Into<f64> + Clone, input: FnInOutRef, repeat: bool, reset: Option<FnInOutRef>) -> Self {
         let switches = vec![
             Switch{
                 state: FnTimerState::Off,
@@ -93,6 +102,7 @@ impl FnTimer {
             id: format!("{}/FnTimer{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
             kind: FnKind::Fn,
             input,
+            reset,
             state: SwitchState::new(FnTimerState::Off, switches),
             sessionElapsed: 0.0,
             initial: initial.clone().into(),
@@ -117,7 +127,11 @@ impl FnOut for FnTimer {
     }
     //
     fn inputs(&self) -> Vec<String> {
-        self.input.borrow().inputs()
+        let mut inputs = self.input.borrow().inputs();
+        if let Some(reset) = &self.reset {
+            inputs.extend(reset.borrow().inputs());
+        }
+        inputs
     }
     ///
     fn out(&mut self) -> PointType {
@@ -126,9 +140,26 @@ impl FnOut for FnTimer {
         let value = match &point {
             PointType::Bool(point) => point.value.0,
             PointType::Int(point) => point.value > 0,
+            PointType::Real(point) => point.value > 0.0,
             PointType::Double(point) => point.value > 0.0,
             _ => panic!("{}.out | {:?} type is not supported: {:?}", self.id, point.print_type_of(), point),
         };
+        let reset_point = self.reset.as_ref().map(|reset| reset.borrow_mut().out());
+        if let Some(reset) = &reset_point {
+            let reset = match reset {
+                PointType::Bool(point) => point.value.0,
+                PointType::Int(point) => point.value > 0,
+                PointType::Real(point) => point.value > 0.0,
+                PointType::Double(point) => point.value > 0.0,
+                _ => panic!("{}.out | {:?} type is not supported: {:?}", self.id, reset.print_type_of(), reset),
+            };
+            if reset {
+                self.start = None;
+                self.sessionElapsed = 0.0;
+                self.totalElapsed = self.initial;
+                self.state.reset();
+            }
+        }
         self.state.add(value);
         let state = self.state.state();
         debug!("{}.out | input.out: {:?}   |   state: {:?}", self.id, &value, &state);
@@ -153,7 +184,7 @@ impl FnOut for FnTimer {
                 }
             }
         };
-        PointType::Double(
+        let out = PointType::Double(
             Point {
                 tx_id: *point.tx_id(),
                 name: format!("{}.out", self.id),
@@ -162,7 +193,8 @@ impl FnOut for FnTimer {
                 cot: Cot::Inf,
                 timestamp: point.timestamp(),
             }
-        )
+        );
+        propagate(out, &reset_point)
     }
     ///
     /// 
@@ -172,6 +204,25 @@ impl FnOut for FnTimer {
         self.totalElapsed = self.initial;
         self.state.reset();
         self.input.borrow_mut().reset();
+        if let Some(reset) = &self.reset {
+            reset.borrow_mut().reset();
+        }
+    }
+    //
+    // The total elapsed seconds, including the running session
+    fn retained(&self) -> Option<PointType> {
+        let elapsed = self.totalElapsed + self.start.map_or(0.0, |start| start.elapsed().as_secs_f64());
+        Some(PointType::Double(Point::new_double(0, &format!("{}.out", self.id), elapsed)))
+    }
+    //
+    fn restore(&mut self, state: PointType) {
+        match state {
+            PointType::Double(state) => {
+                self.sessionElapsed = 0.0;
+                self.totalElapsed = state.value;
+            }
+            _ => error!("{}.restore | Double state expected, but found: {:?}", self.id, state),
+        }
     }
 }
 ///
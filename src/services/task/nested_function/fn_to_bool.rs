@@ -0,0 +1,99 @@
+use log::trace;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use concat_string::concat_string;
+use crate::{
+    core_::{point::{point::Point, point_type::PointType}, types::{bool::Bool, fn_in_out_ref::FnInOutRef}},
+    services::task::nested_function::{
+        fn_::{FnIn, FnInOut, FnOut},
+        fn_conversion::parse,
+        fn_kind::FnKind,
+    },
+};
+///
+/// Function converts input to Bool
+///  - int, real, double: non-zero -> true, zero -> false
+///  - string: try to parse 'true' / 'false' or number, if failed - false with Status::Invalid
+#[derive(Debug)]
+pub struct FnToBool {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+}
+///
+/// 
+impl FnToBool {
+    ///
+    /// Creates new instance of the FnToBool
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef) -> Self {
+        Self { 
+            id: format!("{}/FnToBool{}", parent.into(), COUNT.fetch_add(1, Ordering::SeqCst)),
+            kind: FnKind::Fn,
+            input,
+        }
+    }    
+}
+///
+/// 
+impl FnIn for FnToBool {}
+///
+/// 
+impl FnOut for FnToBool { 
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        trace!("{}.out | input: {:?}", self.id, point);
+        let (out, status) = match &point {
+            PointType::Bool(value) => {
+                (value.value.0, point.status())
+            }
+            PointType::Int(value) => {
+                (value.value != 0, point.status())
+            }
+            PointType::Real(value) => {
+                (value.value != 0.0, point.status())
+            }
+            PointType::Double(value) => {
+                (value.value != 0.0, point.status())
+            }
+            PointType::String(value) => {
+                parse(&self.id, &value.value, point.status())
+            }
+        };
+        trace!("{}.out | out: {:?}", self.id, &out);
+        PointType::Bool(
+            Point {
+                tx_id: *point.tx_id(),
+                name: concat_string!(self.id, ".out"),
+                value: Bool(out),
+                status,
+                cot: point.cot(),
+                timestamp: point.timestamp(),
+            }
+        )
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.input.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnToBool {}
+///
+/// Global static counter of FnToBool instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -0,0 +1,100 @@
+use log::trace;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use concat_string::concat_string;
+use crate::{
+    core_::{point::{point::Point, point_type::PointType}, types::fn_in_out_ref::FnInOutRef},
+    services::task::nested_function::{
+        fn_::{FnIn, FnInOut, FnOut},
+        fn_conversion::parse,
+        fn_kind::FnKind,
+    },
+};
+///
+/// Function converts input to Double
+///  - bool: true -> 1.0, false -> 0.0
+///  - int, real: as f64
+///  - string: try to parse double, if failed - 0.0 with Status::Invalid
+#[derive(Debug)]
+pub struct FnToDouble {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+}
+///
+/// 
+impl FnToDouble {
+    ///
+    /// Creates new instance of the FnToDouble
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef) -> Self {
+        Self { 
+            id: format!("{}/FnToDouble{}", parent.into(), COUNT.fetch_add(1, Ordering::SeqCst)),
+            kind: FnKind::Fn,
+            input,
+        }
+    }    
+}
+///
+/// 
+impl FnIn for FnToDouble {}
+///
+/// 
+impl FnOut for FnToDouble { 
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        trace!("{}.out | input: {:?}", self.id, point);
+        let (out, status) = match &point {
+            PointType::Bool(value) => {
+                (if value.value.0 {1.0} else {0.0}, point.status())
+            }
+            PointType::Int(value) => {
+                (value.value as f64, point.status())
+            }
+            PointType::Real(value) => {
+                (value.value as f64, point.status())
+            }
+            PointType::Double(value) => {
+                (value.value, point.status())
+            }
+            PointType::String(value) => {
+                parse(&self.id, &value.value, point.status())
+            }
+        };
+        trace!("{}.out | out: {:?}", self.id, &out);
+        PointType::Double(
+            Point {
+                tx_id: *point.tx_id(),
+                name: concat_string!(self.id, ".out"),
+                value: out,
+                status,
+                cot: point.cot(),
+                timestamp: point.timestamp(),
+            }
+        )
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.input.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnToDouble {}
+///
+/// Global static counter of FnToDouble instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -2,17 +2,18 @@ use log::trace;
 use std::sync::atomic::{AtomicUsize, Ordering};
 use concat_string::concat_string;
 use crate::{
-    core_::{point::{point::Point, point_type::PointType}, types::{fn_in_out_ref::FnInOutRef, type_of::DebugTypeOf}},
+    core_::{point::{point::Point, point_type::PointType}, types::fn_in_out_ref::FnInOutRef},
     services::task::nested_function::{
         fn_::{FnIn, FnInOut, FnOut},
+        fn_conversion::{parse, to_int},
         fn_kind::FnKind,
     },
 };
 ///
 /// Function converts input to Int
 ///  - bool: true -> 1, false -> 0
-///  - real: 0.1 -> 0 | 0.5 -> 0 | 0.9 -> 0 | 1.1 -> 1
-///  - string: try to parse int
+///  - real / double: truncated, see [to_int]: 0.1 -> 0 | 0.5 -> 0 | 0.9 -> 0 | 1.1 -> 1 | -1.9 -> -1
+///  - string: try to parse int, if failed - 0 with Status::Invalid
 #[derive(Debug)]
 pub struct FnToInt {
     id: String,
@@ -56,20 +57,22 @@ impl FnOut for FnToInt {
     fn out(&mut self) -> PointType {
         let point = self.input.borrow_mut().out();
         trace!("{}.out | input: {:?}", self.id, point);
-        let out = match &point {
+        let (out, status) = match &point {
             PointType::Bool(value) => {
-                if value.value.0 {1} else {0}
+                (if value.value.0 {1} else {0}, point.status())
             }
             PointType::Int(value) => {
-                value.value
+                (value.value, point.status())
             }
             PointType::Real(value) => {
-                value.value.trunc() as i64
+                (to_int(value.value as f64), point.status())
             }
             PointType::Double(value) => {
-                value.value.trunc() as i64
+                (to_int(value.value as f64), point.status())
+            }
+            PointType::String(value) => {
+                parse(&self.id, &value.value, point.status())
             }
-            _ => panic!("{}.out | {:?} type is not supported: {:?}", self.id, point.print_type_of(), point),
         };
         trace!("{}.out | out: {:?}", self.id, &out);
         PointType::Int(
@@ -77,7 +80,7 @@ impl FnOut for FnToInt {
                 tx_id: *point.tx_id(),
                 name: concat_string!(self.id, ".out"),
                 value: out,
-                status: point.status(),
+                status,
                 cot: point.cot(),
                 timestamp: point.timestamp(),
             }
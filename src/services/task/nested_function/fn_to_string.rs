@@ -0,0 +1,99 @@
+use log::trace;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use concat_string::concat_string;
+use crate::{
+    core_::{point::{point::Point, point_type::PointType}, types::fn_in_out_ref::FnInOutRef},
+    services::task::nested_function::{
+        fn_::{FnIn, FnInOut, FnOut},
+        fn_kind::FnKind,
+    },
+};
+///
+/// Function converts input to String
+///  - bool: 'true' / 'false'
+///  - int, real, double: decimal representation
+///  - string: as is
+#[derive(Debug)]
+pub struct FnToString {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+}
+///
+/// 
+impl FnToString {
+    ///
+    /// Creates new instance of the FnToString
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef) -> Self {
+        Self { 
+            id: format!("{}/FnToString{}", parent.into(), COUNT.fetch_add(1, Ordering::SeqCst)),
+            kind: FnKind::Fn,
+            input,
+        }
+    }    
+}
+///
+/// 
+impl FnIn for FnToString {}
+///
+/// 
+impl FnOut for FnToString { 
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        trace!("{}.out | input: {:?}", self.id, point);
+        let out = match &point {
+            PointType::Bool(value) => {
+                value.value.0.to_string()
+            }
+            PointType::Int(value) => {
+                value.value.to_string()
+            }
+            PointType::Real(value) => {
+                value.value.to_string()
+            }
+            PointType::Double(value) => {
+                value.value.to_string()
+            }
+            PointType::String(value) => {
+                value.value.clone()
+            }
+        };
+        trace!("{}.out | out: {:?}", self.id, &out);
+        PointType::String(
+            Point {
+                tx_id: *point.tx_id(),
+                name: concat_string!(self.id, ".out"),
+                value: out,
+                status: point.status(),
+                cot: point.cot(),
+                timestamp: point.timestamp(),
+            }
+        )
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.input.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnToString {}
+///
+/// Global static counter of FnToString instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
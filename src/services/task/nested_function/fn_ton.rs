@@ -0,0 +1,95 @@
+use log::debug;
+use std::{sync::atomic::{AtomicUsize, Ordering}, time::{Duration, Instant}};
+use crate::core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_iec_timer::{out_point, IecTimerElapsed, IecTimerOut}, fn_kind::FnKind, fn_logic::to_bool};
+///
+/// Function | On-delay timer (IEC 61131 TON)
+/// - Q becomes true when the input stays true for the preset time
+/// - Q & elapsed are reset as soon as the input becomes false
+/// - Int / Real / Double input coerced to Bool as non-zero
+/// - returns Q (Bool) or elapsed seconds (Double) depending on [out]
+/// - the elapsed of the same evaluation available through the [elapsed](FnTon::elapsed)
+#[derive(Debug)]
+pub struct FnTon {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+    preset: Duration,
+    out: IecTimerOut,
+    elapsed: IecTimerElapsed,
+    start: Option<Instant>,
+}
+///
+/// 
+impl FnTon {
+    ///
+    /// Creates new instance of the FnTon
+    /// - input - the timer input (IN)
+    /// - preset - the timer preset (PT)
+    /// - out - Q or elapsed time to be returned
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef, preset: Duration, out: IecTimerOut) -> Self {
+        Self { 
+            id: format!("{}/FnTon{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            input,
+            preset,
+            out,
+            elapsed: IecTimerElapsed::default(),
+            start: None,
+        }
+    }
+    ///
+    /// Returns the elapsed time of the latest evaluation, shared with the [FnIecElapsed](super::fn_iec_timer::FnIecElapsed)
+    pub fn elapsed(&self) -> IecTimerElapsed {
+        self.elapsed.clone()
+    }
+}
+///
+/// 
+impl FnIn for FnTon {}
+///
+///
+impl FnOut for FnTon {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        let input = to_bool(&self.id, &point);
+        let elapsed = if input {
+            let start = *self.start.get_or_insert_with(Instant::now);
+            start.elapsed().min(self.preset)
+        } else {
+            self.start = None;
+            Duration::ZERO
+        };
+        let q = input && elapsed >= self.preset;
+        debug!("{}.out | input: {:?}   |   q: {:?}   |   elapsed: {:?}", self.id, input, q, elapsed);
+        out_point(&self.id, &point, &self.out, q, elapsed, &self.elapsed)
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.start = None;
+        self.elapsed.replace(None);
+        self.input.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnTon {}
+///
+/// Global static counter of FnTon instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
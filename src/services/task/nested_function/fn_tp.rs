@@ -0,0 +1,100 @@
+use log::debug;
+use std::{sync::atomic::{AtomicUsize, Ordering}, time::{Duration, Instant}};
+use crate::core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_iec_timer::{out_point, IecTimerElapsed, IecTimerOut}, fn_kind::FnKind, fn_logic::to_bool};
+///
+/// Function | Pulse timer (IEC 61131 TP)
+/// - Q becomes true for the preset time on the rising edge of the input
+/// - the pulse can't be restarted until it's finished
+/// - elapsed is reset when the pulse is finished and the input is false
+/// - Int / Real / Double input coerced to Bool as non-zero
+/// - returns Q (Bool) or elapsed seconds (Double) depending on [out]
+/// - the elapsed of the same evaluation available through the [elapsed](FnTp::elapsed)
+#[derive(Debug)]
+pub struct FnTp {
+    id: String,
+    kind: FnKind,
+    input: FnInOutRef,
+    preset: Duration,
+    out: IecTimerOut,
+    elapsed: IecTimerElapsed,
+    start: Option<Instant>,
+    prev: bool,
+}
+///
+/// 
+impl FnTp {
+    ///
+    /// Creates new instance of the FnTp
+    /// - input - the timer input (IN)
+    /// - preset - the timer preset (PT)
+    /// - out - Q or elapsed time to be returned
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, input: FnInOutRef, preset: Duration, out: IecTimerOut) -> Self {
+        Self { 
+            id: format!("{}/FnTp{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed)),
+            kind: FnKind::Fn,
+            input,
+            preset,
+            out,
+            elapsed: IecTimerElapsed::default(),
+            start: None,
+            prev: false,
+        }
+    }
+    ///
+    /// Returns the elapsed time of the latest evaluation, shared with the [FnIecElapsed](super::fn_iec_timer::FnIecElapsed)
+    pub fn elapsed(&self) -> IecTimerElapsed {
+        self.elapsed.clone()
+    }
+}
+///
+/// 
+impl FnIn for FnTp {}
+///
+///
+impl FnOut for FnTp {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.input.borrow().inputs()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let point = self.input.borrow_mut().out();
+        let input = to_bool(&self.id, &point);
+        if input && !self.prev && self.start.is_none() {
+            self.start = Some(Instant::now());
+        }
+        self.prev = input;
+        let elapsed = self.start.map_or(Duration::ZERO, |start| start.elapsed().min(self.preset));
+        let q = self.start.is_some() && elapsed < self.preset;
+        if !q && !input {
+            self.start = None;
+        }
+        debug!("{}.out | input: {:?}   |   q: {:?}   |   elapsed: {:?}", self.id, input, q, elapsed);
+        out_point(&self.id, &point, &self.out, q, elapsed, &self.elapsed)
+    }
+    //
+    //
+    fn reset(&mut self) {
+        self.start = None;
+        self.elapsed.replace(None);
+        self.prev = false;
+        self.input.borrow_mut().reset();
+    }
+}
+///
+/// 
+impl FnInOut for FnTp {}
+///
+/// Global static counter of FnTp instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
@@ -47,6 +47,10 @@ impl FnOut for FnVar {
     fn inputs(&self) -> Vec<String> {
         self.input.borrow().inputs()
     }
+    //
+    fn is_initial(&self) -> bool {
+        self.input.borrow().is_initial()
+    }
     /// Returns nothing, 
     /// - Evaluetes all calculations,
     /// - Result stores into inner
@@ -0,0 +1,73 @@
+use log::debug;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use crate::core_::{point::point_type::PointType, types::fn_in_out_ref::FnInOutRef};
+use super::{fn_::{FnInOut, FnIn, FnOut}, fn_kind::FnKind, fn_logic::{out_point, to_bool}};
+///
+/// Function | Logical XOR
+/// FnXor ( input1, input2, ... inputN ) === input1 ^ input2 ^ ... inputN (true if odd number of inputs is true)
+/// - Int / Real / Double inputs coerced to Bool as non-zero
+#[derive(Debug)]
+pub struct FnXor {
+    id: String,
+    kind: FnKind,
+    inputs: Vec<FnInOutRef>,
+}
+///
+/// 
+impl FnXor {
+    ///
+    /// Creates new instance of the FnXor
+    /// - inputs - at least one input required
+    #[allow(dead_code)]
+    pub fn new(parent: impl Into<String>, inputs: Vec<FnInOutRef>) -> Self {
+        let id = format!("{}/FnXor{}", parent.into(), COUNT.fetch_add(1, Ordering::Relaxed));
+        if inputs.is_empty() {
+            panic!("{}.new | At least one input required", id);
+        }
+        Self { 
+            id,
+            kind: FnKind::Fn,
+            inputs,
+        }
+    }
+}
+///
+/// 
+impl FnIn for FnXor {}
+///
+///
+impl FnOut for FnXor {
+    //
+    fn id(&self) -> String {
+        self.id.clone()
+    }
+    //
+    fn kind(&self) -> &FnKind {
+        &self.kind
+    }
+    //
+    fn inputs(&self) -> Vec<String> {
+        self.inputs.iter().flat_map(|input| input.borrow().inputs()).collect()
+    }
+    //
+    //
+    fn out(&mut self) -> PointType {
+        let points: Vec<PointType> = self.inputs.iter().map(|input| input.borrow_mut().out()).collect();
+        let value = points.iter().fold(false, |acc, point| acc ^ to_bool(&self.id, point));
+        debug!("{}.out | value: {:?}", self.id, &value);
+        out_point(&self.id, &points, value)
+    }
+    //
+    //
+    fn reset(&mut self) {
+        for input in &self.inputs {
+            input.borrow_mut().reset();
+        }
+    }
+}
+///
+/// 
+impl FnInOut for FnXor {}
+///
+/// Global static counter of FnXor instances
+static COUNT: AtomicUsize = AtomicUsize::new(1);
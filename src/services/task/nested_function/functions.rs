@@ -20,6 +20,51 @@ pub enum Functions {
     PointId,
     Debug,
     ToInt,
+    Sub,
+    Mul,
+    Div,
+    Mod,
+    Pow,
+    Gt,
+    Lt,
+    Le,
+    Eq,
+    Ne,
+    And,
+    Or,
+    Xor,
+    Not,
+    PulseCount,
+    RisingEdge,
+    FallingEdge,
+    AnyEdge,
+    Ton,
+    Tof,
+    Tp,
+    LatchSet,
+    LatchReset,
+    If,
+    Switch,
+    Hysteresis,
+    Average,
+    Min,
+    Max,
+    StdDev,
+    Integral,
+    Derivative,
+    Rate,
+    Scale,
+    Clamp,
+    Interpolate,
+    ToReal,
+    ToDouble,
+    ToBool,
+    ToString,
+    Format,
+    Concat,
+    Expr,
+    OperatingCycle,
+    FaultRecorder,
 }
 ///
 /// 
@@ -37,6 +82,51 @@ impl Functions {
     const POINT_ID: &'static str = "PointId";
     const DEBUG: &'static str = "debug";
     const TO_INT: &'static str = "ToInt";
+    const SUB: &'static str = "sub";
+    const MUL: &'static str = "mul";
+    const DIV: &'static str = "div";
+    const MOD: &'static str = "mod";
+    const POW: &'static str = "pow";
+    const GT: &'static str = "gt";
+    const LT: &'static str = "lt";
+    const LE: &'static str = "le";
+    const EQ: &'static str = "eq";
+    const NE: &'static str = "ne";
+    const AND: &'static str = "and";
+    const OR: &'static str = "or";
+    const XOR: &'static str = "xor";
+    const NOT: &'static str = "not";
+    const PULSE_COUNT: &'static str = "pulseCount";
+    const RISING_EDGE: &'static str = "risingEdge";
+    const FALLING_EDGE: &'static str = "fallingEdge";
+    const ANY_EDGE: &'static str = "anyEdge";
+    const TON: &'static str = "ton";
+    const TOF: &'static str = "tof";
+    const TP: &'static str = "tp";
+    const LATCH_SET: &'static str = "latchSet";
+    const LATCH_RESET: &'static str = "latchReset";
+    const IF: &'static str = "if";
+    const SWITCH: &'static str = "switch";
+    const HYSTERESIS: &'static str = "hysteresis";
+    const AVERAGE: &'static str = "average";
+    const MIN: &'static str = "min";
+    const MAX: &'static str = "max";
+    const STD_DEV: &'static str = "stddev";
+    const INTEGRAL: &'static str = "integral";
+    const DERIVATIVE: &'static str = "derivative";
+    const RATE: &'static str = "rate";
+    const SCALE: &'static str = "scale";
+    const CLAMP: &'static str = "clamp";
+    const INTERPOLATE: &'static str = "interpolate";
+    const TO_REAL: &'static str = "ToReal";
+    const TO_DOUBLE: &'static str = "ToDouble";
+    const TO_BOOL: &'static str = "ToBool";
+    const TO_STRING: &'static str = "ToString";
+    const FORMAT: &'static str = "format";
+    const CONCAT: &'static str = "concat";
+    const EXPR: &'static str = "expr";
+    const OPERATING_CYCLE: &'static str = "operatingCycle";
+    const FAULT_RECORDER: &'static str = "faultRecorder";
     ///
     ///     
     pub fn name(&self) -> &str {
@@ -54,6 +144,51 @@ impl Functions {
             Functions::PointId          => Self::POINT_ID,
             Functions::Debug            => Self::DEBUG,
             Functions::ToInt            => Self::TO_INT,
+            Functions::Sub              => Self::SUB,
+            Functions::Mul              => Self::MUL,
+            Functions::Div              => Self::DIV,
+            Functions::Mod              => Self::MOD,
+            Functions::Pow              => Self::POW,
+            Functions::Gt               => Self::GT,
+            Functions::Lt               => Self::LT,
+            Functions::Le               => Self::LE,
+            Functions::Eq               => Self::EQ,
+            Functions::Ne               => Self::NE,
+            Functions::And              => Self::AND,
+            Functions::Or               => Self::OR,
+            Functions::Xor              => Self::XOR,
+            Functions::Not              => Self::NOT,
+            Functions::PulseCount       => Self::PULSE_COUNT,
+            Functions::RisingEdge       => Self::RISING_EDGE,
+            Functions::FallingEdge      => Self::FALLING_EDGE,
+            Functions::AnyEdge          => Self::ANY_EDGE,
+            Functions::Ton              => Self::TON,
+            Functions::Tof              => Self::TOF,
+            Functions::Tp               => Self::TP,
+            Functions::LatchSet         => Self::LATCH_SET,
+            Functions::LatchReset       => Self::LATCH_RESET,
+            Functions::If               => Self::IF,
+            Functions::Switch           => Self::SWITCH,
+            Functions::Hysteresis       => Self::HYSTERESIS,
+            Functions::Average          => Self::AVERAGE,
+            Functions::Min              => Self::MIN,
+            Functions::Max              => Self::MAX,
+            Functions::StdDev           => Self::STD_DEV,
+            Functions::Integral         => Self::INTEGRAL,
+            Functions::Derivative       => Self::DERIVATIVE,
+            Functions::Rate             => Self::RATE,
+            Functions::Scale            => Self::SCALE,
+            Functions::Clamp            => Self::CLAMP,
+            Functions::Interpolate      => Self::INTERPOLATE,
+            Functions::ToReal           => Self::TO_REAL,
+            Functions::ToDouble         => Self::TO_DOUBLE,
+            Functions::ToBool           => Self::TO_BOOL,
+            Functions::ToString         => Self::TO_STRING,
+            Functions::Format           => Self::FORMAT,
+            Functions::Concat           => Self::CONCAT,
+            Functions::Expr             => Self::EXPR,
+            Functions::OperatingCycle   => Self::OPERATING_CYCLE,
+            Functions::FaultRecorder    => Self::FAULT_RECORDER,
         }
     }
 }
@@ -77,6 +212,51 @@ impl FromStr for Functions {
             Self::POINT_ID          => Ok( Functions::PointId ),
             Self::DEBUG             => Ok( Functions::Debug ),
             Self::TO_INT            => Ok( Functions::ToInt ),
+            Self::SUB               => Ok( Functions::Sub ),
+            Self::MUL               => Ok( Functions::Mul ),
+            Self::DIV               => Ok( Functions::Div ),
+            Self::MOD               => Ok( Functions::Mod ),
+            Self::POW               => Ok( Functions::Pow ),
+            Self::GT                => Ok( Functions::Gt ),
+            Self::LT                => Ok( Functions::Lt ),
+            Self::LE                => Ok( Functions::Le ),
+            Self::EQ                => Ok( Functions::Eq ),
+            Self::NE                => Ok( Functions::Ne ),
+            Self::AND               => Ok( Functions::And ),
+            Self::OR                => Ok( Functions::Or ),
+            Self::XOR               => Ok( Functions::Xor ),
+            Self::NOT               => Ok( Functions::Not ),
+            Self::PULSE_COUNT       => Ok( Functions::PulseCount ),
+            Self::RISING_EDGE       => Ok( Functions::RisingEdge ),
+            Self::FALLING_EDGE      => Ok( Functions::FallingEdge ),
+            Self::ANY_EDGE          => Ok( Functions::AnyEdge ),
+            Self::TON               => Ok( Functions::Ton ),
+            Self::TOF               => Ok( Functions::Tof ),
+            Self::TP                => Ok( Functions::Tp ),
+            Self::LATCH_SET         => Ok( Functions::LatchSet ),
+            Self::LATCH_RESET       => Ok( Functions::LatchReset ),
+            Self::IF                => Ok( Functions::If ),
+            Self::SWITCH            => Ok( Functions::Switch ),
+            Self::HYSTERESIS        => Ok( Functions::Hysteresis ),
+            Self::AVERAGE           => Ok( Functions::Average ),
+            Self::MIN               => Ok( Functions::Min ),
+            Self::MAX               => Ok( Functions::Max ),
+            Self::STD_DEV           => Ok( Functions::StdDev ),
+            Self::INTEGRAL          => Ok( Functions::Integral ),
+            Self::DERIVATIVE        => Ok( Functions::Derivative ),
+            Self::RATE              => Ok( Functions::Rate ),
+            Self::SCALE             => Ok( Functions::Scale ),
+            Self::CLAMP             => Ok( Functions::Clamp ),
+            Self::INTERPOLATE       => Ok( Functions::Interpolate ),
+            Self::TO_REAL           => Ok( Functions::ToReal ),
+            Self::TO_DOUBLE         => Ok( Functions::ToDouble ),
+            Self::TO_BOOL           => Ok( Functions::ToBool ),
+            Self::TO_STRING         => Ok( Functions::ToString ),
+            Self::FORMAT            => Ok( Functions::Format ),
+            Self::CONCAT            => Ok( Functions::Concat ),
+            Self::EXPR              => Ok( Functions::Expr ),
+            Self::OPERATING_CYCLE   => Ok( Functions::OperatingCycle ),
+            Self::FAULT_RECORDER    => Ok( Functions::FaultRecorder ),
             _ => Err(format!("Functions.from_str | Unknown function name '{}'", &input)),
         }
     }
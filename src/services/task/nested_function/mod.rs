@@ -5,12 +5,61 @@ pub mod fn_add;
 pub mod fn_input;
 pub mod fn_count;
 pub mod fn_ge;
+pub mod fn_gt;
+pub mod fn_lt;
+pub mod fn_le;
+pub mod fn_eq;
+pub mod fn_ne;
+pub mod fn_compare;
+pub mod fn_and;
+pub mod fn_or;
+pub mod fn_xor;
+pub mod fn_not;
+pub mod fn_logic;
+pub mod fn_rising_edge;
+pub mod fn_falling_edge;
+pub mod fn_any_edge;
+pub mod fn_edge;
 pub mod fn_timer;
+pub mod fn_pulse_count;
+pub mod fn_ton;
+pub mod fn_tof;
+pub mod fn_tp;
+pub mod fn_iec_timer;
+pub mod fn_latch;
+pub mod fn_if;
+pub mod fn_switch;
+pub mod fn_hysteresis;
+pub mod fn_statistic;
+pub mod fn_integral;
+pub mod fn_derivative;
+pub mod fn_scale;
+pub mod fn_clamp;
+pub mod fn_interpolate;
+pub mod fn_format;
+pub mod fn_concat;
+pub mod fn_expr;
+pub mod fn_operating_cycle;
+pub mod fn_fault_recorder;
+pub mod fn_status;
+pub mod fn_status_ignore;
 pub mod fn_var;
 pub mod fn_const;
 pub mod fn_point_id;
 pub mod fn_debug;
 pub mod fn_to_int;
+pub mod fn_to_real;
+pub mod fn_to_double;
+pub mod fn_to_bool;
+pub mod fn_to_string;
+pub mod fn_conversion;
+pub mod fn_point;
+pub mod fn_sub;
+pub mod fn_mul;
+pub mod fn_div;
+pub mod fn_mod;
+pub mod fn_pow;
+pub mod type_promotion;
 
 pub mod nested_fn;
 
@@ -1,13 +1,28 @@
-use std::{rc::Rc, cell::RefCell, str::FromStr, sync::{mpsc::Sender, Arc, Mutex}};
+use std::{env, path::PathBuf, rc::Rc, cell::RefCell, str::FromStr, sync::{mpsc::Sender, Arc, Mutex}, time::Duration};
+use indexmap::IndexMap;
 use log::{debug, LevelFilter};
 use crate::{
-    conf::{fn_::{fn_conf_keywd::FnConfPointType, fn_conf_kind::FnConfKind}, point_config::{name::Name, point_config::PointConfig}}, core_::{
-        point::point_type::{PointType, ToPoint},
+    conf::{conf_duration::ConfDuration, fn_::{fn_conf_keywd::FnConfPointType, fn_conf_kind::FnConfKind, fn_config::FnConfig}, point_config::{name::Name, point_config::PointConfig}}, core_::{
+        cot::cot::Cot, point::point_type::{PointType, ToPoint},
         types::fn_in_out_ref::FnInOutRef, 
     }, services::{safe_lock::SafeLock, services::Services, task::{nested_function::{fn_var::FnVar, sql_metric::SqlMetric}, task_nodes::TaskNodes}}
 };
 use super::{
-    export::fn_to_api_queue::FnToApiQueue, fn_add::FnAdd, fn_const::FnConst, fn_count::FnCount, fn_debug::FnDebug, fn_ge::FnGe, fn_input::FnInput, fn_point_id::FnPointId, fn_timer::FnTimer, fn_to_int::FnToInt, functions::Functions 
+    fn_::{FnInOut, FnOut},
+    export::{fn_to_api_queue::FnToApiQueue, fn_to_multi_queue::FnToMultiQueue}, fn_point::FnPoint, fn_pulse_count::FnPulseCount, fn_add::FnAdd, fn_const::FnConst, fn_count::FnCount, fn_debug::FnDebug, fn_ge::FnGe, fn_input::FnInput, fn_point_id::FnPointId, fn_timer::FnTimer, fn_to_int::FnToInt, functions::Functions,
+    fn_to_real::FnToReal, fn_to_double::FnToDouble, fn_to_bool::FnToBool, fn_to_string::FnToString,
+    fn_sub::FnSub, fn_mul::FnMul, fn_div::FnDiv, fn_mod::FnMod, fn_pow::FnPow,
+    fn_gt::FnGt, fn_lt::FnLt, fn_le::FnLe, fn_eq::FnEq, fn_ne::FnNe,
+    fn_and::FnAnd, fn_or::FnOr, fn_xor::FnXor, fn_not::FnNot,
+    fn_rising_edge::FnRisingEdge, fn_falling_edge::FnFallingEdge, fn_any_edge::FnAnyEdge,
+    fn_iec_timer::{FnIecElapsed, IecTimerElapsed, IecTimerOut}, fn_ton::FnTon, fn_tof::FnTof, fn_tp::FnTp,
+    fn_latch::{FnLatch, LatchDominance},
+    fn_if::FnIf, fn_switch::FnSwitch, fn_hysteresis::FnHysteresis,
+    fn_statistic::{FnStatistic, StatisticOp, WindowSize},
+    fn_integral::FnIntegral, fn_derivative::FnDerivative,
+    fn_scale::FnScale, fn_clamp::FnClamp, fn_interpolate::{FnInterpolate, Extrapolation},
+    fn_format::FnFormat, fn_concat::FnConcat, fn_expr::Expr,
+    fn_operating_cycle::FnOperatingCycle, fn_fault_recorder::FnFaultRecorder, fn_status_ignore::FnStatusIgnore,
 };
 
 ///
@@ -22,6 +37,99 @@ impl NestedFn {
     ///
     /// 
     fn function(parent: &Name, tx_id: usize, input_name: &str, conf: &mut FnConfKind, task_nodes: &mut TaskNodes, services: Arc<Mutex<Services>>) -> FnInOutRef {
+        let self_id = format!("{}/NestedFn", parent);
+        // The status propagation policy of the function, the worst status of the inputs by default,
+        // can be overridden by the 'status' parameter:
+        //  - propagate - the worst status of the inputs
+        //  - ignore - the statuses of the inputs are ignored, the own status of the function is kept
+        let mut ignore = false;
+        if let FnConfKind::Fn(fn_conf) = conf {
+            if let Some(FnConfKind::Param(status)) = fn_conf.inputs.get("status") {
+                ignore = match status.as_str() {
+                    "ignore" => true,
+                    "propagate" => false,
+                    _ => panic!("{}.function | Invalid 'status' parameter: {:?}, 'ignore' or 'propagate' expected", self_id, status),
+                };
+                fn_conf.inputs.shift_remove("status");
+            }
+        }
+        // The direct inputs of the function configured with 'status: ignore' are built with Status::Ok
+        let parent_ignore = task_nodes.swapIgnoreStatus(ignore);
+        // The path of the function in the Task, the default key of the retained state
+        let path = match conf {
+            FnConfKind::Fn(fn_conf) | FnConfKind::Var(fn_conf) => [input_name, fn_conf.name.as_str()].iter()
+                .filter(|name| !name.is_empty())
+                .cloned()
+                .collect::<Vec<&str>>()
+                .join("."),
+            _ => input_name.to_owned(),
+        };
+        task_nodes.enterPath(path);
+        let function = Self::build_retained(parent, tx_id, input_name, conf, task_nodes, services);
+        task_nodes.exitPath();
+        task_nodes.swapIgnoreStatus(parent_ignore);
+        match parent_ignore {
+            true => Self::fn_status_ignore(parent, function),
+            false => function,
+        }
+    }
+    ///
+    /// Returns the function built from the [conf], registered in the [TaskNodes] if its state has to be retained
+    fn build_retained(parent: &Name, tx_id: usize, input_name: &str, conf: &mut FnConfKind, task_nodes: &mut TaskNodes, services: Arc<Mutex<Services>>) -> FnInOutRef {
+        let self_id = format!("{}/NestedFn", parent);
+        let retain = Self::retain_key(&self_id, conf, task_nodes);
+        let function = Self::build(parent, tx_id, input_name, conf, task_nodes, services);
+        if let Some(key) = retain {
+            if function.borrow().retained().is_some() {
+                task_nodes.addRetained(key, function.clone());
+            } else {
+                debug!("{}.function | Function {:?} has no state to be retained", self_id, function.borrow().id());
+            }
+        }
+        function
+    }
+    ///
+    /// Returns the key of the retained state of the function, None if the state isn't retained
+    /// - the state is retained if 'retain' parameter specified for the function,
+    /// or for the whole Task, the parameter of the function has priority:
+    ///     - retain: true - the key is the path of the function in the Task, for example 'ToApiQueue.input.count'
+    ///     - retain: false - the state isn't retained
+    ///     - retain: <key> - the state is retained with explicitly specified key
+    /// - the 'retain' parameter of the var is applied to the function, the var declared with,
+    /// the var just holds the result of the function
+    fn retain_key(self_id: &str, conf: &mut FnConfKind, task_nodes: &TaskNodes) -> Option<String> {
+        match conf {
+            FnConfKind::Fn(conf) => {
+                let retain = match conf.inputs.shift_remove("retain") {
+                    Some(retain) => retain.name(),
+                    None => return task_nodes.isRetained().then(|| task_nodes.path()),
+                };
+                match retain.as_str() {
+                    "true" => Some(task_nodes.path()),
+                    "false" => None,
+                    key => {
+                        debug!("{}.function | Retain key: {:?}", self_id, key);
+                        Some(key.to_owned())
+                    }
+                }
+            }
+            FnConfKind::Var(conf) => {
+                if let Some(retain) = conf.inputs.shift_remove("retain") {
+                    match conf.inputs.values_mut().next() {
+                        Some(FnConfKind::Fn(input)) => {
+                            input.inputs.entry("retain".to_owned()).or_insert(retain);
+                        }
+                        input => panic!("{}.function | Var {:?} - 'retain' parameter requires the function on the input, but declared: {:?}", self_id, conf.name, input),
+                    }
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+    ///
+    /// 
+    fn build(parent: &Name, tx_id: usize, input_name: &str, conf: &mut FnConfKind, task_nodes: &mut TaskNodes, services: Arc<Mutex<Services>>) -> FnInOutRef {
         let self_id = format!("{}/NestedFn", parent);
         match conf {
             FnConfKind::Fn(conf) => {
@@ -50,21 +158,51 @@ impl NestedFn {
                         Self::fn_add(parent, input1, input2)
                     }
                     Functions::Timer => {
-                        let name = "input1";
-                        let conf = conf.inputs.get_mut(name).unwrap();
-                        let input = Self::function(parent, tx_id, name, conf, task_nodes, services);
-                        Self::fn_timer(parent, 0.0, input, true)
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let initial = match conf.param_opt("initial") {
+                            Some(initial) => initial.name().parse::<f64>().unwrap_or_else(|err| {
+                                panic!("{}.function | Invalid 'initial' parameter: {:?}, error: {:?}", self_id, initial, err);
+                            }),
+                            None => 0.0,
+                        };
+                        let repeat = match conf.param_opt("repeat") {
+                            Some(repeat) => repeat.name().parse::<bool>().unwrap_or_else(|err| {
+                                panic!("{}.function | Invalid 'repeat' parameter: {:?}, error: {:?}", self_id, repeat, err);
+                            }),
+                            None => true,
+                        };
+                        // 'input1' - kept for compatibility with the earlier configurations
+                        let name = if conf.inputs.contains_key("input1") {"input1"} else {"input"};
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let reset = Self::input_opt(parent, tx_id, "reset", conf, task_nodes, services);
+                        Self::fn_timer(parent, initial, input, repeat, reset)
+                    }
+                    Functions::PulseCount => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let initial = match conf.param_opt("initial") {
+                            Some(initial) => initial.name().parse::<i64>().unwrap_or_else(|err| {
+                                panic!("{}.function | Invalid 'initial' parameter: {:?}, error: {:?}", self_id, initial, err);
+                            }),
+                            None => 0,
+                        };
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let reset = Self::input_opt(parent, tx_id, "reset", conf, task_nodes, services);
+                        Self::fn_pulse_count(parent, initial, input, reset)
                     }
                     Functions::ToApiQueue => {
                         let name = "input";
                         let input_conf = conf.input_conf(name);
                         let input = Self::function(parent, tx_id, name, input_conf, task_nodes ,services.clone());
+                        let enable = Self::input_opt(parent, tx_id, "enable", conf, task_nodes, services.clone());
                         let queue_name = conf.param("queue").name();
                         let services_lock = services.slock();
                         let send_queue = services_lock.get_link(&queue_name).unwrap_or_else(|err| {
                             panic!("{}.function | services.get_link error: {:#?}", self_id, err);
                         });
-                        Self::to_api_queue(parent, input, send_queue)
+                        Self::to_api_queue(parent, input, enable, send_queue)
                         // Self::toApiQueue(inputName, queue, input)
                     }
                     Functions::Ge => {
@@ -106,6 +244,462 @@ impl NestedFn {
                         let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
                         Self::fn_to_int(parent, input)
                     }
+                    Functions::ToReal => {
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_to_real(parent, input)
+                    }
+                    Functions::ToDouble => {
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_to_double(parent, input)
+                    }
+                    Functions::ToBool => {
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_to_bool(parent, input)
+                    }
+                    Functions::ToString => {
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_to_string(parent, input)
+                    }
+                    Functions::Sub => {
+                        let name = "input1";
+                        let input_conf = conf.input_conf(name);
+                        let input1 = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "input2";
+                        let input_conf = conf.input_conf(name);
+                        let input2 = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_sub(parent, input1, input2)
+                    }
+                    Functions::Mul => {
+                        let name = "input1";
+                        let input_conf = conf.input_conf(name);
+                        let input1 = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "input2";
+                        let input_conf = conf.input_conf(name);
+                        let input2 = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_mul(parent, input1, input2)
+                    }
+                    Functions::Div => {
+                        let name = "input1";
+                        let input_conf = conf.input_conf(name);
+                        let input1 = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "input2";
+                        let input_conf = conf.input_conf(name);
+                        let input2 = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_div(parent, input1, input2)
+                    }
+                    Functions::Mod => {
+                        let name = "input1";
+                        let input_conf = conf.input_conf(name);
+                        let input1 = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "input2";
+                        let input_conf = conf.input_conf(name);
+                        let input2 = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_mod(parent, input1, input2)
+                    }
+                    Functions::Pow => {
+                        let name = "input1";
+                        let input_conf = conf.input_conf(name);
+                        let input1 = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "input2";
+                        let input_conf = conf.input_conf(name);
+                        let input2 = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_pow(parent, input1, input2)
+                    }
+                    Functions::Gt => {
+                        let name = "input1";
+                        let input_conf = conf.input_conf(name);
+                        let input1 = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "input2";
+                        let input_conf = conf.input_conf(name);
+                        let input2 = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_gt(parent, input1, input2)
+                    }
+                    Functions::Lt => {
+                        let name = "input1";
+                        let input_conf = conf.input_conf(name);
+                        let input1 = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "input2";
+                        let input_conf = conf.input_conf(name);
+                        let input2 = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_lt(parent, input1, input2)
+                    }
+                    Functions::Le => {
+                        let name = "input1";
+                        let input_conf = conf.input_conf(name);
+                        let input1 = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "input2";
+                        let input_conf = conf.input_conf(name);
+                        let input2 = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_le(parent, input1, input2)
+                    }
+                    Functions::Eq => {
+                        let decimals = conf.param_opt("decimals").map(|param| {
+                            param.name().parse::<usize>().unwrap_or_else(|err| {
+                                panic!("{}.function | Invalid 'decimals' parameter: {:?}, error: {:?}", self_id, param, err);
+                            })
+                        });
+                        let name = "input1";
+                        let input_conf = conf.input_conf(name);
+                        let input1 = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "input2";
+                        let input_conf = conf.input_conf(name);
+                        let input2 = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_eq(parent, input1, input2, decimals)
+                    }
+                    Functions::Ne => {
+                        let decimals = conf.param_opt("decimals").map(|param| {
+                            param.name().parse::<usize>().unwrap_or_else(|err| {
+                                panic!("{}.function | Invalid 'decimals' parameter: {:?}, error: {:?}", self_id, param, err);
+                            })
+                        });
+                        let name = "input1";
+                        let input_conf = conf.input_conf(name);
+                        let input1 = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "input2";
+                        let input_conf = conf.input_conf(name);
+                        let input2 = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_ne(parent, input1, input2, decimals)
+                    }
+                    Functions::And => {
+                        let inputs = Self::inputs_n(parent, tx_id, conf, task_nodes, services);
+                        Self::fn_and(parent, inputs)
+                    }
+                    Functions::Or => {
+                        let inputs = Self::inputs_n(parent, tx_id, conf, task_nodes, services);
+                        Self::fn_or(parent, inputs)
+                    }
+                    Functions::Xor => {
+                        let inputs = Self::inputs_n(parent, tx_id, conf, task_nodes, services);
+                        Self::fn_xor(parent, inputs)
+                    }
+                    Functions::Not => {
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_not(parent, input)
+                    }
+                    Functions::ToMultiQueue => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let queue_name = conf.param("queue").name();
+                        let cot = match conf.param_opt("cot") {
+                            Some(cot) => serde_yaml::from_str(&cot.name()).unwrap_or_else(|err| {
+                                panic!("{}.function | Invalid 'cot' parameter: {:?}, error: {:?}", self_id, cot, err);
+                            }),
+                            None => Cot::Inf,
+                        };
+                        let enable = Self::input_opt(parent, tx_id, "enable", conf, task_nodes, services.clone());
+                        let mut inputs = vec![];
+                        for (name, input_conf) in conf.inputs.iter_mut().filter(|(name, _)| *name != "enable") {
+                            match input_conf {
+                                FnConfKind::Param(_) => {}
+                                FnConfKind::PointConf(point_conf) => {
+                                    let input = Self::function(parent, tx_id, name, &mut point_conf.input, task_nodes, services.clone());
+                                    inputs.push(Self::fn_point(parent, tx_id, point_conf.conf.clone(), cot, input));
+                                }
+                                _ => {
+                                    inputs.push(Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone()));
+                                }
+                            }
+                        }
+                        let services_lock = services.slock();
+                        let send_queue = services_lock.get_link(&queue_name).unwrap_or_else(|err| {
+                            panic!("{}.function | services.get_link error: {:#?}", self_id, err);
+                        });
+                        Self::to_multi_queue(parent, inputs, enable, send_queue)
+                    }
+                    Functions::RisingEdge => {
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_rising_edge(parent, input)
+                    }
+                    Functions::FallingEdge => {
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_falling_edge(parent, input)
+                    }
+                    Functions::AnyEdge => {
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_any_edge(parent, input)
+                    }
+                    Functions::Ton => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let (preset, out) = Self::iec_timer_params(&self_id, conf);
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        let timer = FnTon::new(parent, input, preset, out);
+                        Self::iec_timer_elapsed(&self_id, parent, conf, timer.inputs(), timer.elapsed(), task_nodes);
+                        Self::fn_iec_timer(timer)
+                    }
+                    Functions::Tof => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let (preset, out) = Self::iec_timer_params(&self_id, conf);
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        let timer = FnTof::new(parent, input, preset, out);
+                        Self::iec_timer_elapsed(&self_id, parent, conf, timer.inputs(), timer.elapsed(), task_nodes);
+                        Self::fn_iec_timer(timer)
+                    }
+                    Functions::Tp => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let (preset, out) = Self::iec_timer_params(&self_id, conf);
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        let timer = FnTp::new(parent, input, preset, out);
+                        Self::iec_timer_elapsed(&self_id, parent, conf, timer.inputs(), timer.elapsed(), task_nodes);
+                        Self::fn_iec_timer(timer)
+                    }
+                    Functions::LatchSet | Functions::LatchReset => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let dominance = match fn_name {
+                            Functions::LatchSet => LatchDominance::Set,
+                            _ => LatchDominance::Reset,
+                        };
+                        let name = "set";
+                        let input_conf = conf.input_conf(name);
+                        let set = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "reset";
+                        let input_conf = conf.input_conf(name);
+                        let reset = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_latch(parent, set, reset, dominance)
+                    }
+                    Functions::If => {
+                        let name = "condition";
+                        let input_conf = conf.input_conf(name);
+                        let condition = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "then";
+                        let input_conf = conf.input_conf(name);
+                        let then = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "else";
+                        let input_conf = conf.input_conf(name);
+                        let else_ = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_if(parent, condition, then, else_)
+                    }
+                    Functions::Switch => {
+                        let name = "selector";
+                        let input_conf = conf.input_conf(name);
+                        let selector = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let names: Vec<(i64, String)> = conf.inputs.keys()
+                            .filter_map(|name| {
+                                name.strip_prefix("case").and_then(|index| index.parse::<i64>().ok()).map(|index| (index, name.clone()))
+                            })
+                            .collect();
+                        if names.is_empty() {
+                            panic!("{}.function | function {:?} must have at least one 'caseN'", self_id, conf.name);
+                        }
+                        let cases = names.iter().map(|(index, name)| {
+                            let input_conf = conf.input_conf(name);
+                            (*index, Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone()))
+                        }).collect();
+                        let default = Self::input_opt(parent, tx_id, "default", conf, task_nodes, services);
+                        Self::fn_switch(parent, selector, cases, default)
+                    }
+                    Functions::Hysteresis => {
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "high";
+                        let input_conf = conf.input_conf(name);
+                        let high = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "low";
+                        let input_conf = conf.input_conf(name);
+                        let low = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_hysteresis(parent, input, high, low)
+                    }
+                    Functions::Average | Functions::Min | Functions::Max | Functions::StdDev => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let op = match fn_name {
+                            Functions::Average => StatisticOp::Average,
+                            Functions::Min => StatisticOp::Min,
+                            Functions::Max => StatisticOp::Max,
+                            _ => StatisticOp::StdDev,
+                        };
+                        let size = Self::window_size(&self_id, conf);
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let reset = Self::input_opt(parent, tx_id, "reset", conf, task_nodes, services);
+                        Self::fn_statistic(parent, input, op, size, reset)
+                    }
+                    Functions::Integral => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let initial = match conf.param_opt("initial") {
+                            Some(initial) => initial.name().parse::<f64>().unwrap_or_else(|err| {
+                                panic!("{}.function | Invalid 'initial' parameter: {:?}, error: {:?}", self_id, initial, err);
+                            }),
+                            None => 0.0,
+                        };
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let reset = Self::input_opt(parent, tx_id, "reset", conf, task_nodes, services);
+                        Self::fn_integral(parent, initial, input, reset)
+                    }
+                    // 'rate' - alias of the 'derivative'
+                    Functions::Derivative | Functions::Rate => {
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_derivative(parent, input)
+                    }
+                    Functions::Scale => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let in_min = Self::param_f64(&self_id, conf, "inMin");
+                        let in_max = Self::param_f64(&self_id, conf, "inMax");
+                        let out_min = Self::param_f64(&self_id, conf, "outMin");
+                        let out_max = Self::param_f64(&self_id, conf, "outMax");
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_scale(parent, input, in_min, in_max, out_min, out_max)
+                    }
+                    Functions::Clamp => {
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let min = Self::input_opt(parent, tx_id, "min", conf, task_nodes, services.clone());
+                        let max = Self::input_opt(parent, tx_id, "max", conf, task_nodes, services);
+                        Self::fn_clamp(parent, input, min, max)
+                    }
+                    Functions::Interpolate => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        // the table of [x, y] pairs, stored by the FnConfig as JSON string
+                        let table = conf.param("table").name();
+                        let table: Vec<(f64, f64)> = serde_json::from_str(&table).unwrap_or_else(|err| {
+                            panic!("{}.function | Invalid 'table' parameter: {:?}, error: {:?}", self_id, table, err);
+                        });
+                        let extrapolation = match conf.param_opt("extrapolation") {
+                            Some(extrapolation) => Extrapolation::from_str(&extrapolation.name()).unwrap_or_else(|err| {
+                                panic!("{}.function | Invalid 'extrapolation' parameter: {:?}", self_id, err);
+                            }),
+                            None => Extrapolation::Hold,
+                        };
+                        let name = "input";
+                        let input_conf = conf.input_conf(name);
+                        let input = Self::function(parent, tx_id, name, input_conf, task_nodes, services);
+                        Self::fn_interpolate(parent, input, table, extrapolation)
+                    }
+                    Functions::Format => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let template = conf.param("template").name();
+                        let names: Vec<String> = conf.inputs.keys()
+                            .filter(|name| name.as_str() != "template")
+                            .cloned()
+                            .collect();
+                        let inputs = names.iter().map(|name| {
+                            let input_conf = conf.input_conf(name);
+                            (name.clone(), Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone()))
+                        }).collect();
+                        Self::fn_format(parent, &template, inputs)
+                    }
+                    Functions::Concat => {
+                        let separator = conf.param_opt("separator").map_or(String::new(), |separator| separator.name());
+                        let inputs = Self::inputs_n(parent, tx_id, conf, task_nodes, services);
+                        Self::fn_concat(parent, inputs, separator)
+                    }
+                    Functions::Expr => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        // the path of the formula in the config, to be reported on errors
+                        let path = [parent.join(), input_name.to_owned(), format!("fn {}", conf.name), "formula".to_owned()]
+                            .into_iter()
+                            .filter(|part| !part.is_empty())
+                            .collect::<Vec<String>>()
+                            .join(" / ");
+                        let formula = conf.param("formula").name();
+                        let expr = Expr::parse(&formula).unwrap_or_else(|err| {
+                            panic!("{}.function | Syntax error in '{}': {:?}, formula: {:?}", self_id, path, err, formula);
+                        });
+                        let names: Vec<String> = conf.inputs.keys()
+                            .filter(|name| name.as_str() != "formula")
+                            .cloned()
+                            .collect();
+                        let types = names.iter().map(|name| {
+                            let type_ = match &conf.inputs[name] {
+                                FnConfKind::Fn(conf) | FnConfKind::Var(conf) | FnConfKind::Const(conf) | FnConfKind::Point(conf) => conf.type_.clone(),
+                                FnConfKind::PointConf(_) => FnConfPointType::Unknown,
+                                FnConfKind::Param(value) => panic!(
+                                    "{}.function | Input '{}' in '{}' must be const / point / var / fn, but found parameter: {:?}",
+                                    self_id, name, path, value,
+                                ),
+                            };
+                            (name.clone(), type_)
+                        }).collect();
+                        expr.check(&types).unwrap_or_else(|err| {
+                            panic!("{}.function | Type error in '{}': {}, formula: {:?}", self_id, path, err, formula);
+                        });
+                        let inputs = names.iter().map(|name| {
+                            let input_conf = conf.input_conf(name);
+                            (name.clone(), Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone()))
+                        }).collect();
+                        expr.build(&parent.join(), tx_id, &inputs)
+                    }
+                    Functions::OperatingCycle => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let min_duration = match conf.param_opt("minDuration") {
+                            Some(min_duration) => ConfDuration::from_str(&min_duration.name()).unwrap_or_else(|err| {
+                                panic!("{}.function | Invalid 'minDuration' parameter: {:?}, error: {:?}", self_id, min_duration, err);
+                            }).toDuration(),
+                            None => Duration::ZERO,
+                        };
+                        let template = conf.param_opt("template").map(|template| template.name());
+                        let name = "start";
+                        let input_conf = conf.input_conf(name);
+                        let start = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let name = "load";
+                        let input_conf = conf.input_conf(name);
+                        let load = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let alarm_class = Self::input_opt(parent, tx_id, "alarmClass", conf, task_nodes, services);
+                        Self::fn_operating_cycle(parent, start, load, alarm_class, min_duration, template)
+                    }
+                    Functions::FaultRecorder => {
+                        debug!("{}.function | fn_conf: {:?}: {:?}", self_id, conf.name, conf);
+                        let [pre, post] = ["preTrigger", "postTrigger"].map(|name| {
+                            let value = conf.param(name);
+                            ConfDuration::from_str(&value.name()).unwrap_or_else(|err| {
+                                panic!("{}.function | Invalid '{}' parameter: {:?}, error: {:?}", self_id, name, value, err);
+                            }).toDuration()
+                        });
+                        let store = match conf.param_opt("store") {
+                            Some(store) => store.name().parse::<bool>().unwrap_or_else(|err| {
+                                panic!("{}.function | Invalid 'store' parameter: {:?}, error: {:?}", self_id, store, err);
+                            }),
+                            None => true,
+                        };
+                        // Snapshots stored in the 'assets/fault-recorder/<task name>' by default
+                        let dir = store.then(|| {
+                            let dir = match conf.param_opt("path") {
+                                Some(path) => path.name(),
+                                None => Name::new("assets/fault-recorder/", parent.join()).join().trim_start_matches('/').to_owned(),
+                            };
+                            env::current_dir().unwrap().join(dir)
+                        });
+                        let name = "trigger";
+                        let input_conf = conf.input_conf(name);
+                        let trigger = Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone());
+                        let names: Vec<String> = conf.inputs.iter()
+                            .filter(|(name, input)| name.as_str() != "trigger" && !matches!(input, FnConfKind::Param(_)))
+                            .map(|(name, _)| name.clone())
+                            .collect();
+                        let inputs = names.iter().map(|name| {
+                            let input_conf = conf.input_conf(name);
+                            (name.clone(), Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone()))
+                        }).collect();
+                        Self::fn_fault_recorder(parent, trigger, inputs, pre, post, dir)
+                    }
                     _ => panic!("{}.function | Unknown function name: {:?}", self_id, conf.name)
                 }
             }
@@ -175,8 +769,10 @@ impl NestedFn {
                 }
                 input
             }
-            FnConfKind::PointConf(_conf) => {
-                panic!("{}.function | PointConf is not supported in the nested functions yet", self_id);
+            FnConfKind::PointConf(conf) => {
+                debug!("{}.function | PointConf: {:?} ({:?})...", self_id, input_name, conf.conf.name);
+                let input = Self::function(parent, tx_id, input_name, &mut conf.input, task_nodes, services);
+                Self::fn_point(parent, tx_id, conf.conf.clone(), Cot::Inf, input)
             }
             // FnConfKind::Metric(conf) => {
             //     debug!("{}.function | Metric {:?}", &conf.name);
@@ -188,6 +784,103 @@ impl NestedFn {
         }
     }
     ///
+    /// Returns parameters of the IEC 61131 timers (ton, tof, tp):
+    ///  - preset - required, ConfDuration, for example `preset: 5 s`
+    ///  - out - optional, `q` (default) or `elapsed`
+    ///  - elapsed - optional, the name of the variable to be declared, see [iec_timer_elapsed](NestedFn::iec_timer_elapsed)
+    fn iec_timer_params(self_id: &str, conf: &FnConfig) -> (Duration, IecTimerOut) {
+        let preset = conf.param("preset").name();
+        let preset = ConfDuration::from_str(&preset).unwrap_or_else(|err| {
+            panic!("{}.function | Invalid 'preset' parameter: {:?}, error: {:?}", self_id, preset, err);
+        }).toDuration();
+        let out = match conf.param_opt("out") {
+            Some(out) => IecTimerOut::from_str(&out.name()).unwrap_or_else(|err| {
+                panic!("{}.function | Invalid 'out' parameter: {:?}", self_id, err);
+            }),
+            None => IecTimerOut::Q,
+        };
+        (preset, out)
+    }
+    ///
+    /// Declares the variable of the elapsed time of the IEC 61131 timer, if `elapsed: VarName` configured,
+    /// the variable shares the state of the same timer instance, so both Q & elapsed are available:
+    /// ```yaml
+    /// fn ton:
+    ///     input: point bool '/App/Load/Run'
+    ///     preset: 5 s
+    ///     elapsed: RunElapsed
+    /// ```
+    fn iec_timer_elapsed(self_id: &str, parent: &Name, conf: &FnConfig, inputs: Vec<String>, elapsed: IecTimerElapsed, task_nodes: &mut TaskNodes) {
+        if let Some(var_name) = conf.param_opt("elapsed") {
+            let var_name = var_name.name();
+            debug!("{}.function | Elapsed var: {:?}", self_id, var_name);
+            let var: FnInOutRef = Rc::new(RefCell::new(Box::new(
+                FnIecElapsed::new(parent, inputs, elapsed)
+            )));
+            task_nodes.addVar(var_name, var);
+        }
+    }
+    ///
+    /// Returns the sliding window size of the statistic function
+    ///  - 'samples' - the window of the last N samples
+    ///  - 'window' - the window of the time span, ConfDuration
+    fn window_size(self_id: &str, conf: &FnConfig) -> WindowSize {
+        match (conf.param_opt("samples"), conf.param_opt("window")) {
+            (Some(samples), None) => {
+                let samples = samples.name().parse::<usize>().unwrap_or_else(|err| {
+                    panic!("{}.function | Invalid 'samples' parameter: {:?}, error: {:?}", self_id, samples, err);
+                });
+                WindowSize::Samples(samples)
+            }
+            (None, Some(window)) => {
+                let window = ConfDuration::from_str(&window.name()).unwrap_or_else(|err| {
+                    panic!("{}.function | Invalid 'window' parameter: {:?}, error: {:?}", self_id, window, err);
+                }).toDuration();
+                WindowSize::Duration(window)
+            }
+            _ => panic!("{}.function | function {:?} requires either 'samples' or 'window' parameter", self_id, conf.name),
+        }
+    }
+    ///
+    /// Returns required Double parameter of the function
+    fn param_f64(self_id: &str, conf: &FnConfig, name: &str) -> f64 {
+        let param = conf.param(name).name();
+        param.parse::<f64>().unwrap_or_else(|err| {
+            panic!("{}.function | Invalid '{}' parameter: {:?}, error: {:?}", self_id, name, param, err);
+        })
+    }
+    ///
+    /// Returns optional input of the function if it's configured
+    fn input_opt(parent: &Name, tx_id: usize, name: &str, conf: &mut FnConfig, task_nodes: &mut TaskNodes, services: Arc<Mutex<Services>>) -> Option<FnInOutRef> {
+        conf.inputs.get_mut(name).map(|input_conf| {
+            Self::function(parent, tx_id, name, input_conf, task_nodes, services)
+        })
+    }
+    ///
+    /// Returns all the 'inputN' inputs of the function ordered by N,
+    /// at least one input required, the bare 'input' isn't the 'inputN'
+    fn inputs_n(parent: &Name, tx_id: usize, conf: &mut FnConfig, task_nodes: &mut TaskNodes, services: Arc<Mutex<Services>>) -> Vec<FnInOutRef> {
+        let mut names: Vec<(usize, String)> = conf.inputs.keys()
+            .filter_map(|name| {
+                let n = name.strip_prefix("input")?;
+                if !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()) {
+                    Some((n.parse().unwrap_or(usize::MAX), name.clone()))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        names.sort();
+        let names: Vec<String> = names.into_iter().map(|(_, name)| name).collect();
+        if names.is_empty() {
+            panic!("{}/NestedFn.function | function {:?} must have at least one 'inputN'", parent, conf.name);
+        }
+        names.iter().map(|name| {
+            let input_conf = conf.input_conf(name);
+            Self::function(parent, tx_id, name, input_conf, task_nodes, services.clone())
+        }).collect()
+    }
+    ///
     /// 
     /// 
     /// 
@@ -205,9 +898,9 @@ impl NestedFn {
     }
     /// 
     /// 
-    fn to_api_queue(parent: impl Into<String>, input: FnInOutRef, send_queue: Sender<PointType>) -> FnInOutRef {
+    fn to_api_queue(parent: impl Into<String>, input: FnInOutRef, enable: Option<FnInOutRef>, send_queue: Sender<PointType>) -> FnInOutRef {
         Rc::new(RefCell::new(Box::new(
-            FnToApiQueue::new(parent, input, send_queue)
+            FnToApiQueue::new(parent, input, enable, send_queue)
         )))
     }
     // ///
@@ -233,20 +926,28 @@ impl NestedFn {
     }    
     // ///
     // /// 
-    fn fn_timer(parent: impl Into<String>, initial: impl Into<f64> + Clone,input: FnInOutRef, repeat: bool) -> FnInOutRef {
+    fn fn_timer(parent: impl Into<String>, initial: impl Into<f64> + Clone,input: FnInOutRef, repeat: bool, reset: Option<FnInOutRef>) -> FnInOutRef {
         Rc::new(RefCell::new(
             Box::new(        
                 FnTimer::new(
                     parent,
                     initial, 
                     input, 
-                    repeat
+                    repeat,
+                    reset,
                 )
             )
         ))
     }    
     // ///
     // /// 
+    fn fn_pulse_count(parent: impl Into<String>, initial: i64, input: FnInOutRef, reset: Option<FnInOutRef>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnPulseCount::new(parent, initial, input, reset)
+        )))
+    }
+    // ///
+    // /// 
     fn fn_ge(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef) -> FnInOutRef {
         Rc::new(RefCell::new(Box::new(        
             FnGe::new(parent, input1, input2)
@@ -273,4 +974,277 @@ impl NestedFn {
             FnToInt::new(parent, input)
         )))
     }
+    // ///
+    // /// 
+    fn fn_to_real(parent: impl Into<String>, input: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnToReal::new(parent, input)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_to_double(parent: impl Into<String>, input: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnToDouble::new(parent, input)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_to_bool(parent: impl Into<String>, input: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnToBool::new(parent, input)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_to_string(parent: impl Into<String>, input: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnToString::new(parent, input)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_sub(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnSub::new(parent, input1, input2)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_mul(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnMul::new(parent, input1, input2)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_div(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnDiv::new(parent, input1, input2)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_mod(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnMod::new(parent, input1, input2)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_pow(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnPow::new(parent, input1, input2)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_gt(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnGt::new(parent, input1, input2)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_lt(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnLt::new(parent, input1, input2)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_le(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnLe::new(parent, input1, input2)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_eq(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef, decimals: Option<usize>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnEq::new(parent, input1, input2, decimals)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_ne(parent: impl Into<String>, input1: FnInOutRef, input2: FnInOutRef, decimals: Option<usize>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnNe::new(parent, input1, input2, decimals)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_and(parent: impl Into<String>, inputs: Vec<FnInOutRef>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnAnd::new(parent, inputs)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_or(parent: impl Into<String>, inputs: Vec<FnInOutRef>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnOr::new(parent, inputs)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_xor(parent: impl Into<String>, inputs: Vec<FnInOutRef>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnXor::new(parent, inputs)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_not(parent: impl Into<String>, input: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnNot::new(parent, input)
+        )))
+    }
+    /// 
+    /// 
+    fn to_multi_queue(parent: impl Into<String>, inputs: Vec<FnInOutRef>, enable: Option<FnInOutRef>, send_queue: Sender<PointType>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnToMultiQueue::new(parent, inputs, enable, send_queue)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_point(parent: impl Into<String>, tx_id: usize, conf: PointConfig, cot: Cot, input: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnPoint::new(parent, tx_id, conf, cot, input)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_rising_edge(parent: impl Into<String>, input: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnRisingEdge::new(parent, input)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_falling_edge(parent: impl Into<String>, input: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnFallingEdge::new(parent, input)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_any_edge(parent: impl Into<String>, input: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnAnyEdge::new(parent, input)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_iec_timer(timer: impl FnInOut + 'static) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            timer
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_latch(parent: impl Into<String>, set: FnInOutRef, reset: FnInOutRef, dominance: LatchDominance) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnLatch::new(parent, set, reset, dominance)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_if(parent: impl Into<String>, condition: FnInOutRef, then: FnInOutRef, else_: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnIf::new(parent, condition, then, else_)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_switch(parent: impl Into<String>, selector: FnInOutRef, cases: IndexMap<i64, FnInOutRef>, default: Option<FnInOutRef>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnSwitch::new(parent, selector, cases, default)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_hysteresis(parent: impl Into<String>, input: FnInOutRef, high: FnInOutRef, low: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnHysteresis::new(parent, input, high, low)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_statistic(parent: impl Into<String>, input: FnInOutRef, op: StatisticOp, size: WindowSize, reset: Option<FnInOutRef>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnStatistic::new(parent, input, op, size, reset)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_integral(parent: impl Into<String>, initial: f64, input: FnInOutRef, reset: Option<FnInOutRef>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnIntegral::new(parent, initial, input, reset)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_derivative(parent: impl Into<String>, input: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnDerivative::new(parent, input)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_scale(parent: impl Into<String>, input: FnInOutRef, in_min: f64, in_max: f64, out_min: f64, out_max: f64) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnScale::new(parent, input, in_min, in_max, out_min, out_max)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_clamp(parent: impl Into<String>, input: FnInOutRef, min: Option<FnInOutRef>, max: Option<FnInOutRef>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnClamp::new(parent, input, min, max)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_interpolate(parent: impl Into<String>, input: FnInOutRef, table: Vec<(f64, f64)>, extrapolation: Extrapolation) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnInterpolate::new(parent, input, table, extrapolation)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_format(parent: impl Into<String>, template: &str, inputs: IndexMap<String, FnInOutRef>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnFormat::new(parent, template, inputs)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_concat(parent: impl Into<String>, inputs: Vec<FnInOutRef>, separator: String) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnConcat::new(parent, inputs, separator)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_operating_cycle(parent: impl Into<String>, start: FnInOutRef, load: FnInOutRef, alarm_class: Option<FnInOutRef>, min_duration: Duration, template: Option<String>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnOperatingCycle::new(parent, start, load, alarm_class, min_duration, template)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_fault_recorder(parent: impl Into<String>, trigger: FnInOutRef, inputs: IndexMap<String, FnInOutRef>, pre: Duration, post: Duration, dir: Option<PathBuf>) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnFaultRecorder::new(parent, trigger, inputs, pre, post, dir)
+        )))
+    }
+    // ///
+    // /// 
+    fn fn_status_ignore(parent: impl Into<String>, input: FnInOutRef) -> FnInOutRef {
+        Rc::new(RefCell::new(Box::new(
+            FnStatusIgnore::new(parent, input)
+        )))
+    }
 }
@@ -1,12 +1,12 @@
 use std::{collections::HashMap, sync::{Arc, Mutex, atomic::{AtomicUsize, Ordering}}};
 use indexmap::IndexMap;
-use log::{debug, trace};
+use log::{debug, error, trace};
 use crate::{
     conf::{fn_::fn_config::FnConfig, point_config::name::Name}, core_::{
-        format::format::Format, point::{point::Point, point_tx_id::PointTxId, point_type::{PointType, ToPoint}}, types::fn_in_out_ref::FnInOutRef 
+        cot::cot::Cot, format::{sql_dialect::SqlDialect, sql_format::SqlFormat}, point::{point::Point, point_tx_id::PointTxId, point_type::{PointType, ToPoint}}, status::status::Status, types::fn_in_out_ref::FnInOutRef 
     }, services::{services::Services, task::task_nodes::TaskNodes}
 };
-use super::{fn_::{FnInOut, FnOut, FnIn}, nested_fn::NestedFn, fn_kind::FnKind};
+use super::{fn_::{FnInOut, FnOut, FnIn}, nested_fn::NestedFn, fn_kind::FnKind, fn_status::{latest, worst_status}};
 ///
 /// Function | SqlMetric
 ///     - values received from the [input]s puts into the target sql query
@@ -17,11 +17,18 @@ use super::{fn_::{FnInOut, FnOut, FnIn}, nested_fn::NestedFn, fn_kind::FnKind};
 ///         - inpur1.timestamp = '20'
 ///         - input1.status = 
 ///         - "UPDATE {table} SET kind = '{input1}' WHERE id = '{input2}';"    =>  UPDATE table SET kind = input1 WHERE id = '{input2}';
+///     - all values escaped for the [dialect] (postgres by default, mysql, sqlite),
+///     untyped String marker rendered as the quoted literal, if placed without quotes: {input1}
+///     - the [table] must be the plain SQL identifier
+///     - typed marker {input1.name:text} / :int / :real / :bool / :timestamp rendered as the complete SQL literal,
+///     the value which can't be converted rendered as NULL & the output gets Status::Invalid
+///     - the output has the worst status & the latest timestamp of the inputs
 /// ```
 /// fn SqlMetric:
 ///     initial: 0.123      # начальное значение
 ///     table: SelectMetric_test_table_name
-///     sql: "UPDATE {table} SET value = '{input1}' WHERE id = '{input2}';"
+///     dialect: postgres   # optional, postgres / mysql / sqlite
+///     sql: "UPDATE {table} SET value = {input1:real}, name = {input1.name:text} WHERE id = '{input2}';"
 ///     input1 point int '/path/Point.Name'
 ///     input2: const int 11
 ///     
@@ -35,7 +42,7 @@ pub struct SqlMetric {
     inputs: IndexMap<String, FnInOutRef>,
     // initial: f64,
     // table: String,
-    sql: Format,
+    sql: SqlFormat,
     sql_names: HashMap<String, (String, Option<String>)>,
 }
 ///
@@ -56,7 +63,7 @@ impl SqlMetric {
             //     "sql" => true,
             //     _ => false
             // };
-            let delete = matches!(v.as_str(), "initial" | "table" | "sql");
+            let delete = matches!(v.as_str(), "initial" | "table" | "sql" | "dialect");
             !delete
         });
         for name in input_conf_names {
@@ -70,15 +77,21 @@ impl SqlMetric {
         let id = conf.name.clone();
         // let initial = conf.param("initial").name.parse().unwrap();
         let table = conf.param("table").name();
-        let mut sql = Format::new(&conf.param("sql").name());
-        sql.insert("id", id.clone().to_point(tx_id, ""));
-        sql.insert("table", table.clone().to_point(tx_id, ""));
-        sql.prepare();
+        let dialect = match conf.param_opt("dialect") {
+            Some(dialect) => dialect.name().parse().unwrap_or_else(|err| panic!("{}.new | {}", self_id, err)),
+            None => SqlDialect::default(),
+        };
+        let mut sql = SqlFormat::new(&conf.param("sql").name(), dialect);
         let mut sql_names = sql.names();
-        sql_names.remove("initial");
-        sql_names.remove("table");
-        sql_names.remove("sql");
-        sql_names.remove("id");
+        for (full_name, (name, _)) in sql.names() {
+            match name.as_str() {
+                "id" => sql.insert(&full_name, id.clone().to_point(tx_id, "")),
+                "table" => sql.insert_identifier(&full_name, &table),
+                "initial" | "sql" | "dialect" => {}
+                _ => continue,
+            }
+            sql_names.remove(&full_name);
+        }
         SqlMetric {
             id: self_id,
             name: self_name,
@@ -119,29 +132,45 @@ impl FnOut for SqlMetric {
     //
     fn out(&mut self) -> PointType {
         let self_id = self.id.clone();
+        let mut points = vec![];
         for (full_name, (name, sufix)) in &self.sql_names {
             trace!("{}.out | name: {:?}, sufix: {:?}", self_id, name, sufix);
             match self.inputs.get(name) {
                 Some(input) => {
                     trace!("{}.out | input: {:?} - found", self_id, name);
                     let point = input.borrow_mut().out();
-                    self.sql.insert(full_name, point);
+                    self.sql.insert(full_name, point.clone());
+                    points.push(point);
                 }
                 None => {
                     panic!("{}.out | input: {:?} - not found", self_id, name);
                 }
             };
         }
-        trace!("{}.out | sql: {:?}", self_id, self.sql.out());
-        PointType::String(Point::new_string(
+        let (sql, errors) = self.sql.build();
+        trace!("{}.out | sql: {:?}", self_id, sql);
+        let status = match errors.is_empty() {
+            true => worst_status(&points),
+            false => {
+                error!("{}.out | Invalid values in sql: {:?}, errors: {:?}", self_id, sql, errors);
+                Status::Invalid
+            }
+        };
+        let timestamp = latest(&points).map_or(chrono::offset::Utc::now(), |point| point.timestamp());
+        PointType::String(Point::new(
             self.tx_id,
-            &self.name.join(), 
-            self.sql.out(),
+            &self.name.join(),
+            sql,
+            status,
+            Cot::Inf,
+            timestamp,
         ))
     }
     //
     fn reset(&mut self) {
-        todo!()
+        for (_, input) in &self.inputs {
+            input.borrow_mut().reset();
+        }
     }
 }
 ///
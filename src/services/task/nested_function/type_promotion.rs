@@ -0,0 +1,90 @@
+use log::error;
+use crate::core_::{point::{point::{combine, Point}, point_type::PointType}, status::status::Status};
+///
+/// Pair of the numeric points promoted to the common type
+///  - Int & Int => Int
+///  - Int & Real, Real & Real => Real
+///  - Int | Real & Double, Double & Double => Double
+///  - Bool treated as Int (true => 1, false => 0)
+#[derive(Debug)]
+pub enum Promoted {
+    Int(Point<i64>, Point<i64>),
+    Real(Point<f32>, Point<f32>),
+    Double(Point<f64>, Point<f64>),
+}
+///
+/// Promotes pair of the numeric points to the common type Int -> Real -> Double
+/// - [self_id] - the id of the caller, just for proper debugging
+pub fn promote(self_id: &str, point1: PointType, point2: PointType) -> Promoted {
+    match rank(self_id, &point1).max(rank(self_id, &point2)) {
+        0 => Promoted::Int(to_int(point1), to_int(point2)),
+        1 => Promoted::Real(to_real(point1), to_real(point2)),
+        _ => Promoted::Double(to_double(point1), to_double(point2)),
+    }
+}
+///
+/// Returns the result of the binary operation over the promoted pair of points,
+/// the status, tx_id & timestamp selected by the same rules as the Point operators, see [combine]
+/// - [op] - the name of the operation, the result named 'Point.{op}'
+/// - [value] - returns None if the operation failed, for example on overflow or division by zero
+/// - the failed operation or the points having different Cot returns default value (0) with Status::Invalid
+pub fn apply<T: Default>(self_id: &str, op: &str, point1: Point<T>, point2: Point<T>, value: impl FnOnce(T, T) -> Option<T>) -> Point<T> {
+    let cot_equal = point1.cot == point2.cot;
+    if !cot_equal {
+        error!("{}.out | Directions are not equals: {:?}, {:?}", self_id, point1.cot, point2.cot);
+    }
+    let mut valid = false;
+    let point = combine(point1, point2, op, |value1, value2| {
+        let value = if cot_equal {value(value1, value2)} else {None};
+        valid = value.is_some();
+        value.unwrap_or_default()
+    });
+    match valid {
+        true => point,
+        false => Point {
+            status: Status::Invalid,
+            ..point
+        },
+    }
+}
+///
+/// Position of the point type in the promotion chain
+fn rank(self_id: &str, point: &PointType) -> u8 {
+    match point {
+        PointType::Bool(_) => 0,
+        PointType::Int(_) => 0,
+        PointType::Real(_) => 1,
+        PointType::Double(_) => 2,
+        PointType::String(_) => panic!("{}.out | String type is not supported: {:?}", self_id, point),
+    }
+}
+///
+/// Converts Bool / Int point into Point<i64>
+fn to_int(point: PointType) -> Point<i64> {
+    match point {
+        PointType::Bool(p) => Point::new(p.tx_id, &p.name, if p.value.0 {1} else {0}, p.status, p.cot, p.timestamp),
+        PointType::Int(p) => p,
+        _ => panic!("type_promotion.to_int | Invalid point type: {:?}", point),
+    }
+}
+///
+/// Converts Bool / Int / Real point into Point<f32>
+fn to_real(point: PointType) -> Point<f32> {
+    match point {
+        PointType::Bool(p) => Point::new(p.tx_id, &p.name, if p.value.0 {1.0} else {0.0}, p.status, p.cot, p.timestamp),
+        PointType::Int(p) => Point::new(p.tx_id, &p.name, p.value as f32, p.status, p.cot, p.timestamp),
+        PointType::Real(p) => p,
+        _ => panic!("type_promotion.to_real | Invalid point type: {:?}", point),
+    }
+}
+///
+/// Converts Bool / Int / Real / Double point into Point<f64>
+fn to_double(point: PointType) -> Point<f64> {
+    match point {
+        PointType::Bool(p) => Point::new(p.tx_id, &p.name, if p.value.0 {1.0} else {0.0}, p.status, p.cot, p.timestamp),
+        PointType::Int(p) => Point::new(p.tx_id, &p.name, p.value as f64, p.status, p.cot, p.timestamp),
+        PointType::Real(p) => Point::new(p.tx_id, &p.name, p.value as f64, p.status, p.cot, p.timestamp),
+        PointType::Double(p) => p,
+        _ => panic!("type_promotion.to_double | Invalid point type: {:?}", point),
+    }
+}
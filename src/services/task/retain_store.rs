@@ -0,0 +1,88 @@
+//!
+//! Stores the state of the Task function on the disk,
+//! so it can be restored after restart
+//! - Stored in the 'assets/retain/<task name>/<key>.json'
+//! - The state is stored as a single point in the json format, same as in the CacheService:
+//! ```json
+//! {"type": "Bool","value": 1,"name": "/App/path/Point.name1","status": 0,"cot": "Inf","timestamp": "2024-04-08T08:52:32.656576549+00:00"}
+//! ```
+use std::{env, fs, io::Write, path::PathBuf};
+use log::{debug, error, info};
+use crate::{conf::point_config::name::Name, core_::point::point_type::PointType};
+///
+/// Stores / loads the state of the Task function on the disk
+#[derive(Debug)]
+pub struct RetainStore {
+    id: String,
+    path: PathBuf,
+}
+///
+/// 
+impl RetainStore {
+    ///
+    /// Creates new instance of the RetainStore
+    /// - parent - the name of the owner Task
+    /// - key - the unique key of the stored state in the Task
+    pub fn new(parent: &Name, key: impl Into<String>) -> Self {
+        let key = key.into();
+        let dir = Name::new("assets/retain/", parent.join()).join().trim_start_matches('/').to_owned();
+        let path = env::current_dir().unwrap().join(dir).join(format!("{}.json", key));
+        Self {
+            id: format!("{}/RetainStore({})", parent.join(), key),
+            path,
+        }
+    }
+    ///
+    /// Returns retained state if stored, otherwise None
+    pub fn load(&self) -> Option<PointType> {
+        match fs::OpenOptions::new().read(true).open(&self.path) {
+            Ok(f) => {
+                match serde_json::from_reader::<_, PointType>(f) {
+                    Ok(point) => {
+                        info!("{}.load | Retained state loaded from: '{:?}'", self.id, self.path);
+                        Some(point)
+                    }
+                    Err(err) => {
+                        error!("{}.load | Deserialize error: '{:?}'\n\tin file: {:?}", self.id, err, self.path);
+                        None
+                    }
+                }
+            }
+            Err(err) => {
+                debug!("{}.load | Retained state not found in: '{:?}'\n\terror: {:?}", self.id, self.path, err);
+                None
+            }
+        }
+    }
+    ///
+    /// Writes the state to the disk
+    pub fn store(&self, point: &PointType) -> Result<(), String> {
+        if let Some(dir) = self.path.parent() {
+            if let Err(err) = fs::create_dir_all(dir) {
+                let message = format!("{}.store | Error create path: '{:?}'\n\terror: {:?}", self.id, dir, err);
+                error!("{}", message);
+                return Err(message);
+            }
+        }
+        match fs::OpenOptions::new().truncate(true).create(true).write(true).open(&self.path) {
+            Ok(mut f) => {
+                match f.write_all(serde_json::json!(point).to_string().as_bytes()) {
+                    Ok(_) => {
+                        debug!("{}.store | State stored in: {:?}", self.id, self.path);
+                        Ok(())
+                    }
+                    Err(err) => {
+                        let message = format!("{}.store | Error writing to file: '{:?}'\n\terror: {:?}", self.id, self.path, err);
+                        error!("{}", message);
+                        Err(message)
+                    }
+                }
+            }
+            Err(err) => {
+                let message = format!("{}.store | Error open file: '{:?}'\n\terror: {:?}", self.id, self.path, err);
+                error!("{}", message);
+                Err(message)
+            }
+        }
+    }
+}
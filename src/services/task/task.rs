@@ -2,8 +2,8 @@ use std::{
     collections::HashMap, fmt::Debug, sync::{atomic::{AtomicBool, Ordering}, mpsc::{self, Receiver, RecvTimeoutError, Sender}, Arc, Mutex}, thread, time::Duration
 };
 use log::{debug, error, info, trace, warn};
-use crate::{conf::{point_config::name::Name, task_config::TaskConfig}, core_::object::object::Object, services::{multi_queue::subscription_criteria::SubscriptionCriteria, safe_lock::SafeLock, service::service_handles::ServiceHandles}};
-use crate::services::task::service_cycle::ServiceCycle;
+use crate::{conf::{point_config::name::Name, task_config::TaskConfig, task_mode::TaskMode}, core_::object::object::Object, services::{multi_queue::subscription_criteria::SubscriptionCriteria, safe_lock::SafeLock, service::service_handles::ServiceHandles}};
+use crate::services::{cache::delay_store::DelyStore, task::service_cycle::ServiceCycle};
 use crate::{
     services::{task::task_nodes::TaskNodes, service::service::Service, services::Services}, 
     core_::{point::point_type::PointType, constants::constants::RECV_TIMEOUT}, 
@@ -11,8 +11,10 @@ use crate::{
 };
 ///
 /// Task implements entity, which provides cyclically (by event) executing calculations
-///  - executed in the cycle mode (current impl)
-///  - executed event mode (future impl..)
+///  - on-event mode - the dependent nodes evaluated on each received point (default)
+///  - cyclic mode - all nodes evaluated every cycle, received points only applied to the inputs
+///  - both - the dependent nodes evaluated on each received point & all nodes evaluated every cycle
+///  - the state of the retained functions periodically stored on the disk & restored on start
 ///  - has some number of functions / variables / metrics or additional entities
 pub struct Task {
     id: String,
@@ -42,10 +44,11 @@ impl Task {
         }
     }
     ///
-    /// 
-    fn subscriptions(&mut self, conf: &TaskConfig, services: &Arc<Mutex<Services>>) -> Option<(String, Vec<SubscriptionCriteria>)> {
+    /// Returns the list of the service names & the points to subscribe on,
+    /// empty if subscription is not configured
+    fn subscriptions(&mut self, conf: &TaskConfig, services: &Arc<Mutex<Services>>) -> Vec<(String, Vec<SubscriptionCriteria>)> {
         if conf.subscribe.is_empty() {
-            None
+            vec![]
         } else {
             debug!("{}.subscribe | requesting points...", self.id);
             let points = services.slock().points(&self.id);
@@ -54,64 +57,26 @@ impl Task {
             debug!("{}.subscribe | conf.subscribe: {:#?}", self.id, conf.subscribe);
             let subscriptions = conf.subscribe.with(&points);
             trace!("{}.subscribe | subscriptions: {:#?}", self.id, subscriptions);
-            if subscriptions.len() > 1 {
-                panic!("{}.run | Error. Task does not supports multiple subscriptions for now: {:#?}.\n\tTry to use single subscription.", self.id, subscriptions);
-            } else {
-                let subscriptions_first = subscriptions.clone().into_iter().next();
-                match subscriptions_first {
-                    Some((service_name, Some(points))) => {
-                        Some((service_name, points))
-                    }
-                    Some((_, None)) => panic!("{}.run | Error. Task subscription configuration error in: {:#?}", self.id, subscriptions),
+            if subscriptions.is_empty() {
+                panic!("{}.run | Error. Task subscription configuration error in: {:#?}", self.id, conf.subscribe);
+            }
+            subscriptions.iter().map(|(service_name, points)| {
+                match points {
+                    Some(points) => (service_name.clone(), points.clone()),
                     None => panic!("{}.run | Error. Task subscription configuration error in: {:#?}", self.id, subscriptions),
                 }
-            }
+            }).collect()
         }
-    }    
+    }
     ///
-    /// 
-    fn subscribe(&mut self, subscriptions: &Option<(String, Vec<SubscriptionCriteria>)>, services: &Arc<Mutex<Services>>) -> Receiver<PointType> {
-        match subscriptions {
-            Some((service_name, points)) => {
-                let (_, rx_recv) = services.slock().subscribe(
-                    service_name,
-                    &self.name.join(), 
-                    points,
-                );
-                rx_recv
-            }
-            None => {
-                self.rx_recv.pop().unwrap()
-            }
+    /// Returns the Receiver, all subscriptions fanned into,
+    /// the own input queue if subscription is not configured
+    fn subscribe(&mut self, subscriptions: &[(String, Vec<SubscriptionCriteria>)], services: &Arc<Mutex<Services>>) -> Receiver<PointType> {
+        if subscriptions.is_empty() {
+            self.rx_recv.pop().unwrap()
+        } else {
+            services.slock().subscribe_all(&self.name.join(), subscriptions)
         }
-        // if conf.subscribe.is_empty() {
-        //     self.rx_recv.pop().unwrap()
-        // } else {
-        //     debug!("{}.subscribe | requesting points...", self.id);
-        //     let points = services.slock().points(&self.id);
-        //     debug!("{}.subscribe | rceived points: {:#?}", self.id, points.len());
-        //     trace!("{}.subscribe | rceived points: {:#?}", self.id, points);
-        //     debug!("{}.subscribe | conf.subscribe: {:#?}", self.id, conf.subscribe);
-        //     let subscriptions = conf.subscribe.with(&points);
-        //     trace!("{}.subscribe | subscriptions: {:#?}", self.id, subscriptions);
-        //     if subscriptions.len() > 1 {
-        //         panic!("{}.run | Error. Task does not supports multiple subscriptions for now: {:#?}.\n\tTry to use single subscription.", self.id, subscriptions);
-        //     } else {
-        //         let subscriptions_first = subscriptions.clone().into_iter().next();
-        //         match subscriptions_first {
-        //             Some((service_name, Some(points))) => {
-        //                 let (_, rx_recv) = services.slock().subscribe(
-        //                     &service_name,
-        //                     &self.name.join(), 
-        //                     &points,
-        //                 );
-        //                 rx_recv
-        //             }
-        //             Some((_, None)) => panic!("{}.run | Error. Task subscription configuration error in: {:#?}", self.id, subscriptions),
-        //             None => panic!("{}.run | Error. Task subscription configuration error in: {:#?}", self.id, subscriptions),
-        //         }
-        //     }
-        // }
     }
 }
 ///
@@ -154,6 +119,7 @@ impl Service for Task {
         let exit = self.exit.clone();
         let conf = self.conf.clone();
         let services = self.services.clone();
+        let mode = conf.mode;
         let (cyclic, cycle_interval) = match conf.cycle {
             Some(interval) => (interval > Duration::ZERO, interval),
             None => (false, Duration::ZERO),
@@ -162,39 +128,81 @@ impl Service for Task {
         let rx_recv = self.subscribe(&subscriptions, &services);
         let handle = thread::Builder::new().name(format!("{} - main", self_id)).spawn(move || {
             let mut cycle = ServiceCycle::new(&self_id, cycle_interval);
+            let mut dely_store = DelyStore::new(conf.retain_delay);
             let mut task_nodes = TaskNodes::new(&self_id);
             task_nodes.buildNodes(&self_name, conf, services.clone());
             trace!("{}.run | taskNodes: {:#?}", self_id, task_nodes);
             'main: loop {
-                cycle.start();
-                trace!("{}.run | calculation step...", self_id);
-                match rx_recv.recv_timeout(RECV_TIMEOUT) {
-                    Ok(point) => {
-                        debug!("{}.run | point: {:?}", self_id, &point);
-                        task_nodes.eval(point);
-                        debug!("{}.run | calculation step - done ({:?})", self_id, cycle.elapsed());
-                        if cyclic {
-                            cycle.wait();
-                        }
+                match mode {
+                    TaskMode::OnEvent => {
+                        cycle.start();
+                        trace!("{}.run | calculation step...", self_id);
+                        match rx_recv.recv_timeout(RECV_TIMEOUT) {
+                            Ok(point) => {
+                                debug!("{}.run | point: {:?}", self_id, &point);
+                                task_nodes.eval(point);
+                                debug!("{}.run | calculation step - done ({:?})", self_id, cycle.elapsed());
+                                if cyclic {
+                                    cycle.wait();
+                                }
+                            }
+                            Err(err) => {
+                                match err {
+                                    RecvTimeoutError::Timeout => {
+                                        trace!("{}.run | Receive error: {:?}", self_id, err);
+                                    }
+                                    RecvTimeoutError::Disconnected => {
+                                        error!("{}.run | Error receiving from queue: {:?}", self_id, err);
+                                        break 'main;
+                                    }
+                                }
+                            }
+                        };
                     }
-                    Err(err) => {
-                        match err {
-                            RecvTimeoutError::Timeout => {
-                                trace!("{}.run | Receive error: {:?}", self_id, err);
+                    TaskMode::Cyclic | TaskMode::Both => {
+                        let timeout = cycle_interval.saturating_sub(cycle.elapsed()).min(RECV_TIMEOUT);
+                        match rx_recv.recv_timeout(timeout) {
+                            Ok(point) => {
+                                debug!("{}.run | point: {:?}", self_id, &point);
+                                if mode == TaskMode::Both {
+                                    task_nodes.eval(point);
+                                } else {
+                                    task_nodes.add(point);
+                                }
                             }
-                            RecvTimeoutError::Disconnected => {
-                                error!("{}.run | Error receiving from queue: {:?}", self_id, err);
-                                break 'main;
+                            Err(err) => {
+                                match err {
+                                    RecvTimeoutError::Timeout => {
+                                        trace!("{}.run | Receive error: {:?}", self_id, err);
+                                    }
+                                    RecvTimeoutError::Disconnected => {
+                                        error!("{}.run | Error receiving from queue: {:?}", self_id, err);
+                                        break 'main;
+                                    }
+                                }
                             }
+                        };
+                        if cycle.elapsed() >= cycle_interval {
+                            cycle.start();
+                            trace!("{}.run | cyclic calculation step...", self_id);
+                            task_nodes.evalAll();
+                            debug!("{}.run | cyclic calculation step - done ({:?})", self_id, cycle.elapsed());
                         }
                     }
-                };
+                }
+                if dely_store.exceeded() {
+                    task_nodes.storeRetained();
+                    dely_store.set_stored();
+                }
                 if exit.load(Ordering::SeqCst) {
                     break 'main;
                 }
             };
-            if let Some((service_name, points)) = subscriptions {
-                if let Err(err) = services.slock().unsubscribe(&service_name,&self_name.join(), &points) {
+            if !dely_store.stored() {
+                task_nodes.storeRetained();
+            }
+            if !subscriptions.is_empty() {
+                if let Err(err) = services.slock().unsubscribe_all(&self_name.join(), &subscriptions) {
                     error!("{}.run | Unsubscribe error: {:#?}", self_id, err);
                 }
             }
@@ -1,13 +1,13 @@
 #![allow(non_snake_case)]
 use std::sync::{Arc, Mutex};
 use indexmap::IndexMap;
-use log::{debug, trace, warn};
+use log::{debug, error, trace, warn};
 use crate::{
     conf::{fn_::fn_conf_kind::FnConfKind, point_config::name::Name, task_config::TaskConfig}, 
     core_::{point::{point_tx_id::PointTxId, point_type::PointType}, types::fn_in_out_ref::FnInOutRef}, 
     services::{services::Services, task::nested_function::{fn_kind::FnKind, nested_fn::NestedFn}},
 };
-use super::{task_node_vars::TaskNodeVars, task_eval_node::TaskEvalNode};
+use super::{retain_store::RetainStore, task_node_vars::TaskNodeVars, task_eval_node::TaskEvalNode};
 ///
 /// TaskNodes - holds the IndexMap<String, TaskNode> in the following structure:
 ///   ```
@@ -34,6 +34,11 @@ pub struct TaskNodes {
     inputs: IndexMap<String, TaskEvalNode>,
     vars: IndexMap<String, FnInOutRef>,
     newNodeVars: Option<TaskNodeVars>,
+    retain: bool,
+    retained: IndexMap<String, FnInOutRef>,
+    retainStores: Vec<(RetainStore, FnInOutRef)>,
+    ignoreStatus: bool,
+    path: Vec<String>,
 }
 ///
 /// 
@@ -46,6 +51,11 @@ impl TaskNodes {
             inputs: IndexMap::new(),
             vars: IndexMap::new(),
             newNodeVars: None,
+            retain: false,
+            retained: IndexMap::new(),
+            retainStores: vec![],
+            ignoreStatus: false,
+            path: vec![],
         }
     }
     ///
@@ -119,6 +129,79 @@ impl TaskNodes {
         }
     }    
     ///
+    /// Sets the flag, the statuses of the functions being built has to be ignored,
+    /// used by the NestedFn for the inputs of the function configured with 'status: ignore'
+    /// - returns the previous value of the flag
+    pub fn swapIgnoreStatus(&mut self, ignore: bool) -> bool {
+        std::mem::replace(&mut self.ignoreStatus, ignore)
+    }
+    ///
+    /// Returns true if the state of the all functions has to be retained,
+    /// configured by the 'retain' parameter of the Task
+    pub fn isRetained(&self) -> bool {
+        self.retain
+    }
+    ///
+    /// Enters the function being built, used by the NestedFn to track the path of the function in the Task
+    /// - name - the segment of the path, for example 'input.count', empty segment is skipped
+    pub fn enterPath(&mut self, name: impl Into<String>) {
+        self.path.push(name.into());
+    }
+    ///
+    /// Leaves the function entered by [enterPath](Self::enterPath)
+    pub fn exitPath(&mut self) {
+        self.path.pop();
+    }
+    ///
+    /// Returns the path of the function being built relative to the Task,
+    /// for example 'ToApiQueue.input.count'
+    pub fn path(&self) -> String {
+        self.path.iter().filter(|name| !name.is_empty()).cloned().collect::<Vec<String>>().join(".")
+    }
+    ///
+    /// Adding the function, which state has to be retained
+    /// - key - the unique key of the stored state in the Task
+    /// - panics if the key is already used by another function
+    pub fn addRetained(&mut self, key: impl Into<String>, function: FnInOutRef) {
+        let id = function.borrow().id();
+        if self.retained.values().any(|retained| retained.borrow().id() == id) {
+            trace!("{}.addRetained | function {:?} - already added", self.id, id);
+            return;
+        }
+        let key = key.into();
+        if key.is_empty() || key.contains(['/', '\\']) {
+            panic!("{}.addRetained | Invalid retain key {:?} of the function {:?}", self.id, key, id);
+        }
+        if self.retained.contains_key(&key) {
+            panic!("{}.addRetained | Duplicated retain key {:?} of the function {:?}, use 'retain: <key>' parameter to specify the unique key", self.id, key, id);
+        }
+        debug!("{}.addRetained | adding function {:?} with key {:?}", self.id, id, key);
+        self.retained.insert(key, function);
+    }
+    ///
+    /// Restores the state of the retained functions from the disk
+    fn restoreRetained(&mut self, parent: &Name) {
+        for (key, function) in self.retained.drain(..) {
+            let store = RetainStore::new(parent, key);
+            if let Some(state) = store.load() {
+                debug!("{}.restoreRetained | restoring {:?}: {:?}", self.id, function.borrow().id(), state);
+                function.borrow_mut().restore(state);
+            }
+            self.retainStores.push((store, function));
+        }
+    }
+    ///
+    /// Stores the state of the retained functions on the disk
+    pub fn storeRetained(&self) {
+        for (store, function) in &self.retainStores {
+            if let Some(state) = function.borrow().retained() {
+                if let Err(err) = store.store(&state) {
+                    error!("{}.storeRetained | Error storing {:?}: {:?}", self.id, function.borrow().id(), err);
+                }
+            }
+        }
+    }
+    ///
     /// Call this method to finish configuration of jast created task node
     fn finishNewNode(&mut self, out: FnInOutRef) {
         match self.newNodeVars {
@@ -159,8 +242,10 @@ impl TaskNodes {
     ///
     /// Creates all task nodes depending on it config
     ///  - if Task config contains 'point [type] every' then single evaluation node allowed only
+    ///  - the state of the retained functions restored from the disk
     pub fn buildNodes(&mut self, parent: &Name, conf: TaskConfig, services: Arc<Mutex<Services>>) {
         let txId = PointTxId::fromStr(&parent.join());
+        self.retain = conf.retain;
         for (idx, (_nodeName, mut nodeConf)) in conf.nodes.into_iter().enumerate() {
             let nodeName = nodeConf.name();
             debug!("{}.buildNodes | node[{}]: {:?}", self.id, idx, nodeName);
@@ -196,6 +281,7 @@ impl TaskNodes {
                 }
             }
         }
+        self.restoreRetained(parent);
     }
     ///
     /// Evaluates all containing node:
@@ -229,4 +315,51 @@ impl TaskNodes {
             }
         };
     }
-}
\ No newline at end of file
+    ///
+    /// Adds new point to the inputs without evaluation,
+    /// used in the cyclic mode, the nodes evaluated by [evalAll](Self::evalAll)
+    pub fn add(&mut self, point: PointType) {
+        let self_id = self.id.clone();
+        let pointName = point.name();
+        if let Some(evalNode) = self.getEvalNode("every") {
+            trace!("{}.add | evalNode '{}' - adding point...", self_id, &evalNode.name());
+            evalNode.add(point.clone());
+        };
+        match self.getEvalNode(&pointName) {
+            Some(evalNode) => {
+                trace!("{}.add | evalNode '{}' - adding point...", self_id, &evalNode.name());
+                evalNode.add(point);
+            }
+            None => {
+                if self.getEvalNode("every").is_none() {
+                    warn!("{}.add | evalNode '{}' - not fount, input point ignored", self_id, &pointName);
+                }
+            }
+        };
+    }
+    ///
+    /// Evaluates all containing nodes regardless of the received points:
+    ///  - evaluating all vars
+    ///  - evaluating all outs
+    ///  - each var & out evaluated once, even if depends on multiple inputs
+    pub fn evalAll(&mut self) {
+        let mut vars: IndexMap<String, FnInOutRef> = IndexMap::new();
+        let mut outs: IndexMap<String, FnInOutRef> = IndexMap::new();
+        for evalNode in self.inputs.values() {
+            for var in evalNode.getVars() {
+                vars.entry(var.borrow().id()).or_insert_with(|| var.clone());
+            }
+            for out in evalNode.getOuts() {
+                outs.entry(out.borrow().id()).or_insert_with(|| out.clone());
+            }
+        }
+        for (id, var) in vars {
+            trace!("{}.evalAll | var '{}' evaluating...", self.id, id);
+            var.borrow_mut().eval();
+        }
+        for (id, out) in outs {
+            let out = out.borrow_mut().out();
+            trace!("{}.evalAll | out '{}': {:?}", self.id, id, out);
+        }
+    }
+}
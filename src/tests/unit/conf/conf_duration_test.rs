@@ -1,7 +1,7 @@
 #![allow(non_snake_case)]
 #[cfg(test)]
 use log::{debug, info};
-use std::{sync::Once, str::FromStr};
+use std::{sync::Once, str::FromStr, time::Duration};
 use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
 use crate::conf::{fn_::fn_conf_keywd::FnConfKeywd, conf_duration::{ConfDuration, ConfDurationUnit}};
 ///
@@ -76,3 +76,25 @@ fn test_create_invalid() {
         assert_eq!(confDuration.is_err(), true);
     }
 }
+
+#[test]
+fn test_to_duration() {
+    DebugSession::init(LogLevel::Info, Backtrace::Short);
+    init_once();
+    init_each();
+    info!("test_to_duration");
+    let test_data = vec![
+        ("111 ns"   , Duration::from_nanos(111)),
+        ("12 us"    , Duration::from_micros(12)),
+        ("11 ms"    , Duration::from_millis(11)),
+        ("5 s"      , Duration::from_secs(5)),
+        ("3"        , Duration::from_secs(3)),
+        ("2 m"      , Duration::from_secs(120)),
+        ("8 h"      , Duration::from_secs(8 * 3600)),
+    ];
+    for (value, target) in test_data {
+        let duration = ConfDuration::from_str(value).unwrap().toDuration();
+        debug!("value: {:?}   |   duration: {:?}   |   target: {:?}", value, duration, target);
+        assert_eq!(duration, target);
+    }
+}
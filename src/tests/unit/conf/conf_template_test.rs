@@ -0,0 +1,132 @@
+#[cfg(test)]
+
+mod conf_template {
+    use log::debug;
+    use std::sync::Once;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::conf::{app::app_config::AppConfig, conf_tree::ConfTree, point_config::name::Name, task_config::TaskConfig};
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Returns the config of the single Task of the application config
+    fn task_conf(self_name: &Name, conf: &str) -> TaskConfig {
+        let conf = serde_yaml::from_str(conf).unwrap();
+        let app_conf = AppConfig::from_yaml_value(&conf);
+        let mut node_conf: ConfTree = app_conf.nodes.values().next().unwrap().clone();
+        debug!("node_conf: {:#?}", node_conf);
+        TaskConfig::new(self_name, &mut node_conf)
+    }
+    ///
+    /// Testing the template instances expanded into the same config as written by hand
+    #[test]
+    fn expand() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_id = "conf_template_test";
+        let self_name = Name::new("", self_id);
+        let conf = r#"
+            name: ApplicationName
+            description: Short explanation / purpose etc.
+            template FaultDetection(input, limit, alarm):
+                fn ToMultiQueue:
+                    queue: /App/MultiQueue.in-queue
+                    in1 point $alarm:
+                        type: 'Bool'
+                        alarm: 1
+                        input fn latchSet:
+                            set fn ge:
+                                input1: $input
+                                input2: $limit
+                            reset: const bool false
+            template Nominal(point):
+                fn add:
+                    input1: point real '$point.Nominal'
+                    input2: const real 0.5
+            service Task Task1:
+                cycle: 100 ms
+                in queue recv-queue:
+                    max-length: 10000
+                load template FaultDetection:
+                    input: point real '/App/Load'
+                    limit: const real 90.0
+                    alarm: Load.Overload
+                speed template FaultDetection:
+                    input: point real '/App/Speed'
+                    limit template Nominal:
+                        point: /App/Speed
+                    alarm: Speed.Overspeed
+        "#;
+        let target = r#"
+            name: ApplicationName
+            description: Short explanation / purpose etc.
+            service Task Task1:
+                cycle: 100 ms
+                in queue recv-queue:
+                    max-length: 10000
+                load fn ToMultiQueue:
+                    queue: /App/MultiQueue.in-queue
+                    in1 point Load.Overload:
+                        type: 'Bool'
+                        alarm: 1
+                        input fn latchSet:
+                            set fn ge:
+                                input1: point real '/App/Load'
+                                input2: const real 90.0
+                            reset: const bool false
+                speed fn ToMultiQueue:
+                    queue: /App/MultiQueue.in-queue
+                    in1 point Speed.Overspeed:
+                        type: 'Bool'
+                        alarm: 1
+                        input fn latchSet:
+                            set fn ge:
+                                input1: point real '/App/Speed'
+                                input2 fn add:
+                                    input1: point real '/App/Speed.Nominal'
+                                    input2: const real 0.5
+                            reset: const bool false
+        "#;
+        let result = task_conf(&self_name, conf);
+        let target = task_conf(&self_name, target);
+        assert!(result.nodes.len() == 2, "\nresult: {:?}\ntarget: {:?}", result.nodes.len(), 2);
+        assert!(result == target, "\nresult: {:#?}\ntarget: {:#?}", result, target);
+    }
+    ///
+    /// Testing the template instance with missing argument is rejected
+    #[test]
+    #[should_panic]
+    fn missing_argument() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_name = Name::new("", "conf_template_test");
+        task_conf(&self_name, r#"
+            name: ApplicationName
+            description: Short explanation / purpose etc.
+            template Nominal(point):
+                fn add:
+                    input1: point real '$point.Nominal'
+                    input2: const real 0.5
+            service Task Task1:
+                cycle: 100 ms
+                in queue recv-queue:
+                    max-length: 10000
+                fn debug:
+                    input template Nominal:
+                        value: /App/Speed
+        "#);
+    }
+}
@@ -127,6 +127,16 @@ mod tests {
                     ]) } )),
                 ]) } )
             ),
+            (
+                r#"fn interpolate:
+                    table: [[0, 0.0], [10, 5.5]]
+                    input: point real '/path/Point.Name/'
+                "#,
+                FnConfKind::Fn( FnConfig { name: "interpolate".to_string(), type_: FnConfPointType::Unknown, inputs: IndexMap::from([
+                    ("table".to_string(), FnConfKind::Param( "[[0,0.0],[10,5.5]]".to_string() )),
+                    ("input".to_string(), FnConfKind::Point( FnConfig { name: "/path/Point.Name/".to_string(), type_: FnConfPointType::Real, inputs: IndexMap::new() } )),
+                ]) } )
+            ),
         ];
         for (value, target) in test_data {
             debug!("test value: {:?}", value);
@@ -41,6 +41,7 @@ mod tests {
             alarm: None,
             address: None,
             filters: None,
+            limits: None,
             comment: Some("Some indication".to_string()),
         };
         let test_point2 = PointConfig {
@@ -51,6 +52,7 @@ mod tests {
             alarm: None,
             address: None,
             filters: None,
+            limits: None,
             comment: Some("Some indication".to_string()),
         };
         let test_data = [
@@ -106,4 +108,26 @@ mod tests {
             assert_eq!(points, points_target);
         }
     }
+    ///
+    /// The alarm limits are evaluated only for the Profinet client DB points
+    #[test]
+    #[should_panic(expected = "alarm limits are evaluated only for the Profinet client DB points")]
+    fn test_fn_config_point_limits() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_id = "test FnConfig | point limits";
+        let self_name = Name::new("", self_id);
+        let conf: serde_yaml::Value = serde_yaml::from_str(r#"
+            fn ToMultiQueue:
+                in1 point Temperature:
+                    type: 'Real'
+                    limits:
+                        hi: 80.0
+                    input:
+                        const real 0.05
+        "#).unwrap();
+        let mut vars = vec![];
+        FnConfig::from_yaml(self_id, &self_name, &conf, &mut vars);
+    }
 }
@@ -22,4 +22,6 @@ mod conf_subscribe_test;
 
 mod subscription_ctiteria_test;
 
-mod diag_keywd_test;
\ No newline at end of file
+mod diag_keywd_test;
+
+mod conf_template_test;
\ No newline at end of file
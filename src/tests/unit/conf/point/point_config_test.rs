@@ -53,6 +53,7 @@ mod point_config {
                     history: PointConfigHistory::None, alarm: Some(0),
                     address: Some(PointConfigAddress { offset: Some(0), bit: Some(0) }),
                     filters: Some(PointConfigFilter { threshold: 5.0, factor: None }),
+                    limits: None,
                     comment: Some(format!("Test Point Bool")),
                 },
             ),
@@ -74,6 +75,7 @@ mod point_config {
                     history: PointConfigHistory::None, alarm: Some(0),
                     address: Some(PointConfigAddress { offset: Some(0), bit: Some(0) }),
                     filters: Some(PointConfigFilter { threshold: 5.0, factor: Some(0.1) }),
+                    limits: None,
                     comment: Some(format!("Test Point Bool")),
                 },
             ),
@@ -91,6 +93,7 @@ mod point_config {
                     history: PointConfigHistory::Read, alarm: None,
                     address: Some(PointConfigAddress { offset: Some(0), bit: None }),
                     filters: None,
+                    limits: None,
                     comment: Some(format!("Test Point")),
                 },
             ),
@@ -108,6 +111,7 @@ mod point_config {
                     history: PointConfigHistory::None, alarm: Some(4),
                     address: Some(PointConfigAddress { offset: Some(0), bit: None }),
                     filters: None,
+                    limits: None,
                     comment: Some(format!("Test Point")),
                 },
             ),
@@ -125,6 +129,7 @@ mod point_config {
                     history: PointConfigHistory::Write, alarm: None,
                     address: Some(PointConfigAddress { offset: Some(12), bit: None }),
                     filters: None,
+                    limits: None,
                     comment: Some(format!("Test Point")),
                 },
             ),
@@ -142,6 +147,7 @@ mod point_config {
                     history: PointConfigHistory::ReadWrite, alarm: None,
                     address: Some(PointConfigAddress { offset: Some(12), bit: None }),
                     filters: None,
+                    limits: None,
                     comment: Some(format!("Test Point")),
                 },
             ),
@@ -187,6 +193,7 @@ mod point_config {
                     history: PointConfigHistory::ReadWrite, alarm: Some(0),
                     address: Some(PointConfigAddress { offset: Some(0), bit: Some(0) }),
                     filters: Some(PointConfigFilter { threshold: 5.0, factor: None }),
+                    limits: None,
                     comment: Some(format!("Test Point Bool")),
                 },
             ),
@@ -204,6 +211,7 @@ mod point_config {
                     history: PointConfigHistory::Write, alarm: None,
                     address: Some(PointConfigAddress { offset: Some(0), bit: None }),
                     filters: None,
+                    limits: None,
                     comment: Some(format!("Test Point")),
                 },
             ),
@@ -221,6 +229,7 @@ mod point_config {
                     history: PointConfigHistory::None, alarm: Some(4),
                     address: Some(PointConfigAddress { offset: Some(0), bit: None }),
                     filters: None,
+                    limits: None,
                     comment: Some(format!("Test Point")),
                 },
             ),
@@ -237,6 +246,7 @@ mod point_config {
                     history: PointConfigHistory::None, alarm: None,
                     address: Some(PointConfigAddress { offset: Some(12), bit: None }),
                     filters: None,
+                    limits: None,
                     comment: Some(format!("Test Point")),
                 },
             ),
@@ -281,6 +291,7 @@ mod point_config {
                     history: PointConfigHistory::ReadWrite, alarm: Some(0),
                     address: Some(PointConfigAddress { offset: Some(0), bit: Some(0) }),
                     filters: Some(PointConfigFilter { threshold: 5.0, factor: None }),
+                    limits: None,
                     comment: Some(format!("Test Point Bool")),
                 },
             ),
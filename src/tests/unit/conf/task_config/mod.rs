@@ -1,3 +1,3 @@
 pub mod task_config_new_test;
 pub mod task_config_read_test;
-
+pub mod task_config_mode_test;
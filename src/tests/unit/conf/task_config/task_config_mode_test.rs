@@ -0,0 +1,122 @@
+#[cfg(test)]
+
+mod task_config_mode {
+    use log::info;
+    use std::{sync::Once, time::Duration};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::conf::{point_config::name::Name, task_config::TaskConfig, task_mode::TaskMode};
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing the 'mode' parameter parsed & the default mode is on-event
+    #[test]
+    fn mode() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_id = "task_config_mode_test";
+        let self_name = Name::new("", self_id);
+        info!("{}", self_id);
+        let test_data = [
+            (
+                r#"service Task Task0:
+                    cycle: 100 ms
+                    in queue recv-queue:
+                        max-length: 10000
+                    fn Debug:
+                        input: point real '/path/Point.Name'
+                "#,
+                TaskMode::OnEvent,
+            ),
+            (
+                r#"service Task Task1:
+                    cycle: 100 ms
+                    mode: on-event
+                    in queue recv-queue:
+                        max-length: 10000
+                    fn Debug:
+                        input: point real '/path/Point.Name'
+                "#,
+                TaskMode::OnEvent,
+            ),
+            (
+                r#"service Task Task2:
+                    cycle: 100 ms
+                    mode: cyclic
+                    in queue recv-queue:
+                        max-length: 10000
+                    fn Debug:
+                        input: point real '/path/Point.Name'
+                "#,
+                TaskMode::Cyclic,
+            ),
+            (
+                r#"service Task Task3:
+                    cycle: 1 s
+                    mode: both
+                    in queue recv-queue:
+                        max-length: 10000
+                    fn Debug:
+                        input: point real '/path/Point.Name'
+                "#,
+                TaskMode::Both,
+            ),
+        ];
+        for (conf, target) in test_data {
+            let conf = serde_yaml::from_str(conf).unwrap();
+            let result = TaskConfig::from_yaml(&self_name, &conf);
+            assert!(result.mode == target, "\nresult: {:?}\ntarget: {:?}", result.mode, target);
+            assert!(result.nodes.len() == 1, "\nresult: {:?}\ntarget: {:?}", result.nodes.len(), 1);
+            assert!(result.cycle.is_some_and(|cycle| cycle >= Duration::from_millis(100)), "\nresult: {:?}", result.cycle);
+        }
+    }
+    ///
+    /// Testing cyclic mode without cycle is rejected
+    #[test]
+    #[should_panic]
+    fn cyclic_without_cycle() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_name = Name::new("", "task_config_mode_test");
+        let conf = serde_yaml::from_str(r#"service Task Task4:
+                mode: cyclic
+                in queue recv-queue:
+                    max-length: 10000
+                fn Debug:
+                    input: point real '/path/Point.Name'
+            "#).unwrap();
+        TaskConfig::from_yaml(&self_name, &conf);
+    }
+    ///
+    /// Testing unknown mode is rejected
+    #[test]
+    #[should_panic]
+    fn unknown_mode() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_name = Name::new("", "task_config_mode_test");
+        let conf = serde_yaml::from_str(r#"service Task Task5:
+                cycle: 100 ms
+                mode: periodic
+                in queue recv-queue:
+                    max-length: 10000
+                fn Debug:
+                    input: point real '/path/Point.Name'
+            "#).unwrap();
+        TaskConfig::from_yaml(&self_name, &conf);
+    }
+}
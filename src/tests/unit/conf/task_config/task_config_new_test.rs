@@ -6,7 +6,7 @@ mod task_config_new {
     use std::{sync::Once, time::Duration};
     use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
     use crate::conf::{
-        conf_subscribe::ConfSubscribe, fn_::{fn_conf_keywd::FnConfPointType, fn_conf_kind::FnConfKind, fn_config::FnConfig}, point_config::name::Name, task_config::TaskConfig
+        conf_subscribe::ConfSubscribe, fn_::{fn_conf_keywd::FnConfPointType, fn_conf_kind::FnConfKind, fn_config::FnConfig}, point_config::name::Name, task_config::TaskConfig, task_mode::TaskMode
     };
     ///
     ///
@@ -65,6 +65,9 @@ mod task_config_new {
                 TaskConfig {
                     name: Name::new(&self_name, "Task0"),
                     cycle: Some(Duration::from_millis(100)),
+                    mode: TaskMode::OnEvent,
+                    retain: false,
+                    retain_delay: Duration::from_secs(30),
                     rx: format!("recv-queue"),
                     rx_max_length: 10000,
                     subscribe: ConfSubscribe::new(serde_yaml::Value::Null),
@@ -122,6 +125,9 @@ mod task_config_new {
                 TaskConfig {
                     name: Name::new(&self_name, "Task1"),
                     cycle: Some(Duration::from_millis(100)),
+                    mode: TaskMode::OnEvent,
+                    retain: false,
+                    retain_delay: Duration::from_secs(30),
                     rx: format!("recv-queue"),
                     rx_max_length: 10000,
                     subscribe: ConfSubscribe::new(serde_yaml::Value::Null),
@@ -179,6 +185,9 @@ mod task_config_new {
                 TaskConfig {
                     name: Name::new(&self_name, "Task2"),
                     cycle: Some(Duration::from_millis(100)),
+                    mode: TaskMode::OnEvent,
+                    retain: false,
+                    retain_delay: Duration::from_secs(30),
                     rx: format!("recv-queue"),
                     rx_max_length: 10000,
                     subscribe: ConfSubscribe::new(serde_yaml::Value::Null),
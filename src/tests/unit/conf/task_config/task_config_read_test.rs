@@ -9,7 +9,7 @@ mod task_config_read {
         conf_subscribe::ConfSubscribe,
         fn_::{fn_conf_keywd::FnConfPointType, fn_conf_kind::FnConfKind, fn_config::FnConfig},
         point_config::name::Name,
-        task_config::TaskConfig,
+        task_config::TaskConfig, task_mode::TaskMode,
     };
     ///
     ///
@@ -38,6 +38,9 @@ mod task_config_read {
         let target = TaskConfig {
             name: Name::new(&self_name, "Task1"),
             cycle: Some(Duration::from_millis(100)),
+            mode: TaskMode::OnEvent,
+            retain: false,
+            retain_delay: Duration::from_secs(30),
             rx: format!("recv-queue"),
             rx_max_length: 10000,
             subscribe: ConfSubscribe::new(serde_yaml::Value::Null),
@@ -0,0 +1,103 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::sync::Once;
+    use chrono::Utc;
+    use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+    use crate::{
+        conf::point_config::{name::Name, point_config::PointConfig, point_config_type::PointConfigType},
+        core_::{alarm::alarm_limits::AlarmLimits, cot::cot::Cot, point::{point::Point, point_type::PointType}, status::status::Status},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> PointConfig {
+        let conf = serde_yaml::from_str(r#"
+            Temp:
+                type: Real
+                alarm: 4
+                limits:
+                    hihi: 90.0
+                    hi: 80.0
+                    lo: 10.0
+                    deadband: 1.0
+                    delay: 500ms
+        "#).unwrap();
+        PointConfig::from_yaml(&Name::new("", "test"), &conf)
+    }
+    ///
+    ///
+    #[test]
+    fn test_alarm_points() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_alarm_points");
+        let conf = init_each();
+        let points = conf.alarm_points();
+        debug!("points: {:#?}", points);
+        let names: Vec<&str> = points.iter().map(|point| point.name.as_str()).collect();
+        assert_eq!(names, ["/test/Temp.HiHi", "/test/Temp.Hi", "/test/Temp.Lo"]);
+        for point in &points {
+            assert_eq!(point._type, PointConfigType::Bool);
+            assert_eq!(point.alarm, Some(4));
+            assert_eq!(point.limits, None);
+        }
+        let target = conf.clone();
+        let conf = PointConfig::from_yaml(&Name::new("", "test"), &conf.to_yaml());
+        assert_eq!(conf.limits, target.limits);
+    }
+    ///
+    ///
+    #[test]
+    fn test_limits() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_limits");
+        let conf = init_each();
+        let mut alarmLimits = AlarmLimits::new("test", 0, &conf);
+        let start = Utc::now();
+        let test_data: [(i64, Option<f32>, Vec<(&str, bool)>); 14] = [
+            // millis, value, target alarms
+            (0, Some(50.0), vec![("/test/Temp.HiHi", false), ("/test/Temp.Hi", false), ("/test/Temp.Lo", false)]),
+            (100, Some(85.0), vec![]),
+            (400, None, vec![]),                                                // delay isn't elapsed
+            (600, None, vec![("/test/Temp.Hi", true)]),
+            (700, Some(79.5), vec![]),                                          // inside the deadband
+            (800, Some(78.9), vec![("/test/Temp.Hi", false)]),
+            (900, Some(95.0), vec![]),
+            (1000, Some(70.0), vec![]),                                         // shorter then delay
+            (1100, Some(95.0), vec![]),
+            (1600, None, vec![("/test/Temp.HiHi", true), ("/test/Temp.Hi", true)]),
+            (1700, Some(5.0), vec![("/test/Temp.HiHi", false), ("/test/Temp.Hi", false)]),
+            (2200, None, vec![("/test/Temp.Lo", true)]),
+            (2300, Some(10.5), vec![]),                                         // inside the deadband
+            (2400, Some(11.5), vec![("/test/Temp.Lo", false)]),
+        ];
+        for (millis, value, target) in test_data {
+            let timestamp = start + chrono::Duration::milliseconds(millis);
+            if let Some(value) = value {
+                alarmLimits.add(&PointType::Real(Point::new(0, &conf.name, value, Status::Ok, Cot::Inf, timestamp)));
+            }
+            let alarms = alarmLimits.next(timestamp);
+            debug!("millis: {}   |   value: {:?}   |   alarms: {:?}", millis, value, alarms);
+            let result: Vec<(String, bool)> = alarms.iter().map(|point| (point.name(), point.as_bool().value.0)).collect();
+            let target: Vec<(String, bool)> = target.into_iter().map(|(name, state)| (name.to_owned(), state)).collect();
+            assert_eq!(result, target, "millis: {}", millis);
+            for alarm in alarms {
+                assert_eq!(alarm.timestamp(), timestamp);
+            }
+        }
+    }
+}
@@ -0,0 +1 @@
+pub mod alarm_limits_test;
\ No newline at end of file
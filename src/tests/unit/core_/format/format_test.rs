@@ -36,6 +36,7 @@ mod tests {
             ("abc {a} xyz {b} rty {c} str {d}.", (false, 12, 1.618, "1223"), "abc false xyz 12 rty 1.618 str 1223."),
             ("abc {a} xyz '{b}' rty \"{c}\" str '{d}'.", (false, 12, 1.618, "1223"), "abc false xyz '12' rty \"1.618\" str '1223'."),
             ("abc {a} xyz '{b}' rty \"{c}\" str \"{d}\".", (false, 12, 1.618, "1223"), "abc false xyz '12' rty \"1.618\" str \"1223\"."),
+            ("{\"a\": {a}, \"b\": {\"c\": {c}, \"d\": \"{d}\"}}", (false, 12, 1.618, "1223"), "{\"a\": false, \"b\": {\"c\": 1.618, \"d\": \"1223\"}}"),
         ];
         for (input, values, target) in test_data {
             let mut format = Format::new(input);
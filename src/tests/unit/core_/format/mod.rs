@@ -1 +1,3 @@
-pub mod format_test;
\ No newline at end of file
+pub mod format_test;
+
+pub mod sql_format_test;
\ No newline at end of file
@@ -0,0 +1,120 @@
+#[cfg(test)]
+
+mod sql_format {
+    use log::debug;
+    use std::sync::Once;
+    use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
+    use crate::core_::{format::{sql_dialect::SqlDialect, sql_format::SqlFormat}, point::point_type::ToPoint};
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Untyped markers escaped inside of the user quotes, for each dialect
+    #[test]
+    fn escape() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_id = "test SqlFormat.escape";
+        println!("\n{}", self_id);
+        let input = "UPDATE t SET v = '{a}' WHERE id = '{b}';";
+        let test_data = vec![
+            (SqlDialect::Postgres, "x'; DROP TABLE t; --", "UPDATE t SET v = 'x''; DROP TABLE t; --' WHERE id = '12';"),
+            (SqlDialect::Sqlite, "it's", "UPDATE t SET v = 'it''s' WHERE id = '12';"),
+            (SqlDialect::MySql, "x\\'; DROP TABLE t; --", "UPDATE t SET v = 'x\\\\\\'; DROP TABLE t; --' WHERE id = '12';"),
+            (SqlDialect::MySql, "a\"b\nc", "UPDATE t SET v = 'a\\\"b\\nc' WHERE id = '12';"),
+            (SqlDialect::Postgres, "{b}", "UPDATE t SET v = '{b}' WHERE id = '12';"),
+        ];
+        for (dialect, value, target) in test_data {
+            let mut format = SqlFormat::new(input, dialect);
+            format.insert("a", value.to_point(0, "/a"));
+            format.insert("b", 12.to_point(0, "/b"));
+            let result = format.out();
+            debug!("{} | dialect: {:?}, result: {}", self_id, dialect, result);
+            assert!(result == target, "{:?} \nresult: {} \ntarget: {}", dialect, result, target);
+        }
+    }
+    ///
+    /// Typed markers rendered as SQL literals, the value which can't be converted rendered as NULL
+    #[test]
+    fn typed() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_id = "test SqlFormat.typed";
+        println!("\n{}", self_id);
+        let input = "INSERT INTO t VALUES ({a.name:text}, {a:text}, {b:int}, {c:real}, {d:bool}, {b.status:int});";
+        let test_data = vec![
+            ("o'k", 12, 1.5, true, "INSERT INTO t VALUES ('/a', 'o''k', 12, 1.5, TRUE, 0);", 0),
+            ("12", 0, 0.0, false, "INSERT INTO t VALUES ('/a', '12', 0, 0, FALSE, 0);", 0),
+        ];
+        for (a, b, c, d, target, errors) in test_data {
+            let mut format = SqlFormat::new(input, SqlDialect::Postgres);
+            format.insert("a.name:text", a.to_point(0, "/a"));
+            format.insert("a:text", a.to_point(0, "/a"));
+            format.insert("b:int", b.to_point(0, "/b"));
+            format.insert("c:real", c.to_point(0, "/c"));
+            format.insert("d:bool", d.to_point(0, "/d"));
+            format.insert("b.status:int", b.to_point(0, "/b"));
+            let (result, result_errors) = format.build();
+            debug!("{} | result: {}", self_id, result);
+            assert!(result == target, "\nresult: {} \ntarget: {}", result, target);
+            assert!(result_errors.len() == errors, "\nresult: {:?} \ntarget: {}", result_errors, errors);
+        }
+        let mut format = SqlFormat::new("SELECT {a:int}, {a:real};", SqlDialect::Postgres);
+        format.insert("a:int", "1; DROP TABLE t".to_point(0, "/a"));
+        format.insert("a:real", "NaN".to_point(0, "/a"));
+        let (result, errors) = format.build();
+        assert!(result == "SELECT NULL, NULL;", "\nresult: {} \ntarget: SELECT NULL, NULL;", result);
+        assert!(errors.len() == 2, "\nresult: {:?} \ntarget: 2 errors", errors);
+    }
+    ///
+    /// Untyped markers without quotes: numbers as is, Strings as the quoted literal
+    #[test]
+    fn unquoted() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_id = "test SqlFormat.unquoted";
+        println!("\n{}", self_id);
+        let input = "DELETE FROM {table} WHERE id = {a} AND status = {b.status} AND value = {b.value} AND name = {b.name};";
+        let test_data = vec![
+            (SqlDialect::Postgres, "1 OR 1=1", "DELETE FROM public.event WHERE id = '1 OR 1=1' AND status = 0 AND value = 12 AND name = '/b';"),
+            (SqlDialect::Postgres, "1' OR '1'='1", "DELETE FROM public.event WHERE id = '1'' OR ''1''=''1' AND status = 0 AND value = 12 AND name = '/b';"),
+            (SqlDialect::MySql, "1\\' OR 1=1 --", "DELETE FROM public.event WHERE id = '1\\\\\\' OR 1=1 --' AND status = 0 AND value = 12 AND name = '/b';"),
+        ];
+        for (dialect, value, target) in test_data {
+            let mut format = SqlFormat::new(input, dialect);
+            format.insert_identifier("table", "public.event");
+            format.insert("a", value.to_point(0, "/a"));
+            format.insert("b.status", 12.to_point(0, "/b"));
+            format.insert("b.value", 12.to_point(0, "/b"));
+            format.insert("b.name", 12.to_point(0, "/b"));
+            let result = format.out();
+            debug!("{} | dialect: {:?}, result: {}", self_id, dialect, result);
+            assert!(result == target, "{:?} \nresult: {} \ntarget: {}", dialect, result, target);
+        }
+    }
+    ///
+    /// Identifier can't be used to inject the sql
+    #[test]
+    #[should_panic]
+    fn invalid_identifier() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        let mut format = SqlFormat::new("SELECT * FROM {table};", SqlDialect::Postgres);
+        format.insert_identifier("table", "event; DROP TABLE event");
+    }
+}
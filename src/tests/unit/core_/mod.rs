@@ -10,4 +10,6 @@ pub mod point;
 
 pub mod net;
 
-pub mod cot;
\ No newline at end of file
+pub mod cot;
+
+pub mod alarm;
\ No newline at end of file
@@ -1 +1,2 @@
-mod services_points_test;
\ No newline at end of file
+mod services_points_test;
+pub mod services_subscribe_all_test;
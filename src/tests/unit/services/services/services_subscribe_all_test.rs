@@ -0,0 +1,158 @@
+use std::{collections::HashMap, sync::mpsc::{self, Receiver, Sender}};
+use crate::{
+    conf::point_config::name::Name,
+    core_::{object::object::Object, point::point_type::PointType},
+    services::{multi_queue::subscription_criteria::SubscriptionCriteria, service::{service::Service, service_handles::ServiceHandles}},
+};
+#[cfg(test)]
+
+mod services_subscribe_all {
+    use log::debug;
+    use std::{sync::{Arc, Mutex, Once}, time::Duration};
+    use testing::stuff::max_test_duration::TestDuration;
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        core_::point::point_type::{PointType, ToPoint},
+        services::{safe_lock::SafeLock, services::Services},
+        tests::unit::services::services::services_subscribe_all_test::MockPublisher,
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Testing the points of the multiple services received by the single Receiver
+    /// & all subscriptions removed by unsubscribe_all
+    #[test]
+    fn subscribe_all() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        init_once();
+        init_each();
+        println!();
+        let self_id = "services_subscribe_all_test";
+        println!("\n{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(10));
+        test_duration.run().unwrap();
+        let receiver_name = format!("/{}/Receiver", self_id);
+        let services = Arc::new(Mutex::new(Services::new(self_id)));
+        let publishers: Vec<Arc<Mutex<MockPublisher>>> = ["Queue1", "Queue2", "Queue3"].iter().map(|name| {
+            Arc::new(Mutex::new(MockPublisher::new(self_id, name)))
+        }).collect();
+        for publisher in &publishers {
+            services.slock().insert(publisher.clone());
+        }
+        let subscriptions: Vec<(String, Vec<_>)> = publishers.iter().map(|publisher| {
+            (format!("/{}/{}", self_id, publisher.lock().unwrap().name), vec![])
+        }).collect();
+        let recv = services.slock().subscribe_all(&receiver_name, &subscriptions);
+        for publisher in &publishers {
+            let count = publisher.lock().unwrap().subscribers();
+            assert!(count == 1, "\nresult: {:?}\ntarget: {:?}", count, 1);
+        }
+        let mut target = vec![];
+        for (index, publisher) in publishers.iter().enumerate() {
+            for value in 0..10 {
+                let point = ((index * 100 + value) as i64).to_point(0, &format!("/{}/Point{}", self_id, index));
+                publisher.lock().unwrap().publish(point);
+                target.push((index * 100 + value) as i64);
+            }
+        }
+        let mut result = vec![];
+        while result.len() < target.len() {
+            match recv.recv_timeout(Duration::from_secs(1)) {
+                Ok(PointType::Int(point)) => result.push(point.value),
+                Ok(point) => panic!("Unexpected point: {:?}", point),
+                Err(err) => panic!("Receive error: {:?}, received: {:?}", err, result),
+            }
+        }
+        debug!("received: {:?}", result);
+        result.sort();
+        assert!(result == target, "\nresult: {:?}\ntarget: {:?}", result, target);
+        services.slock().unsubscribe_all(&receiver_name, &subscriptions).unwrap();
+        for publisher in &publishers {
+            let count = publisher.lock().unwrap().subscribers();
+            assert!(count == 0, "\nresult: {:?}\ntarget: {:?}", count, 0);
+        }
+        let result = recv.recv_timeout(Duration::from_secs(1));
+        assert!(result.is_err(), "\nresult: {:?}\ntarget: Disconnected", result);
+        test_duration.exit();
+    }
+}
+///
+/// Service, sends published points to all subscribers
+#[derive(Debug)]
+struct MockPublisher {
+    id: String,
+    name: String,
+    senders: HashMap<String, Sender<PointType>>,
+}
+///
+///
+impl MockPublisher {
+    fn new(parent: &str, name: &str) -> Self {
+        Self {
+            id: format!("/{}/{}", parent, name),
+            name: name.to_owned(),
+            senders: HashMap::new(),
+        }
+    }
+    ///
+    /// Sends the point to all subscribers
+    fn publish(&self, point: PointType) {
+        for send in self.senders.values() {
+            send.send(point.clone()).unwrap();
+        }
+    }
+    ///
+    /// Returns the number of the subscribers
+    fn subscribers(&self) -> usize {
+        self.senders.len()
+    }
+}
+///
+///
+impl Object for MockPublisher {
+    fn id(&self) -> &str {
+        &self.id
+    }
+    fn name(&self) -> Name {
+        Name::from(self.id.as_str())
+    }
+}
+///
+///
+impl Service for MockPublisher {
+    //
+    //
+    fn subscribe(&mut self, receiver_id: &str, _points: &[SubscriptionCriteria]) -> (Sender<PointType>, Receiver<PointType>) {
+        let (send, recv) = mpsc::channel();
+        self.senders.insert(receiver_id.to_owned(), send.clone());
+        (send, recv)
+    }
+    //
+    //
+    fn unsubscribe(&mut self, receiver_name: &str, _points: &[SubscriptionCriteria]) -> Result<(), String> {
+        match self.senders.remove(receiver_name) {
+            Some(_) => Ok(()),
+            None => Err(format!("{}.unsubscribe | Receiver '{}' - not found", self.id, receiver_name)),
+        }
+    }
+    //
+    //
+    fn run(&mut self) -> Result<ServiceHandles, String> {
+        Ok(ServiceHandles::new(vec![]))
+    }
+    //
+    //
+    fn exit(&self) {}
+}
@@ -6,7 +6,7 @@ mod tests {
     use std::{sync::Once, rc::Rc, cell::RefCell};
     use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
     use crate::{
-        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_add::FnAdd, fn_input::FnInput}
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{cot::cot::Cot, point::{point::Point, point_type::{PointType, ToPoint}}, status::status::Status, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_add::FnAdd, fn_input::FnInput}
     };
     ///
     ///
@@ -121,4 +121,42 @@ mod tests {
             println!();
         }
     }
+    ///
+    /// Int overflow, not finite Real / Double and different Cot returns 0 with Status::Invalid
+    #[test]
+    fn test_overflow() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_overflow");
+        let test_data = vec![
+            (i64::MAX.to_point(0, "int"), 0.to_point(0, "int"), Cot::Inf, i64::MAX as f64, Status::Ok),
+            (i64::MAX.to_point(0, "int"), 1.to_point(0, "int"), Cot::Inf, 0.0, Status::Invalid),
+            (i64::MIN.to_point(0, "int"), (-1).to_point(0, "int"), Cot::Inf, 0.0, Status::Invalid),
+            (f32::MAX.to_point(0, "real"), f32::MAX.to_point(0, "real"), Cot::Inf, 0.0, Status::Invalid),
+            (f64::MAX.to_point(0, "double"), f64::MAX.to_point(0, "double"), Cot::Inf, 0.0, Status::Invalid),
+            (1.5f64.to_point(0, "double"), 1.to_point(0, "int"), Cot::Inf, 2.5, Status::Ok),
+            (1.5f64.to_point(0, "double"), 1.to_point(0, "int"), Cot::Act, 0.0, Status::Invalid),
+        ];
+        for (value1, value2, cot2, target, status) in test_data {
+            let value2 = match value2 {
+                PointType::Int(point) => PointType::Int(Point { cot: cot2, ..point }),
+                PointType::Real(point) => PointType::Real(Point { cot: cot2, ..point }),
+                PointType::Double(point) => PointType::Double(Point { cot: cot2, ..point }),
+                _ => unreachable!(),
+            };
+            let input1 = init_each(value1.clone(), FnConfPointType::Any);
+            let input2 = init_each(value2.clone(), FnConfPointType::Any);
+            let mut fnAdd = FnAdd::new("test", input1, input2);
+            let state = fnAdd.out();
+            debug!("value1: {:?}, value2: {:?}   |   state: {:?}", value1, value2, state);
+            let result = match &state {
+                PointType::Int(point) => point.value as f64,
+                PointType::Real(point) => point.value as f64,
+                PointType::Double(point) => point.value,
+                _ => unreachable!(),
+            };
+            assert_eq!(result, target);
+            assert_eq!(state.status(), status);
+        }
+    }
 }
@@ -0,0 +1,110 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::{Arc, Mutex, Once}, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::{fn_::fn_conf_keywd::FnConfPointType, point_config::name::Name, task_config::TaskConfig},
+        core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef},
+        services::{services::Services, task::{nested_function::{fn_::FnOut, fn_and::FnAnd, fn_input::FnInput}, task_nodes::TaskNodes}},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_inputs() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_inputs");
+        let test_data = vec![
+            (vec![true.to_point(0, "bool"), true.to_point(0, "bool")], true),
+            (vec![true.to_point(0, "bool"), false.to_point(0, "bool")], false),
+            (vec![true.to_point(0, "bool"), true.to_point(0, "bool"), true.to_point(0, "bool")], true),
+            (vec![true.to_point(0, "bool"), true.to_point(0, "bool"), false.to_point(0, "bool")], false),
+            (vec![1.to_point(0, "int"), 0.5f32.to_point(0, "real"), (-2.0f64).to_point(0, "double")], true),
+            (vec![1.to_point(0, "int"), 0.to_point(0, "int")], false),
+            (vec![true.to_point(0, "bool"), 0.0f64.to_point(0, "double")], false),
+            (vec![true.to_point(0, "bool")], true),
+        ];
+        for (values, target) in test_data {
+            let inputs: Vec<FnInOutRef> = values.iter().map(|value: &PointType| {
+                init_each(value.clone(), FnConfPointType::Any)
+            }).collect();
+            let mut fnAnd = FnAnd::new("test", inputs);
+            let state = fnAnd.out();
+            debug!("values: {:?}   |   state: {:?}", values, state);
+            assert_eq!(state.as_bool().value.0, target);
+        }
+    }
+    ///
+    /// Builds the TaskNodes from the [conf]
+    fn build(self_id: &str, conf: &str) -> TaskNodes {
+        let parent = Name::new("", self_id);
+        let conf = TaskConfig::from_yaml(&parent, &serde_yaml::from_str(conf).unwrap());
+        let services = Arc::new(Mutex::new(Services::new(self_id)));
+        let mut task_nodes = TaskNodes::new(self_id);
+        task_nodes.buildNodes(&parent, conf, services);
+        task_nodes
+    }
+    ///
+    /// The 'inputN' inputs are taken in the order of N, not in the order of config
+    #[test]
+    fn test_inputs_order() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_inputs_order");
+        let task_nodes = build("test_inputs_order", r#"
+            service Task AndTask:
+                cycle: 10 ms
+                in queue recv-queue:
+                    max-length: 10000
+                let Text:
+                    input fn concat:
+                        input10: const int 10
+                        input2: const int 2
+                        input1: const int 1
+        "#);
+        let result = task_nodes.getVar("Text").unwrap().borrow_mut().out().as_string().value;
+        debug!("result: {:?}", result);
+        assert_eq!(result, "1210");
+    }
+    ///
+    /// The bare 'input' isn't the 'inputN'
+    #[test]
+    #[should_panic]
+    fn test_inputs_bare() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_inputs_bare");
+        build("test_inputs_bare", r#"
+            service Task AndTask:
+                cycle: 10 ms
+                in queue recv-queue:
+                    max-length: 10000
+                let Ok:
+                    input fn and:
+                        input: const bool true
+        "#);
+    }
+}
@@ -0,0 +1,145 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::{fn_::fn_conf_keywd::FnConfPointType, point_config::{name::Name, point_config::PointConfig}},
+        core_::{cot::cot::Cot, point::point_type::{PointType, ToPoint}, status::status::Status, types::fn_in_out_ref::FnInOutRef},
+        services::task::nested_function::{fn_::FnOut, fn_input::FnInput, fn_point::FnPoint, fn_to_int::FnToInt, fn_to_real::FnToReal, fn_to_double::FnToDouble, fn_to_bool::FnToBool, fn_to_string::FnToString},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_to_numeric() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_to_numeric");
+        let test_data = [
+            // input, int, real, double, status
+            (true.to_point(0, "input"), 1, 1.0, 1.0, Status::Ok),
+            (7.to_point(0, "input"), 7, 7.0, 7.0, Status::Ok),
+            (2.5f32.to_point(0, "input"), 2, 2.5, 2.5, Status::Ok),
+            ((-2.5f64).to_point(0, "input"), -2, -2.5, -2.5, Status::Ok),
+            ("12".to_point(0, "input"), 12, 12.0, 12.0, Status::Ok),
+            (" 3.75 ".to_point(0, "input"), 3, 3.75, 3.75, Status::Ok),
+            ("abc".to_point(0, "input"), 0, 0.0, 0.0, Status::Invalid),
+            ("".to_point(0, "input"), 0, 0.0, 0.0, Status::Invalid),
+        ];
+        for (value, target_int, target_real, target_double, target_status) in test_data {
+            let input = init_each(value.clone(), FnConfPointType::Any);
+            let state = FnToInt::new("test", input.clone()).out();
+            debug!("value: {:?}   |   int: {:?}", value, state);
+            assert_eq!(state.as_int().value, target_int);
+            assert_eq!(state.status(), target_status);
+            let state = FnToReal::new("test", input.clone()).out();
+            debug!("value: {:?}   |   real: {:?}", value, state);
+            assert_eq!(state.as_real().value, target_real);
+            assert_eq!(state.status(), target_status);
+            let state = FnToDouble::new("test", input).out();
+            debug!("value: {:?}   |   double: {:?}", value, state);
+            assert_eq!(state.as_double().value, target_double);
+            assert_eq!(state.status(), target_status);
+        }
+    }
+    ///
+    /// The Int point generated by the FnPoint converted by the same rule as FnToInt - truncated
+    #[test]
+    fn test_point_int() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_point_int");
+        let test_data = [
+            // input, int
+            (2.4f32.to_point(0, "input"), 2),
+            (2.5f32.to_point(0, "input"), 2),
+            (2.9f64.to_point(0, "input"), 2),
+            ((-2.5f32).to_point(0, "input"), -2),
+            ((-2.9f64).to_point(0, "input"), -2),
+            (" 3.75 ".to_point(0, "input"), 3),
+        ];
+        for (value, target) in test_data {
+            let input = init_each(value.clone(), FnConfPointType::Any);
+            let conf = PointConfig::from_yaml(&Name::from("/App/Task"), &serde_yaml::from_str(r#"
+                point Point.Int:
+                    type: 'Int'
+            "#).unwrap());
+            let point = FnPoint::new("test", 0, conf, Cot::Inf, input.clone()).out();
+            debug!("value: {:?}   |   point: {:?}", value, point);
+            assert_eq!(point.as_int().value, target);
+            let state = FnToInt::new("test", input).out();
+            debug!("value: {:?}   |   int: {:?}", value, state);
+            assert_eq!(state.as_int().value, target);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_to_bool() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_to_bool");
+        let test_data = [
+            (false.to_point(0, "input"), false, Status::Ok),
+            (3.to_point(0, "input"), true, Status::Ok),
+            (0.0f32.to_point(0, "input"), false, Status::Ok),
+            (0.1f64.to_point(0, "input"), true, Status::Ok),
+            ("true".to_point(0, "input"), true, Status::Ok),
+            ("false".to_point(0, "input"), false, Status::Ok),
+            ("1".to_point(0, "input"), true, Status::Ok),
+            ("0".to_point(0, "input"), false, Status::Ok),
+            ("yes".to_point(0, "input"), false, Status::Invalid),
+        ];
+        for (value, target, target_status) in test_data {
+            let input = init_each(value.clone(), FnConfPointType::Any);
+            let state = FnToBool::new("test", input).out();
+            debug!("value: {:?}   |   state: {:?}", value, state);
+            assert_eq!(state.as_bool().value.0, target);
+            assert_eq!(state.status(), target_status);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_to_string() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_to_string");
+        let test_data = [
+            (true.to_point(0, "input"), "true"),
+            ((-12).to_point(0, "input"), "-12"),
+            (1.5f32.to_point(0, "input"), "1.5"),
+            (0.25f64.to_point(0, "input"), "0.25"),
+            ("text".to_point(0, "input"), "text"),
+        ];
+        for (value, target) in test_data {
+            let input = init_each(value.clone(), FnConfPointType::Any);
+            let state = FnToString::new("test", input).out();
+            debug!("value: {:?}   |   state: {:?}", value, state);
+            assert_eq!(state.as_string().value, target);
+            assert_eq!(state.status(), Status::Ok);
+        }
+    }
+}
@@ -0,0 +1,125 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{cot::cot::Cot, point::{point::Point, point_type::{PointType, ToPoint}}, status::status::Status, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_compare::to_double, fn_div::FnDiv, fn_input::FnInput}
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_int() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_int");
+        let input1 = init_each(0.to_point(0, "int"), FnConfPointType::Int);
+        let input2 = init_each(1.to_point(0, "int"), FnConfPointType::Int);
+        let mut fnDiv = FnDiv::new(
+            "test",
+            input1.clone(),
+            input2.clone(),
+        );
+        let test_data = vec![
+            (4, 2, 2, Status::Ok),
+            (5, 2, 2, Status::Ok),
+            (-9, 3, -3, Status::Ok),
+            (7, 0, 0, Status::Invalid),
+            (0, 5, 0, Status::Ok),
+            (0, 0, 0, Status::Invalid),
+            (12, -4, -3, Status::Ok),
+            (i64::MIN, -1, 0, Status::Invalid),
+        ];
+        for (value1, value2, target, status) in test_data {
+            input1.borrow_mut().add(value1.to_point(0, "test"));
+            input2.borrow_mut().add(value2.to_point(0, "test"));
+            let state = fnDiv.out();
+            debug!("value1: {:?}, value2: {:?}   |   state: {:?}", value1, value2, state);
+            assert_eq!(state.as_int().value, target);
+            assert_eq!(state.status(), status);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_double() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_double");
+        let input1 = init_each(0.0f64.to_point(0, "double"), FnConfPointType::Double);
+        let input2 = init_each(1.0f64.to_point(0, "double"), FnConfPointType::Double);
+        let mut fnDiv = FnDiv::new(
+            "test",
+            input1.clone(),
+            input2.clone(),
+        );
+        let test_data = vec![
+            (1.0, 2.0, 0.5, Status::Ok),
+            (-9.0, 3.0, -3.0, Status::Ok),
+            (7.0, 0.0, 0.0, Status::Invalid),
+            (0.0, 0.0, 0.0, Status::Invalid),
+            (0.0, 5.0, 0.0, Status::Ok),
+        ];
+        for (value1, value2, target, status) in test_data {
+            input1.borrow_mut().add(value1.to_point(0, "test"));
+            input2.borrow_mut().add(value2.to_point(0, "test"));
+            let state = fnDiv.out();
+            debug!("value1: {:?}, value2: {:?}   |   state: {:?}", value1, value2, state);
+            assert_eq!(state.as_double().value, target);
+            assert_eq!(state.status(), status);
+        }
+    }
+    ///
+    /// The inputs having different Cot returns 0 with Status::Invalid for the all types, without panic
+    #[test]
+    fn test_cot() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_cot");
+        let test_data = vec![
+            (6.to_point(0, "int"), 2.to_point(0, "int"), Cot::Inf, 3.0, Status::Ok),
+            (6.to_point(0, "int"), 2.to_point(0, "int"), Cot::Act, 0.0, Status::Invalid),
+            (6.0f32.to_point(0, "real"), 2.0f32.to_point(0, "real"), Cot::Inf, 3.0, Status::Ok),
+            (6.0f32.to_point(0, "real"), 2.0f32.to_point(0, "real"), Cot::Act, 0.0, Status::Invalid),
+            (6.0f64.to_point(0, "double"), 2.0f64.to_point(0, "double"), Cot::Inf, 3.0, Status::Ok),
+            (6.0f64.to_point(0, "double"), 2.0f64.to_point(0, "double"), Cot::Act, 0.0, Status::Invalid),
+        ];
+        for (value1, value2, cot2, target, status) in test_data {
+            let value2 = match value2 {
+                PointType::Int(point) => PointType::Int(Point { cot: cot2, ..point }),
+                PointType::Real(point) => PointType::Real(Point { cot: cot2, ..point }),
+                PointType::Double(point) => PointType::Double(Point { cot: cot2, ..point }),
+                _ => unreachable!(),
+            };
+            let input1 = init_each(value1.clone(), FnConfPointType::Any);
+            let input2 = init_each(value2.clone(), FnConfPointType::Any);
+            let mut fnDiv = FnDiv::new("test", input1, input2);
+            let state = fnDiv.out();
+            debug!("value1: {:?}, value2: {:?}   |   state: {:?}", value1, value2, state);
+            assert_eq!(to_double("test", &state), target);
+            assert_eq!(state.status(), status);
+        }
+    }
+}
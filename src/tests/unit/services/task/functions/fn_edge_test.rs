@@ -0,0 +1,88 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_rising_edge::FnRisingEdge, fn_falling_edge::FnFallingEdge, fn_input::FnInput}
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_bool() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_bool");
+        let input = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnRisingEdge = FnRisingEdge::new("test", input.clone());
+        let mut fnFallingEdge = FnFallingEdge::new("test", input.clone());
+        let test_data = vec![
+        //  value  rising falling
+            (false, false, false),
+            (true, true, false),
+            (true, false, false),
+            (true, false, false),
+            (false, false, true),
+            (false, false, false),
+            (true, true, false),
+            (false, false, true),
+            (true, true, false),
+            (false, false, true),
+        ];
+        for (value, rising, falling) in test_data {
+            input.borrow_mut().add(value.to_point(0, "test"));
+            let state = fnRisingEdge.out();
+            debug!("value: {:?}   |   rising: {:?}", value, state);
+            assert_eq!(state.as_bool().value.0, rising);
+            let state = fnFallingEdge.out();
+            debug!("value: {:?}   |   falling: {:?}", value, state);
+            assert_eq!(state.as_bool().value.0, falling);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_int() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_int");
+        let input = init_each(0.to_point(0, "int"), FnConfPointType::Int);
+        let mut fnRisingEdge = FnRisingEdge::new("test", input.clone());
+        let test_data = vec![
+            (0, false),
+            (5, true),
+            (7, false),
+            (0, false),
+            (-1, true),
+            (-1, false),
+        ];
+        for (value, rising) in test_data {
+            input.borrow_mut().add(value.to_point(0, "test"));
+            let state = fnRisingEdge.out();
+            debug!("value: {:?}   |   rising: {:?}", value, state);
+            assert_eq!(state.as_bool().value.0, rising);
+        }
+    }
+}
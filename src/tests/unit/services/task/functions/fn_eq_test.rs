@@ -0,0 +1,79 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_eq::FnEq, fn_input::FnInput}
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_exact() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_exact");
+        let test_data = vec![
+            (2.to_point(0, "int"), 2.to_point(0, "int"), true),
+            (2.to_point(0, "int"), 3.to_point(0, "int"), false),
+            (2.to_point(0, "int"), 2.0f64.to_point(0, "double"), true),
+            (0.1f64.to_point(0, "double"), 0.1001f64.to_point(0, "double"), false),
+            (true.to_point(0, "bool"), 1.to_point(0, "int"), true),
+            ("abc".to_point(0, "string"), "abc".to_point(0, "string"), true),
+            ("abc".to_point(0, "string"), "abd".to_point(0, "string"), false),
+        ];
+        for (value1, value2, target) in test_data {
+            let input1 = init_each(value1.clone(), FnConfPointType::Any);
+            let input2 = init_each(value2.clone(), FnConfPointType::Any);
+            let mut fnEq = FnEq::new("test", input1, input2, None);
+            let state = fnEq.out();
+            debug!("value1: {:?}, value2: {:?}   |   state: {:?}", value1, value2, state);
+            assert_eq!(state.as_bool().value.0, target);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_decimals() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_decimals");
+        let test_data = vec![
+            (0.1f64.to_point(0, "double"), 0.1001f64.to_point(0, "double"), 3, true),
+            (0.1f64.to_point(0, "double"), 0.1001f64.to_point(0, "double"), 4, false),
+            (1.25f32.to_point(0, "real"), 1.2549f32.to_point(0, "real"), 2, true),
+            (1.25f32.to_point(0, "real"), 1.26f32.to_point(0, "real"), 2, false),
+            (3.to_point(0, "int"), 3.0001f64.to_point(0, "double"), 3, true),
+        ];
+        for (value1, value2, decimals, target) in test_data {
+            let input1 = init_each(value1.clone(), FnConfPointType::Any);
+            let input2 = init_each(value2.clone(), FnConfPointType::Any);
+            let mut fnEq = FnEq::new("test", input1, input2, Some(decimals));
+            let state = fnEq.out();
+            debug!("value1: {:?}, value2: {:?}   |   state: {:?}", value1, value2, state);
+            assert_eq!(state.as_bool().value.0, target);
+        }
+    }
+}
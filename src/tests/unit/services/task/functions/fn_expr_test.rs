@@ -0,0 +1,151 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use indexmap::IndexMap;
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef},
+        services::task::nested_function::{fn_input::FnInput, fn_expr::Expr},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_parse() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_parse");
+        let test_data = [
+            ("a + b * c", "(a + (b * c))"),
+            ("(a + b) * c", "((a + b) * c)"),
+            ("a - b - c", "((a - b) - c)"),
+            ("-a ^ 2", "-(a ^ 2)"),
+            ("2 ^ 3 ^ 2", "(2 ^ (3 ^ 2))"),
+            ("2 ^ -1", "(2 ^ -1)"),
+            ("(a * 2.5 + b) / c > limit && !blocked", "(((((a * 2.5) + b) / c) > limit) && !blocked)"),
+            ("a || b && c", "(a || (b && c))"),
+            ("a % 3 == 0 || x != 1e-3", "(((a % 3) == 0) || (x != 0.001))"),
+            ("flag == true", "(flag == true)"),
+        ];
+        for (formula, target) in test_data {
+            let expr = Expr::parse(formula).unwrap();
+            debug!("formula: {:?}   |   expr: {}", formula, expr);
+            assert_eq!(expr.to_string(), target);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_syntax_errors() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_syntax_errors");
+        let test_data = [
+            ("a +", "unexpected end of the formula at 3"),
+            ("(a + b", "expected ')' at 6"),
+            ("a + b)", "unexpected Close at 5"),
+            ("a < b < c", "comparison can't be chained, unexpected '<' at 6"),
+            ("a $ b", "unexpected '$' at 2"),
+            ("a + 12x", "invalid number '12x' at 4"),
+            ("a b", "unexpected Ident(\"b\") at 2"),
+        ];
+        for (formula, target) in test_data {
+            let result = Expr::parse(formula);
+            debug!("formula: {:?}   |   result: {:?}", formula, result);
+            assert_eq!(result, Err(target.to_owned()));
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_type_errors() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_type_errors");
+        let types = IndexMap::from([
+            ("a".to_owned(), FnConfPointType::Real),
+            ("s".to_owned(), FnConfPointType::String),
+            ("t".to_owned(), FnConfPointType::String),
+            ("u".to_owned(), FnConfPointType::Unknown),
+        ]);
+        let test_data = [
+            ("a * 2 + u", true),
+            ("s == t", true),
+            ("s == u", true),
+            ("s + 1", false),
+            ("!s", false),
+            ("s > a", false),
+            ("a && s", false),
+            ("a + b", false),
+        ];
+        for (formula, target) in test_data {
+            let result = Expr::parse(formula).unwrap().check(&types);
+            debug!("formula: {:?}   |   result: {:?}", formula, result);
+            assert_eq!(result.is_ok(), target, "formula: {:?}   |   result: {:?}", formula, result);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_build() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_build");
+        let a = init_each(0.0f32.to_point(0, "a"), FnConfPointType::Real);
+        let b = init_each(0.to_point(0, "b"), FnConfPointType::Int);
+        let c = init_each(0.0f64.to_point(0, "c"), FnConfPointType::Double);
+        let limit = init_each(0.0f64.to_point(0, "limit"), FnConfPointType::Double);
+        let blocked = init_each(false.to_point(0, "blocked"), FnConfPointType::Bool);
+        let inputs = IndexMap::from([
+            ("a".to_owned(), a.clone()),
+            ("b".to_owned(), b.clone()),
+            ("c".to_owned(), c.clone()),
+            ("limit".to_owned(), limit.clone()),
+            ("blocked".to_owned(), blocked.clone()),
+        ]);
+        let bool_expr = Expr::parse("(a * 2.5 + b) / c > limit && !blocked").unwrap().build("test", 0, &inputs);
+        let double_expr = Expr::parse("-(a * 2.5 + b) / c + 2 ^ 2").unwrap().build("test", 0, &inputs);
+        let test_data = [
+            // a, b, c, limit, blocked, bool target, double target
+            (2.0f32, 1, 2.0, 2.0, false, true, 1.0),
+            (2.0f32, 1, 2.0, 3.0, false, false, 1.0),
+            (2.0f32, 1, 2.0, 2.0, true, false, 1.0),
+            (4.0f32, -2, 4.0, 1.9, false, true, 2.0),
+        ];
+        for (a_value, b_value, c_value, limit_value, blocked_value, bool_target, double_target) in test_data {
+            a.borrow_mut().add(a_value.to_point(0, "a"));
+            b.borrow_mut().add(b_value.to_point(0, "b"));
+            c.borrow_mut().add(c_value.to_point(0, "c"));
+            limit.borrow_mut().add(limit_value.to_point(0, "limit"));
+            blocked.borrow_mut().add(blocked_value.to_point(0, "blocked"));
+            let state = bool_expr.borrow_mut().out();
+            debug!("bool   |   state: {:?}", state);
+            assert_eq!(state.as_bool().value.0, bool_target);
+            let state = double_expr.borrow_mut().out();
+            debug!("double   |   state: {:?}", state);
+            assert!((state.as_double().value - double_target).abs() < 1e-6, "result: {:?}, target: {}", state, double_target);
+        }
+    }
+}
@@ -0,0 +1,195 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use indexmap::IndexMap;
+    use log::{debug, info};
+    use chrono::{DateTime, Utc};
+    use std::{cell::RefCell, env, fs, rc::Rc, sync::Once, time::Duration};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{cot::cot::Cot, point::{point::Point, point_type::{PointType, ToPoint}}, status::status::Status, types::{bool::Bool, fn_in_out_ref::FnInOutRef}},
+        services::task::nested_function::{fn_::FnOut, fn_input::FnInput, fn_fault_recorder::FnFaultRecorder},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    /// Returns the timestamp in milliseconds from the [start]
+    fn at(start: DateTime<Utc>, millis: i64) -> DateTime<Utc> {
+        start + chrono::Duration::milliseconds(millis)
+    }
+    ///
+    ///
+    #[test]
+    fn test_fault_recorder() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_fault_recorder");
+        let dir = env::current_dir().unwrap().join("assets/fault-recorder/test/FnFaultRecorderTest");
+        let _ = fs::remove_dir_all(&dir);
+        let start = Utc::now();
+        let trigger = init_each(false.to_point(0, "trigger"), FnConfPointType::Bool);
+        let load = init_each(0.0.to_point(0, "load"), FnConfPointType::Double);
+        let speed = init_each(0.to_point(0, "speed"), FnConfPointType::Int);
+        let mut fnFaultRecorder = FnFaultRecorder::new(
+            "test",
+            trigger.clone(),
+            IndexMap::from([
+                ("load".to_owned(), load.clone()),
+                ("speed".to_owned(), speed.clone()),
+            ]),
+            Duration::from_millis(1000),
+            Duration::from_millis(500),
+            Some(dir.clone()),
+        );
+        let test_data = [
+            // millis, trigger, speed millis, speed, target snapshot (trigger millis, load samples, first load millis, speed samples)
+            (0, false, 0, 0, None),
+            (250, false, 0, 0, None),
+            (500, false, 0, 0, None),
+            (750, false, 0, 0, None),
+            (1000, false, 0, 0, None),
+            (1250, false, 0, 0, None),
+            (1500, false, 0, 0, None),
+            (1750, false, 0, 0, None),
+            (2000, true, 1800, 10, None),
+            (2250, false, 1800, 10, None),
+            (2500, true, 1800, 10, Some((2000, 7, 1000, 2))),     // retrigger during the capture - ignored
+            (2750, false, 1800, 10, Some((2000, 7, 1000, 2))),
+            (3000, true, 1800, 10, Some((2000, 7, 1000, 2))),
+            (3250, true, 1800, 10, Some((2000, 7, 1000, 2))),
+            (3500, true, 1800, 10, Some((3000, 7, 2000, 1))),
+            (3750, false, 1800, 10, Some((3000, 7, 2000, 1))),
+        ];
+        for (millis, trigger_value, speed_millis, speed_value, target) in test_data {
+            trigger.borrow_mut().add(PointType::Bool(Point::new(0, "trigger", Bool(trigger_value), Status::Ok, Cot::Inf, at(start, millis))));
+            load.borrow_mut().add(PointType::Double(Point::new(0, "load", millis as f64 / 100.0, Status::Ok, Cot::Inf, at(start, millis))));
+            speed.borrow_mut().add(PointType::Int(Point::new(0, "speed", speed_value, Status::Ok, Cot::Inf, at(start, speed_millis))));
+            let state = fnFaultRecorder.out();
+            debug!("millis: {}   |   state: {:?}", millis, state);
+            let snapshot = state.as_string().value;
+            match target {
+                Some((trigger_millis, load_samples, load_first, speed_samples)) => {
+                    let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+                    assert_eq!(snapshot["trigger"], "trigger");
+                    assert_eq!(snapshot["timestamp"], at(start, trigger_millis).to_rfc3339());
+                    assert_eq!(snapshot["preTrigger"], 1.0);
+                    assert_eq!(snapshot["postTrigger"], 0.5);
+                    let load = snapshot["inputs"]["load"].as_array().unwrap();
+                    assert_eq!(load.len(), load_samples, "millis: {}   |   load: {:?}", millis, load);
+                    let first: PointType = serde_json::from_value(load[0].clone()).unwrap();
+                    assert_eq!(first.timestamp(), at(start, load_first));
+                    let speed = snapshot["inputs"]["speed"].as_array().unwrap();
+                    assert_eq!(speed.len(), speed_samples, "millis: {}   |   speed: {:?}", millis, speed);
+                }
+                None => assert_eq!(snapshot, ""),
+            }
+        }
+        let stored = fs::read_dir(&dir).unwrap().count();
+        assert_eq!(stored, 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    ///
+    /// The capture completed by the clock, if the inputs stopped changing after the trigger
+    #[test]
+    fn test_quiet_inputs() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_quiet_inputs");
+        let start = Utc::now();
+        let trigger = init_each(false.to_point(0, "trigger"), FnConfPointType::Bool);
+        let load = init_each(0.0.to_point(0, "load"), FnConfPointType::Double);
+        let mut fnFaultRecorder = FnFaultRecorder::new(
+            "test",
+            trigger.clone(),
+            IndexMap::from([("load".to_owned(), load.clone())]),
+            Duration::from_millis(1000),
+            Duration::from_millis(200),
+            None,
+        );
+        for (millis, trigger_value) in [(0, false), (100, false), (200, true)] {
+            trigger.borrow_mut().add(PointType::Bool(Point::new(0, "trigger", Bool(trigger_value), Status::Ok, Cot::Inf, at(start, millis))));
+            load.borrow_mut().add(PointType::Double(Point::new(0, "load", millis as f64, Status::Ok, Cot::Inf, at(start, millis))));
+            assert_eq!(fnFaultRecorder.out().as_string().value, "");
+        }
+        // the inputs are quiet, the function evaluated cyclically
+        std::thread::sleep(Duration::from_millis(100));
+        assert_eq!(fnFaultRecorder.out().as_string().value, "");
+        std::thread::sleep(Duration::from_millis(150));
+        let snapshot = fnFaultRecorder.out().as_string().value;
+        debug!("snapshot: {}", snapshot);
+        let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+        assert_eq!(snapshot["timestamp"], at(start, 200).to_rfc3339());
+        assert_eq!(snapshot["inputs"]["load"].as_array().unwrap().len(), 3);
+    }
+    ///
+    /// The placeholders of the inputs are not recorded,
+    /// the snapshots of the recorders on the same trigger are stored into the separate files
+    #[test]
+    fn test_placeholder_files() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_placeholder_files");
+        let dir = env::current_dir().unwrap().join("assets/fault-recorder/test/FnFaultRecorderFilesTest");
+        let _ = fs::remove_dir_all(&dir);
+        let start = Utc::now();
+        let trigger = init_each(false.to_point(0, "trigger"), FnConfPointType::Bool);
+        let load = init_each(0.0.to_point(0, "load"), FnConfPointType::Double);
+        let mut recorders: Vec<FnFaultRecorder> = (0..2).map(|_| {
+            FnFaultRecorder::new(
+                "test",
+                trigger.clone(),
+                IndexMap::from([("load".to_owned(), load.clone())]),
+                Duration::from_millis(1000),
+                Duration::from_millis(200),
+                Some(dir.clone()),
+            )
+        }).collect();
+        // the inputs are not received yet
+        for recorder in &mut recorders {
+            assert_eq!(recorder.out().as_string().value, "");
+        }
+        trigger.borrow_mut().add(PointType::Bool(Point::new(0, "trigger", Bool(false), Status::Ok, Cot::Inf, at(start, 0))));
+        for recorder in &mut recorders {
+            assert_eq!(recorder.out().as_string().value, "");
+        }
+        for (millis, trigger_value) in [(100, false), (200, true), (300, true), (500, false)] {
+            trigger.borrow_mut().add(PointType::Bool(Point::new(0, "trigger", Bool(trigger_value), Status::Ok, Cot::Inf, at(start, millis))));
+            load.borrow_mut().add(PointType::Double(Point::new(0, "load", millis as f64, Status::Ok, Cot::Inf, at(start, millis))));
+            for recorder in &mut recorders {
+                recorder.out();
+            }
+        }
+        for recorder in &mut recorders {
+            let snapshot = recorder.out().as_string().value;
+            debug!("snapshot: {}", snapshot);
+            let snapshot: serde_json::Value = serde_json::from_str(&snapshot).unwrap();
+            assert_eq!(snapshot["timestamp"], at(start, 200).to_rfc3339());
+            let load = snapshot["inputs"]["load"].as_array().unwrap();
+            assert_eq!(load.len(), 3, "load: {:?}", load);
+            let first: PointType = serde_json::from_value(load[0].clone()).unwrap();
+            assert_eq!(first.timestamp(), at(start, 100));
+        }
+        let stored = fs::read_dir(&dir).unwrap().count();
+        assert_eq!(stored, 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,161 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use indexmap::IndexMap;
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, status::status::Status, types::fn_in_out_ref::FnInOutRef},
+        services::task::nested_function::{fn_::FnOut, fn_input::FnInput, fn_format::FnFormat, fn_concat::FnConcat},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_format() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_format");
+        let winch = init_each(1.to_point(0, "winch"), FnConfPointType::Int);
+        let load = init_each(0.0f64.to_point(0, "load"), FnConfPointType::Double);
+        let mut fnFormat = FnFormat::new(
+            "test",
+            "Winch {winch} overload at {load.value} t",
+            IndexMap::from([
+                ("winch".to_owned(), winch.clone()),
+                ("load".to_owned(), load.clone()),
+            ]),
+        );
+        let test_data = [
+            (1, 12.5, "Winch 1 overload at 12.5 t"),
+            (2, 7.25, "Winch 2 overload at 7.25 t"),
+            (3, 100.0, "Winch 3 overload at 100 t"),
+        ];
+        for (winch_value, load_value, target) in test_data {
+            winch.borrow_mut().add(winch_value.to_point(0, "winch"));
+            load.borrow_mut().add(load_value.to_point(0, "load"));
+            let state = fnFormat.out();
+            debug!("winch: {:?}   |   load: {:?}   |   state: {:?}", winch_value, load_value, state);
+            assert_eq!(state.as_string().value, target);
+            assert_eq!(state.status(), Status::Ok);
+        }
+    }
+    ///
+    ///
+    #[test]
+    #[should_panic]
+    fn test_format_unknown_input() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_format_unknown_input");
+        let winch = init_each(1.to_point(0, "winch"), FnConfPointType::Int);
+        FnFormat::new("test", "Winch {winch} overload at {load} t", IndexMap::from([("winch".to_owned(), winch)]));
+    }
+    ///
+    /// The braces of the JSON template aren't the markers
+    #[test]
+    fn test_format_json() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_format_json");
+        let winch = init_each(1.to_point(0, "winch"), FnConfPointType::Int);
+        let load = init_each(0.0f64.to_point(0, "load"), FnConfPointType::Double);
+        let mut fnFormat = FnFormat::new(
+            "test",
+            r#"{"winch": {winch}, "load": {load.value}, "overload": {"status": {load.status}}}"#,
+            IndexMap::from([
+                ("winch".to_owned(), winch.clone()),
+                ("load".to_owned(), load.clone()),
+            ]),
+        );
+        let test_data = [
+            (1, 12.5, r#"{"winch": 1, "load": 12.5, "overload": {"status": 0}}"#),
+            (2, 7.25, r#"{"winch": 2, "load": 7.25, "overload": {"status": 0}}"#),
+        ];
+        for (winch_value, load_value, target) in test_data {
+            winch.borrow_mut().add(winch_value.to_point(0, "winch"));
+            load.borrow_mut().add(load_value.to_point(0, "load"));
+            let state = fnFormat.out();
+            debug!("winch: {:?}   |   load: {:?}   |   state: {:?}", winch_value, load_value, state);
+            assert_eq!(state.as_string().value, target);
+            let json: serde_json::Value = serde_json::from_str(&state.as_string().value).unwrap();
+            assert_eq!(json["load"], serde_json::json!(load_value));
+        }
+    }
+    ///
+    /// The String value typed as json escaped, so the JSON stays valid
+    #[test]
+    fn test_format_json_escaped() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_format_json_escaped");
+        let message = init_each("".to_point(0, "message"), FnConfPointType::String);
+        let load = init_each(0.0f64.to_point(0, "load"), FnConfPointType::Double);
+        let mut fnFormat = FnFormat::new(
+            "test",
+            r#"{"message": {message:json}, "load": {load:json}, "name": {load.name:json}}"#,
+            IndexMap::from([
+                ("message".to_owned(), message.clone()),
+                ("load".to_owned(), load.clone()),
+            ]),
+        );
+        let test_data = [
+            (r#"Winch "2" overload"#, 12.5, r#"{"message": "Winch \"2\" overload", "load": 12.5, "name": "load"}"#),
+            (r#"C:\path"#, 7.25, r#"{"message": "C:\\path", "load": 7.25, "name": "load"}"#),
+            ("plain", 0.5, r#"{"message": "plain", "load": 0.5, "name": "load"}"#),
+        ];
+        for (message_value, load_value, target) in test_data {
+            message.borrow_mut().add(message_value.to_point(0, "message"));
+            load.borrow_mut().add(load_value.to_point(0, "load"));
+            let state = fnFormat.out();
+            debug!("message: {:?}   |   load: {:?}   |   state: {:?}", message_value, load_value, state);
+            assert_eq!(state.as_string().value, target);
+            let json: serde_json::Value = serde_json::from_str(&state.as_string().value).unwrap();
+            assert_eq!(json["message"], serde_json::json!(message_value));
+            assert_eq!(json["load"], serde_json::json!(load_value));
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_concat() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_concat");
+        let test_data = [
+            (vec![true.to_point(0, "input1"), 12.to_point(0, "input2"), 0.5f32.to_point(0, "input3"), "abc".to_point(0, "input4")], "", "true120.5abc"),
+            (vec![true.to_point(0, "input1"), 12.to_point(0, "input2"), 0.5f32.to_point(0, "input3"), "abc".to_point(0, "input4")], ", ", "true, 12, 0.5, abc"),
+            (vec![1.25f64.to_point(0, "input1")], "-", "1.25"),
+        ];
+        for (values, separator, target) in test_data {
+            let inputs: Vec<FnInOutRef> = values.iter().map(|value: &PointType| {
+                init_each(value.clone(), FnConfPointType::Any)
+            }).collect();
+            let mut fnConcat = FnConcat::new("test", inputs, separator);
+            let state = fnConcat.out();
+            debug!("values: {:?}   |   state: {:?}", values, state);
+            assert_eq!(state.as_string().value, target);
+        }
+    }
+}
@@ -0,0 +1,64 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_hysteresis::FnHysteresis, fn_input::FnInput}
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_limits() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_limits");
+        let input = init_each(0.0f64.to_point(0, "input"), FnConfPointType::Double);
+        let high = init_each(80.0f64.to_point(0, "high"), FnConfPointType::Double);
+        let low = init_each(70.to_point(0, "low"), FnConfPointType::Int);
+        let mut fnHysteresis = FnHysteresis::new("test", input.clone(), high.clone(), low.clone());
+        let test_data = vec![
+            (60.0, 80.0, false),
+            (75.0, 80.0, false),
+            (80.0, 80.0, false),
+            (80.1, 80.0, true),
+            (79.0, 80.0, true),
+            (70.0, 80.0, true),
+            (72.0, 80.0, true),
+            (69.9, 80.0, false),
+            (75.0, 80.0, false),
+            (75.0, 74.0, true),
+            (71.0, 74.0, true),
+            (65.0, 74.0, false),
+        ];
+        for (value, high_value, target) in test_data {
+            input.borrow_mut().add(value.to_point(0, "input"));
+            high.borrow_mut().add(high_value.to_point(0, "high"));
+            let state = fnHysteresis.out();
+            debug!("value: {:?}   |   high: {:?}   |   state: {:?}", value, high_value, state);
+            assert_eq!(state.as_bool().value.0, target);
+        }
+    }
+}
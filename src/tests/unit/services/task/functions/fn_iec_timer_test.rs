@@ -0,0 +1,207 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::{Arc, Mutex, Once}, rc::Rc, cell::RefCell, thread, time::Duration};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::{fn_::fn_conf_keywd::FnConfPointType, point_config::name::Name, task_config::TaskConfig},
+        core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef},
+        services::{services::Services, task::task_nodes::TaskNodes},
+        services::task::nested_function::{fn_::FnOut, fn_iec_timer::{FnIecElapsed, IecTimerOut}, fn_ton::FnTon, fn_tof::FnTof, fn_tp::FnTp, fn_input::FnInput},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    /// Runs timer through the test data: (input, sleep before evaluation ms, target Q)
+    fn run(fnTimer: &mut dyn FnOut, input: &FnInOutRef, test_data: Vec<(bool, u64, bool)>) {
+        for (step, (value, sleep, target)) in test_data.into_iter().enumerate() {
+            thread::sleep(Duration::from_millis(sleep));
+            input.borrow_mut().add(value.to_point(0, "test"));
+            let state = fnTimer.out();
+            debug!("step: {}, value: {:?}   |   state: {:?}", step, value, state);
+            assert_eq!(state.as_bool().value.0, target, "step: {}, value: {:?}", step, value);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_ton() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_ton");
+        let input = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnTon = FnTon::new("test", input.clone(), Duration::from_millis(100), IecTimerOut::Q);
+        run(&mut fnTon, &input, vec![
+            (false, 0, false),
+            (true, 0, false),
+            (true, 50, false),
+            (false, 0, false),     // short spike - no output
+            (true, 0, false),
+            (true, 60, false),
+            (true, 60, true),
+            (true, 10, true),
+            (false, 0, false),
+        ]);
+    }
+    ///
+    ///
+    #[test]
+    fn test_ton_elapsed() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_ton_elapsed");
+        let input = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnTon = FnTon::new("test", input.clone(), Duration::from_millis(100), IecTimerOut::Elapsed);
+        input.borrow_mut().add(true.to_point(0, "test"));
+        assert!(fnTon.out().as_double().value < 0.05);
+        thread::sleep(Duration::from_millis(60));
+        let elapsed = fnTon.out().as_double().value;
+        assert!((0.06..0.1).contains(&elapsed), "elapsed: {}", elapsed);
+        thread::sleep(Duration::from_millis(60));
+        assert_eq!(fnTon.out().as_double().value, 0.1);
+        input.borrow_mut().add(false.to_point(0, "test"));
+        assert_eq!(fnTon.out().as_double().value, 0.0);
+    }
+    ///
+    ///
+    #[test]
+    fn test_tof() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_tof");
+        let input = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnTof = FnTof::new("test", input.clone(), Duration::from_millis(100), IecTimerOut::Q);
+        run(&mut fnTof, &input, vec![
+            (false, 0, false),
+            (true, 0, true),
+            (false, 0, true),
+            (false, 50, true),
+            (true, 0, true),       // restarted
+            (false, 0, true),
+            (false, 60, true),
+            (false, 60, false),
+            (false, 10, false),
+        ]);
+    }
+    ///
+    ///
+    #[test]
+    fn test_tp() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_tp");
+        let input = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnTp = FnTp::new("test", input.clone(), Duration::from_millis(100), IecTimerOut::Q);
+        run(&mut fnTp, &input, vec![
+            (false, 0, false),
+            (true, 0, true),
+            (false, 0, true),
+            (true, 20, true),      // can't be restarted
+            (false, 20, true),
+            (false, 70, false),
+            (false, 0, false),
+            (true, 0, true),
+            (true, 110, false),
+            (true, 10, false),
+            (false, 0, false),
+        ]);
+    }
+    ///
+    /// Q & elapsed of the same timer instance
+    #[test]
+    fn test_q_elapsed() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_q_elapsed");
+        let input = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnTof = FnTof::new("test", input.clone(), Duration::from_millis(100), IecTimerOut::Q);
+        let mut fnElapsed = FnIecElapsed::new("test", fnTof.inputs(), fnTof.elapsed());
+        assert_eq!(fnElapsed.out().as_double().value, 0.0);
+        let test_data = [
+            // input, sleep before evaluation ms, target Q, target elapsed range
+            (true, 0, true, 0.0..0.001),
+            (false, 0, true, 0.0..0.05),
+            (false, 60, true, 0.06..0.1),
+            (false, 60, false, 0.1..0.101),
+            (true, 0, true, 0.0..0.001),
+        ];
+        for (step, (value, sleep, target_q, target_elapsed)) in test_data.into_iter().enumerate() {
+            thread::sleep(Duration::from_millis(sleep));
+            input.borrow_mut().add(value.to_point(0, "test"));
+            let q = fnTof.out();
+            let elapsed = fnElapsed.out();
+            debug!("step: {}, value: {:?}   |   q: {:?}   |   elapsed: {:?}", step, value, q, elapsed);
+            assert_eq!(q.as_bool().value.0, target_q, "step: {}, value: {:?}", step, value);
+            assert!(target_elapsed.contains(&elapsed.as_double().value), "step: {}, elapsed: {:?}", step, elapsed.as_double().value);
+            assert_eq!(elapsed.timestamp(), q.timestamp(), "step: {}", step);
+        }
+        fnTof.reset();
+        assert_eq!(fnElapsed.out().as_double().value, 0.0);
+    }
+    ///
+    /// The elapsed declared in the config by the 'elapsed' parameter, used as the variable
+    #[test]
+    fn test_elapsed_conf() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_elapsed_conf");
+        let self_id = "test_elapsed_conf";
+        let parent = Name::new("", self_id);
+        let conf = serde_yaml::from_str(r#"
+            service Task ElapsedTask:
+                cycle: 10 ms
+                in queue recv-queue:
+                    max-length: 10000
+                let Run:
+                    input fn ton:
+                        input: point bool '/path/Run'
+                        preset: 100 ms
+                        elapsed: RunElapsed
+                fn ToBool:
+                    input: Run
+                fn ToDouble:
+                    input: RunElapsed
+        "#).unwrap();
+        let conf = TaskConfig::from_yaml(&parent, &conf);
+        let services = Arc::new(Mutex::new(Services::new(self_id)));
+        let mut task_nodes = TaskNodes::new(self_id);
+        task_nodes.buildNodes(&parent, conf, services);
+        let test_data = [
+            // input, sleep before evaluation ms, target Q, target elapsed range
+            (true, 0, false, 0.0..0.05),
+            (true, 120, true, 0.1..0.101),
+            (false, 0, false, 0.0..0.001),
+        ];
+        for (step, (value, sleep, target_q, target_elapsed)) in test_data.into_iter().enumerate() {
+            thread::sleep(Duration::from_millis(sleep));
+            task_nodes.eval(value.to_point(0, "/path/Run"));
+            let outs = task_nodes.getEvalNode("/path/Run").unwrap().getOuts().clone();
+            assert_eq!(outs.len(), 2);
+            let q = outs[0].borrow_mut().out();
+            let elapsed = outs[1].borrow_mut().out();
+            debug!("step: {}, value: {:?}   |   q: {:?}   |   elapsed: {:?}", step, value, q, elapsed);
+            assert_eq!(q.as_bool().value.0, target_q, "step: {}, value: {:?}", step, value);
+            assert!(target_elapsed.contains(&elapsed.as_double().value), "step: {}, elapsed: {:?}", step, elapsed.as_double().value);
+        }
+    }
+}
@@ -0,0 +1,60 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_if::FnIf, fn_input::FnInput}
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_select() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_select");
+        let condition = init_each(false.to_point(0, "condition"), FnConfPointType::Any);
+        let then = init_each(0.to_point(0, "then"), FnConfPointType::Int);
+        let else_ = init_each(0.to_point(0, "else"), FnConfPointType::Int);
+        let mut fnIf = FnIf::new("test", condition.clone(), then.clone(), else_.clone());
+        let test_data = vec![
+            (true.to_point(0, "condition"), 11, 22, 11),
+            (false.to_point(0, "condition"), 11, 22, 22),
+            (true.to_point(0, "condition"), 33, 22, 33),
+            (1.to_point(0, "condition"), 44, 55, 44),
+            (0.to_point(0, "condition"), 44, 55, 55),
+            (0.1f32.to_point(0, "condition"), 66, 77, 66),
+            (0.0f64.to_point(0, "condition"), 66, 77, 77),
+        ];
+        for (cond, then_value, else_value, target) in test_data {
+            condition.borrow_mut().add(cond.clone());
+            then.borrow_mut().add(then_value.to_point(0, "then"));
+            else_.borrow_mut().add(else_value.to_point(0, "else"));
+            let state = fnIf.out();
+            debug!("condition: {:?}   |   state: {:?}", cond, state);
+            assert_eq!(state.as_int().value, target);
+        }
+    }
+}
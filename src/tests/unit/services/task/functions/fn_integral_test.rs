@@ -0,0 +1,128 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use chrono::{DateTime, Utc};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{cot::cot::Cot, point::{point::Point, point_type::{PointType, ToPoint}}, status::status::Status, types::fn_in_out_ref::FnInOutRef},
+        services::task::nested_function::{fn_::FnOut, fn_input::FnInput, fn_integral::FnIntegral, fn_derivative::FnDerivative},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    /// Returns Double point with the given timestamp in milliseconds from the [start]
+    fn point(start: DateTime<Utc>, millis: i64, value: f64) -> PointType {
+        PointType::Double(Point::new(0, "input", value, Status::Ok, Cot::Inf, start + chrono::Duration::milliseconds(millis)))
+    }
+    ///
+    ///
+    #[test]
+    fn test_integral() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_integral");
+        let input = init_each(0.0.to_point(0, "input"), FnConfPointType::Double);
+        let reset = init_each(true.to_point(0, "reset"), FnConfPointType::Bool);
+        let mut fnIntegral = FnIntegral::new("test", 10.0, input.clone(), Some(reset.clone()));
+        // the placeholders of the inputs evaluated before the first sample, for example in the cyclic Task
+        assert_eq!(fnIntegral.out().as_double().value, 10.0);
+        let start = Utc::now();
+        let test_data = [
+            // millis, value, reset, integral
+            (0, 2.0, false, 10.0),
+            (1000, 4.0, false, 13.0),
+            (1000, 100.0, false, 13.0),       // the same timestamp - ignored
+            (1500, 4.0, false, 15.0),
+            (1510, 4.0, false, 15.04),        // burst of points
+            (1520, 4.0, false, 15.08),
+            (3520, 0.0, false, 19.08),
+            (4000, 1.0, true, 10.0),
+            (6000, 1.0, false, 12.0),
+            (5000, 1.0, false, 12.0),         // older timestamp - ignored
+        ];
+        for (millis, value, reset_value, target) in test_data {
+            input.borrow_mut().add(point(start, millis, value));
+            reset.borrow_mut().add(reset_value.to_point(0, "reset"));
+            let state = fnIntegral.out().as_double().value;
+            debug!("millis: {:?}   |   value: {:?}   |   reset: {:?}   |   state: {:?}", millis, value, reset_value, state);
+            assert!((state - target).abs() < 1e-9, "millis {}   |   result: {}, target: {}", millis, state, target);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_derivative() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_derivative");
+        let input = init_each(0.0.to_point(0, "input"), FnConfPointType::Double);
+        let mut fnDerivative = FnDerivative::new("test", input.clone());
+        // the placeholder of the input evaluated before the first sample, for example in the cyclic Task
+        assert_eq!(fnDerivative.out().as_double().value, 0.0);
+        let start = Utc::now();
+        let test_data = [
+            // millis, value, rate per second
+            (0, 2.0, 0.0),
+            (1000, 4.0, 2.0),
+            (1000, 100.0, 2.0),       // the same timestamp - ignored
+            (1500, 3.0, -2.0),
+            (1510, 3.5, 50.0),        // burst of points
+            (3510, 3.5, 0.0),
+        ];
+        for (millis, value, target) in test_data {
+            input.borrow_mut().add(point(start, millis, value));
+            let state = fnDerivative.out().as_double().value;
+            debug!("millis: {:?}   |   value: {:?}   |   state: {:?}", millis, value, state);
+            assert!((state - target).abs() < 1e-9, "millis {}   |   result: {}, target: {}", millis, state, target);
+        }
+    }
+    ///
+    /// The samples older then the function created are used, the time taken from the Point.timestamp only
+    #[test]
+    fn test_samples_older_then_created() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_samples_older_then_created");
+        let start = Utc::now() - chrono::Duration::hours(1);
+        let input = init_each(0.0.to_point(0, "input"), FnConfPointType::Double);
+        let mut fnIntegral = FnIntegral::new("test", 0.0, input.clone(), None);
+        let mut fnDerivative = FnDerivative::new("test", input.clone());
+        assert_eq!(fnIntegral.out().as_double().value, 0.0);
+        assert_eq!(fnDerivative.out().as_double().value, 0.0);
+        let test_data = [
+            // millis, value, integral, rate per second
+            (0, 2.0, 0.0, 0.0),
+            (1000, 4.0, 3.0, 2.0),
+            (2000, 4.0, 7.0, 0.0),
+        ];
+        for (millis, value, target_integral, target_rate) in test_data {
+            input.borrow_mut().add(point(start, millis, value));
+            let integral = fnIntegral.out().as_double().value;
+            let rate = fnDerivative.out().as_double().value;
+            debug!("millis: {:?}   |   value: {:?}   |   integral: {:?}   |   rate: {:?}", millis, value, integral, rate);
+            assert!((integral - target_integral).abs() < 1e-9, "millis {}   |   integral: {}, target: {}", millis, integral, target_integral);
+            assert!((rate - target_rate).abs() < 1e-9, "millis {}   |   rate: {}, target: {}", millis, rate, target_rate);
+        }
+    }
+}
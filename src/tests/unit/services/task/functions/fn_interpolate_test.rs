@@ -0,0 +1,77 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, status::status::Status, types::fn_in_out_ref::FnInOutRef},
+        services::task::nested_function::{fn_::FnOut, fn_input::FnInput, fn_interpolate::{FnInterpolate, Extrapolation}},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_table() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_table");
+        // unsorted on purpose, sorted by the function
+        let table = vec![(10.0, 20.0), (0.0, 0.0), (5.0, 5.0)];
+        let test_data = [
+            // x, extrapolation, y, status
+            (0.0, Extrapolation::Hold, 0.0, Status::Ok),
+            (2.5, Extrapolation::Hold, 2.5, Status::Ok),
+            (5.0, Extrapolation::Hold, 5.0, Status::Ok),
+            (7.5, Extrapolation::Hold, 12.5, Status::Ok),
+            (10.0, Extrapolation::Hold, 20.0, Status::Ok),
+            (-5.0, Extrapolation::Hold, 0.0, Status::Ok),
+            (15.0, Extrapolation::Hold, 20.0, Status::Ok),
+            (-5.0, Extrapolation::Linear, -5.0, Status::Ok),
+            (15.0, Extrapolation::Linear, 35.0, Status::Ok),
+            (7.5, Extrapolation::Invalid, 12.5, Status::Ok),
+            (-5.0, Extrapolation::Invalid, 0.0, Status::Invalid),
+            (15.0, Extrapolation::Invalid, 20.0, Status::Invalid),
+        ];
+        for (x, extrapolation, target, target_status) in test_data {
+            let input = init_each(x.to_point(0, "input"), FnConfPointType::Double);
+            let mut fnInterpolate = FnInterpolate::new("test", input, table.clone(), extrapolation);
+            let state = fnInterpolate.out();
+            debug!("x: {:?}   |   extrapolation: {:?}   |   state: {:?}", x, extrapolation, state);
+            assert!((state.as_double().value - target).abs() < 1e-9, "x {}   |   result: {:?}, target: {}", x, state, target);
+            assert_eq!(state.status(), target_status);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_int_input() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_int_input");
+        let input = init_each(3.to_point(0, "input"), FnConfPointType::Int);
+        let mut fnInterpolate = FnInterpolate::new("test", input, vec![(0.0, 100.0), (4.0, 0.0)], Extrapolation::Hold);
+        let state = fnInterpolate.out().as_double().value;
+        assert!((state - 25.0).abs() < 1e-9, "result: {}, target: {}", state, 25.0);
+    }
+}
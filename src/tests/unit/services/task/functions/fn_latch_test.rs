@@ -0,0 +1,111 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_input::FnInput, fn_latch::{FnLatch, LatchDominance}}
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_dominance() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_dominance");
+        let set = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let reset = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnLatchSet = FnLatch::new("test", set.clone(), reset.clone(), LatchDominance::Set);
+        let mut fnLatchReset = FnLatch::new("test", set.clone(), reset.clone(), LatchDominance::Reset);
+        let test_data = vec![
+        //  set    reset  SR     RS
+            (false, false, false, false),
+            (true, false, true, true),
+            (false, false, true, true),
+            (false, true, false, false),
+            (false, false, false, false),
+            (true, true, true, false),
+            (false, false, true, false),
+            (true, false, true, true),
+            (true, true, true, false),
+            (false, true, false, false),
+        ];
+        for (set_value, reset_value, target_sr, target_rs) in test_data {
+            set.borrow_mut().add(set_value.to_point(0, "test"));
+            reset.borrow_mut().add(reset_value.to_point(0, "test"));
+            let state = fnLatchSet.out();
+            debug!("set: {:?}, reset: {:?}   |   SR: {:?}", set_value, reset_value, state);
+            assert_eq!(state.as_bool().value.0, target_sr);
+            let state = fnLatchReset.out();
+            debug!("set: {:?}, reset: {:?}   |   RS: {:?}", set_value, reset_value, state);
+            assert_eq!(state.as_bool().value.0, target_rs);
+        }
+    }
+    ///
+    /// The latched state cleared by the reset
+    #[test]
+    fn test_reset() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_reset");
+        let set = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let reset = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnLatch = FnLatch::new("test", set.clone(), reset.clone(), LatchDominance::Set);
+        set.borrow_mut().add(true.to_point(0, "test"));
+        assert_eq!(fnLatch.out().as_bool().value.0, true);
+        set.borrow_mut().add(false.to_point(0, "test"));
+        assert_eq!(fnLatch.out().as_bool().value.0, true);
+        fnLatch.reset();
+        assert_eq!(fnLatch.out().as_bool().value.0, false);
+    }
+    ///
+    /// The latched state returned by retained() & restored by restore()
+    #[test]
+    fn test_retain() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_retain");
+        let set = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let reset = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnLatch = FnLatch::new("test", set.clone(), reset.clone(), LatchDominance::Set);
+        assert_eq!(fnLatch.retained().unwrap().as_bool().value.0, false);
+        set.borrow_mut().add(true.to_point(0, "test"));
+        assert_eq!(fnLatch.out().as_bool().value.0, true);
+        let state = fnLatch.retained().unwrap();
+        assert_eq!(state.as_bool().value.0, true);
+        // restart
+        let set = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let reset = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnLatch = FnLatch::new("test", set.clone(), reset.clone(), LatchDominance::Set);
+        fnLatch.restore(state);
+        assert_eq!(fnLatch.out().as_bool().value.0, true);
+        reset.borrow_mut().add(true.to_point(0, "test"));
+        assert_eq!(fnLatch.out().as_bool().value.0, false);
+        // non-Bool state ignored
+        let mut fnLatch = FnLatch::new("test", set, reset, LatchDominance::Set);
+        fnLatch.restore(1.to_point(0, "test"));
+        assert_eq!(fnLatch.retained().unwrap().as_bool().value.0, false);
+    }
+}
@@ -0,0 +1,78 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_lt::FnLt, fn_input::FnInput}
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_numeric() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_numeric");
+        let test_data = vec![
+            (1.to_point(0, "int"), 2.to_point(0, "int"), true),
+            (2.to_point(0, "int"), 2.to_point(0, "int"), false),
+            (3.to_point(0, "int"), 2.to_point(0, "int"), false),
+            (1.5f32.to_point(0, "real"), 2.to_point(0, "int"), true),
+            (2.5f64.to_point(0, "double"), 2.4f32.to_point(0, "real"), false),
+            (false.to_point(0, "bool"), true.to_point(0, "bool"), true),
+            (true.to_point(0, "bool"), 0.5f64.to_point(0, "double"), false),
+        ];
+        for (value1, value2, target) in test_data {
+            let input1 = init_each(value1.clone(), FnConfPointType::Any);
+            let input2 = init_each(value2.clone(), FnConfPointType::Any);
+            let mut fnLt = FnLt::new("test", input1, input2);
+            let state = fnLt.out();
+            debug!("value1: {:?}, value2: {:?}   |   state: {:?}", value1, value2, state);
+            assert_eq!(state.as_bool().value.0, target);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_string() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_string");
+        let test_data = vec![
+            ("a", "b", true),
+            ("b", "a", false),
+            ("abc", "abc", false),
+            ("", "a", true),
+        ];
+        for (value1, value2, target) in test_data {
+            let input1 = init_each(value1.to_point(0, "string"), FnConfPointType::String);
+            let input2 = init_each(value2.to_point(0, "string"), FnConfPointType::String);
+            let mut fnLt = FnLt::new("test", input1, input2);
+            let state = fnLt.out();
+            debug!("value1: {:?}, value2: {:?}   |   state: {:?}", value1, value2, state);
+            assert_eq!(state.as_bool().value.0, target);
+        }
+    }
+}
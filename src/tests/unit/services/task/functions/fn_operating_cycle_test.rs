@@ -0,0 +1,117 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use chrono::{DateTime, Utc};
+    use std::{sync::Once, rc::Rc, cell::RefCell, time::Duration};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{cot::cot::Cot, point::{point::Point, point_type::{PointType, ToPoint}}, status::status::Status, types::{bool::Bool, fn_in_out_ref::FnInOutRef}},
+        services::task::nested_function::{fn_::FnOut, fn_input::FnInput, fn_operating_cycle::FnOperatingCycle},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    /// Returns Double point with the given timestamp in milliseconds from the [start]
+    fn point(start: DateTime<Utc>, millis: i64, value: f64) -> PointType {
+        PointType::Double(Point::new(0, "input", value, Status::Ok, Cot::Inf, start + chrono::Duration::milliseconds(millis)))
+    }
+    ///
+    /// Returns Bool point with the given timestamp in milliseconds from the [start]
+    fn bool_point(start: DateTime<Utc>, millis: i64, value: bool) -> PointType {
+        PointType::Bool(Point::new(0, "start", Bool(value), Status::Ok, Cot::Inf, start + chrono::Duration::milliseconds(millis)))
+    }
+    ///
+    ///
+    #[test]
+    fn test_cycles() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_cycles");
+        let start_time = Utc::now();
+        let start = init_each(false.to_point(0, "start"), FnConfPointType::Bool);
+        let load = init_each(0.0f64.to_point(0, "load"), FnConfPointType::Double);
+        let alarm_class = init_each(0.to_point(0, "alarmClass"), FnConfPointType::Int);
+        let mut fnOperatingCycle = FnOperatingCycle::new(
+            "test", start.clone(), load.clone(), Some(alarm_class.clone()), Duration::from_secs(5), None,
+        );
+        let test_data = [
+            // millis, start, load, alarm class, closed cycle: (start, stop, duration, alarm class, average load, max load)
+            (0, false, 0.0, 0, None),
+            (1000, true, 10.0, 0, None),
+            (2000, true, 20.0, 2, None),
+            (3000, true, 30.0, 1, None),
+            (11000, false, 0.0, 0, Some((1000, 11000, 10.0, 2, 20.0, 30.0))),
+            (12000, false, 0.0, 0, Some((1000, 11000, 10.0, 2, 20.0, 30.0))),
+            // too short cycle, ignored
+            (13000, true, 50.0, 3, Some((1000, 11000, 10.0, 2, 20.0, 30.0))),
+            (15000, false, 0.0, 0, Some((1000, 11000, 10.0, 2, 20.0, 30.0))),
+            (20000, true, 4.0, 0, Some((1000, 11000, 10.0, 2, 20.0, 30.0))),
+            (26000, false, 0.0, 0, Some((20000, 26000, 6.0, 0, 4.0, 4.0))),
+        ];
+        for (millis, start_value, load_value, alarm_class_value, target) in test_data {
+            start.borrow_mut().add(bool_point(start_time, millis, start_value));
+            load.borrow_mut().add(point(start_time, millis, load_value));
+            alarm_class.borrow_mut().add(alarm_class_value.to_point(0, "alarmClass"));
+            let state = fnOperatingCycle.out().as_string().value;
+            debug!("millis: {:?}   |   start: {:?}   |   load: {:?}   |   state: {:?}", millis, start_value, load_value, state);
+            match target {
+                Some((start_millis, stop_millis, duration, alarm_class, average_load, max_load)) => {
+                    let record: serde_json::Value = serde_json::from_str(&state).unwrap();
+                    let target = serde_json::json!({
+                        "start": (start_time + chrono::Duration::milliseconds(start_millis)).to_rfc3339(),
+                        "stop": (start_time + chrono::Duration::milliseconds(stop_millis)).to_rfc3339(),
+                        "duration": duration,
+                        "alarmClass": alarm_class,
+                        "averageLoad": average_load,
+                        "maxLoad": max_load,
+                    });
+                    assert_eq!(record, target);
+                }
+                None => assert_eq!(state, ""),
+            }
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_template() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_template");
+        let start_time = Utc::now();
+        let start = init_each(false.to_point(0, "start"), FnConfPointType::Bool);
+        let load = init_each(0.0f64.to_point(0, "load"), FnConfPointType::Double);
+        let mut fnOperatingCycle = FnOperatingCycle::new(
+            "test", start.clone(), load.clone(), None, Duration::ZERO,
+            Some("insert into operating_cycle (duration, alarm_class, average_load, max_load) values ({duration}, {alarmClass}, {averageLoad}, {maxLoad});".to_owned()),
+        );
+        for (millis, start_value, load_value) in [(0, true, 1.5), (500, true, 3.5), (2500, false, 0.0)] {
+            start.borrow_mut().add(bool_point(start_time, millis, start_value));
+            load.borrow_mut().add(point(start_time, millis, load_value));
+            fnOperatingCycle.out();
+        }
+        let state = fnOperatingCycle.out().as_string().value;
+        debug!("state: {:?}", state);
+        assert_eq!(state, "insert into operating_cycle (duration, alarm_class, average_load, max_load) values (2.5, 0, 2.5, 3.5);");
+    }
+}
@@ -0,0 +1,91 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, status::status::Status, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_pow::FnPow, fn_input::FnInput}
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_int() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_int");
+        let input1 = init_each(0.to_point(0, "int"), FnConfPointType::Int);
+        let input2 = init_each(0.to_point(0, "int"), FnConfPointType::Int);
+        let mut fnPow = FnPow::new(
+            "test",
+            input1.clone(),
+            input2.clone(),
+        );
+        let test_data = vec![
+            (2, 3, 8, Status::Ok),
+            (-3, 3, -27, Status::Ok),
+            (5, 0, 1, Status::Ok),
+            (2, -1, 0, Status::Invalid),
+            (2, 64, 0, Status::Invalid),
+            (10, 2, 100, Status::Ok),
+        ];
+        for (value1, value2, target, status) in test_data {
+            input1.borrow_mut().add(value1.to_point(0, "test"));
+            input2.borrow_mut().add(value2.to_point(0, "test"));
+            let state = fnPow.out();
+            debug!("value1: {:?}, value2: {:?}   |   state: {:?}", value1, value2, state);
+            assert_eq!(state.as_int().value, target);
+            assert_eq!(state.status(), status);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_real() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_real");
+        let input1 = init_each(0.0f32.to_point(0, "real"), FnConfPointType::Real);
+        let input2 = init_each(0.0f32.to_point(0, "real"), FnConfPointType::Real);
+        let mut fnPow = FnPow::new(
+            "test",
+            input1.clone(),
+            input2.clone(),
+        );
+        let test_data = vec![
+            (4.0, 0.5, 2.0, Status::Ok),
+            (2.0, -1.0, 0.5, Status::Ok),
+            (-8.0, 0.5, 0.0, Status::Invalid),
+            (0.0, -1.0, 0.0, Status::Invalid),
+        ];
+        for (value1, value2, target, status) in test_data {
+            input1.borrow_mut().add(value1.to_point(0, "test"));
+            input2.borrow_mut().add(value2.to_point(0, "test"));
+            let state = fnPow.out();
+            debug!("value1: {:?}, value2: {:?}   |   state: {:?}", value1, value2, state);
+            assert_eq!(state.as_real().value, target);
+            assert_eq!(state.status(), status);
+        }
+    }
+}
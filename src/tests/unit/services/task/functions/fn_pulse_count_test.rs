@@ -1,153 +1,190 @@
 #![allow(non_snake_case)]
 #[cfg(test)]
-use log::{debug, info};
-use std::{sync::Once, rc::Rc, cell::RefCell};
 
-use crate::{
-    core_::{debug::debug_session::{DebugSession, LogLevel, Backtrace},
-    point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef},
-    services::task::nested_function::{fn_::{FnInOut, FnOut},
-    fn_count::FnCount, fn_input::FnInput},
-};
-///
-///
-static INIT: Once = Once::new();
-///
-/// once called initialisation
-fn init_once() {
-    INIT.call_once(|| {
-        // implement your initialisation code to be called only once for current test file
-    })
-}
-///
-/// returns:
-///  - ...
-fn init_each(initial: PointType) -> FnInOutRef {
-    fn boxFnInput(input: FnInput) -> Box<(dyn FnInOut)> {
-        Box::new(input)
+mod tests {
+    use log::{debug, info};
+    use std::{sync::{Arc, Mutex, Once}, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::{fn_::fn_conf_keywd::FnConfPointType, point_config::name::Name, task_config::TaskConfig},
+        core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef},
+        services::{services::Services, task::task_nodes::TaskNodes},
+        services::task::nested_function::{fn_::FnOut, fn_pulse_count::FnPulseCount, fn_input::FnInput},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
     }
-    Rc::new(RefCell::new(
-        boxFnInput(
-            FnInput::new("test", initial)
-        )
-    ))
-}
-///
-///
-#[test]
-fn test_single() {
-    DebugSession::init(LogLevel::Info, Backtrace::Short);
-    init_once();
-    info!("test_single");
-    let input = init_each(false.toPoint("bool"));
-    let mut fnCount = FnCount::new(
-        "test",
-        0,
-        input.clone(),
-    );
-    let test_data = vec![
-        (false, 0),
-        (false, 0),
-        (true, 1),
-        (false, 1),
-        (false, 1),
-        (true, 2),
-        (false, 2),
-        (true, 3),
-        (false, 3),
-        (false, 3),
-        (true, 4),
-        (true, 4),
-        (false, 4),
-        (false, 4),
-    ];
-    for (value, targetState) in test_data {
-        let point = value.toPoint("test");
-        input.borrow_mut().add(point);
-        // debug!("input: {:?}", &input);
-        let state = fnCount.out();
-        // debug!("input: {:?}", &mut input);
-        debug!("value: {:?}   |   state: {:?}", value, state);
-        assert_eq!(state.asInt().value, targetState);
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
     }
-}
-//
-
-#[test]
-fn test_multiple() {
-    DebugSession::init(LogLevel::Info, Backtrace::Short);
-    init_once();
-    info!("test_multiple");
-    let input = init_each(false.toPoint("bool"));
-    let mut fnCount = FnCount::new(
-        "test",
-        0,
-        input.clone(),
-    );
-    let test_data = vec![
-        (false, 0),
-        (false, 0),
-        (true, 1),
-        (false, 1),
-        (false, 1),
-        (true, 2),
-        (false, 2),
-        (true, 3),
-        (false, 3),
-        (false, 3),
-        (true, 4),
-        (true, 4),
-        (false, 4),
-        (false, 4),
-    ];
-    for (value, targetState) in test_data {
-        let point = value.toPoint("test");
-        input.borrow_mut().add(point);
-        // debug!("input: {:?}", &input);
-        let state = fnCount.out();
-        // debug!("input: {:?}", &mut input);
-        debug!("value: {:?}   |   state: {:?}", value, state);
-        assert_eq!(state.asInt().value, targetState);
+    ///
+    ///
+    #[test]
+    fn test_multiple() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_multiple");
+        let input = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnPulseCount = FnPulseCount::new(
+            "test",
+            0,
+            input.clone(),
+            None,
+        );
+        let test_data = vec![
+            (false, 0),
+            (false, 0),
+            (true, 1),
+            (false, 1),
+            (false, 1),
+            (true, 2),
+            (false, 2),
+            (true, 3),
+            (false, 3),
+            (false, 3),
+            (true, 4),
+            (true, 4),
+            (true, 4),
+            (false, 4),
+            (false, 4),
+        ];
+        for (value, target) in test_data {
+            input.borrow_mut().add(value.to_point(0, "test"));
+            let state = fnPulseCount.out();
+            debug!("value: {:?}   |   state: {:?}", value, state);
+            assert_eq!(state.as_int().value, target);
+        }
     }
-}
-
-#[test]
-fn test_multiple_reset() {
-    DebugSession::init(LogLevel::Info, Backtrace::Short);
-    init_once();
-    info!("test_multiple_reset");
-    let input = init_each(false.toPoint("bool"));
-    let mut fnCount = FnCount::new(
-        "test",
-        0,
-        input.clone(),
-    );
-    let test_data = vec![
-        (false, 0, false),
-        (false, 0, false),
-        (true, 1, false),
-        (false, 1, false),
-        (false, 1, false),
-        (true, 2, false),
-        (false, 0, true),
-        (true, 1, false),
-        (false, 1, false),
-        (false, 1, false),
-        (true, 2, false),
-        (true, 2, false),
-        (false, 0, true),
-        (false, 0, false),
-    ];
-    for (value, targetState, reset) in test_data {
-        if reset {
-            fnCount.reset();
+    ///
+    ///
+    #[test]
+    fn test_multiple_reset() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_multiple_reset");
+        let input = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnPulseCount = FnPulseCount::new(
+            "test",
+            0,
+            input.clone(),
+            None,
+        );
+        let test_data = vec![
+            (false, 0, false),
+            (false, 0, false),
+            (true, 1, false),
+            (false, 1, false),
+            (false, 1, false),
+            (true, 2, false),
+            (false, 0, true),
+            (true, 1, false),
+            (false, 1, false),
+            (false, 1, false),
+            (true, 2, false),
+            (true, 2, false),
+            (false, 0, true),
+            (false, 0, false),
+        ];
+        for (value, target, reset) in test_data {
+            if reset {
+                fnPulseCount.reset();
+            }
+            input.borrow_mut().add(value.to_point(0, "test"));
+            let state = fnPulseCount.out();
+            debug!("value: {:?}   |   state: {:?}", value, state);
+            assert_eq!(state.as_int().value, target);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_reset_input() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_reset_input");
+        let input = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let reset = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let mut fnPulseCount = FnPulseCount::new(
+            "test",
+            5,
+            input.clone(),
+            Some(reset.clone()),
+        );
+        let test_data = vec![
+            (false, false, 5),
+            (true, false, 6),
+            (false, false, 6),
+            (true, false, 7),
+            (true, true, 5),
+            (false, true, 5),
+            (true, true, 5),
+            (true, false, 5),
+            (false, false, 5),
+            (true, false, 6),
+        ];
+        for (value, reset_value, target) in test_data {
+            input.borrow_mut().add(value.to_point(0, "test"));
+            reset.borrow_mut().add(reset_value.to_point(0, "test"));
+            let state = fnPulseCount.out();
+            debug!("value: {:?}, reset: {:?}   |   state: {:?}", value, reset_value, state);
+            assert_eq!(state.as_int().value, target);
+        }
+    }
+    ///
+    /// The 'initial' & 'reset' configured in the Task
+    #[test]
+    fn test_conf() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_conf");
+        let self_id = "test_pulse_count_conf";
+        let parent = Name::new("", self_id);
+        let conf = serde_yaml::from_str(r#"
+            service Task PulseCountTask:
+                cycle: 10 ms
+                in queue recv-queue:
+                    max-length: 10000
+                fn pulseCount:
+                    initial: 5
+                    input: point bool '/path/Pulse'
+                    reset: point bool '/path/Reset'
+        "#).unwrap();
+        let conf = TaskConfig::from_yaml(&parent, &conf);
+        let services = Arc::new(Mutex::new(Services::new(self_id)));
+        let mut task_nodes = TaskNodes::new(self_id);
+        task_nodes.buildNodes(&parent, conf, services);
+        let test_data = vec![
+            ("/path/Pulse", false, 5),
+            ("/path/Pulse", true, 6),
+            ("/path/Pulse", false, 6),
+            ("/path/Pulse", true, 7),
+            ("/path/Reset", true, 5),
+            ("/path/Pulse", false, 5),
+            ("/path/Pulse", true, 5),
+            ("/path/Reset", false, 5),
+            ("/path/Pulse", false, 5),
+            ("/path/Pulse", true, 6),
+        ];
+        for (name, value, target) in test_data {
+            task_nodes.eval(value.to_point(0, name));
+            let out = task_nodes.getEvalNode(name).unwrap().getOuts().first().unwrap().clone();
+            let state = out.borrow_mut().out();
+            debug!("{}: {:?}   |   state: {:?}", name, value, state);
+            assert_eq!(state.as_int().value, target, "{}: {:?}", name, value);
         }
-        let point = value.toPoint("test");
-        input.borrow_mut().add(point);
-        // debug!("input: {:?}", &input);
-        let state = fnCount.out();
-        // debug!("input: {:?}", &mut input);
-        debug!("value: {:?}   |   state: {:?}", value, state);
-        assert_eq!(state.asInt().value, targetState);
     }
 }
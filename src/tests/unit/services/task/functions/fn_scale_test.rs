@@ -0,0 +1,92 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, status::status::Status, types::fn_in_out_ref::FnInOutRef},
+        services::task::nested_function::{fn_::FnOut, fn_input::FnInput, fn_scale::FnScale, fn_clamp::FnClamp},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_scale() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_scale");
+        // 4..20 mA => 0..50 t
+        let test_data = [
+            (4.to_point(0, "input"), 0.0),
+            (20.to_point(0, "input"), 50.0),
+            (12.0f32.to_point(0, "input"), 25.0),
+            (8.0f64.to_point(0, "input"), 12.5),
+            (0.to_point(0, "input"), -12.5),
+            (24.0f64.to_point(0, "input"), 62.5),
+        ];
+        for (value, target) in test_data {
+            let input = init_each(value.clone(), FnConfPointType::Any);
+            let mut fnScale = FnScale::new("test", input, 4.0, 20.0, 0.0, 50.0);
+            let state = fnScale.out();
+            // the output is Double regardless of the input type
+            assert!(matches!(state, PointType::Double(_)), "value {:?}   |   result: {:?}", value, state);
+            let state = state.as_double().value;
+            debug!("value: {:?}   |   state: {:?}", value, state);
+            assert!((state - target).abs() < 1e-9, "value {:?}   |   result: {}, target: {}", value, state, target);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_clamp() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_clamp");
+        let test_data = [
+            (5.to_point(0, "input"), Some(0.0), Some(10.0), 5.to_point(0, "input")),
+            ((-5).to_point(0, "input"), Some(0.0), Some(10.0), 0.to_point(0, "input")),
+            (15.to_point(0, "input"), Some(0.0), Some(10.0), 10.to_point(0, "input")),
+            (15.5f32.to_point(0, "input"), Some(0.0), Some(10.5), 10.5f32.to_point(0, "input")),
+            ((-1.5f64).to_point(0, "input"), Some(-1.0), None, (-1.0f64).to_point(0, "input")),
+            ((-1.5f64).to_point(0, "input"), None, Some(1.0), (-1.5f64).to_point(0, "input")),
+            (123.0f64.to_point(0, "input"), None, None, 123.0f64.to_point(0, "input")),
+            // Int input with the fractional limits, the result is kept inside the range
+            (3.to_point(0, "input"), Some(0.5), Some(2.5), 2.to_point(0, "input")),
+            (0.to_point(0, "input"), Some(0.5), Some(2.5), 1.to_point(0, "input")),
+            (2.to_point(0, "input"), Some(0.5), Some(2.5), 2.to_point(0, "input")),
+            (5.to_point(0, "input"), Some(-2.7), Some(2.7), 2.to_point(0, "input")),
+            ((-5).to_point(0, "input"), Some(-2.7), Some(2.7), (-2).to_point(0, "input")),
+        ];
+        for (value, min, max, target) in test_data {
+            let input = init_each(value.clone(), FnConfPointType::Any);
+            let min = min.map(|min: f64| init_each(min.to_point(0, "min"), FnConfPointType::Double));
+            let max = max.map(|max: f64| init_each(max.to_point(0, "max"), FnConfPointType::Double));
+            let mut fnClamp = FnClamp::new("test", input, min, max);
+            let state = fnClamp.out();
+            debug!("value: {:?}   |   state: {:?}", value, state);
+            assert_eq!(state.value(), target.value());
+            assert_eq!(state.status(), Status::Ok);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use chrono::{DateTime, Utc};
+    use std::{sync::Once, rc::Rc, cell::RefCell, time::Duration};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{aprox_eq::aprox_eq::AproxEq, cot::cot::Cot, point::{point::Point, point_type::{PointType, ToPoint}}, status::status::Status, types::fn_in_out_ref::FnInOutRef},
+        services::task::nested_function::{fn_::FnOut, fn_input::FnInput, fn_statistic::{FnStatistic, StatisticOp, WindowSize}},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    /// Returns Double point with the given timestamp in seconds from the [start]
+    fn point(start: DateTime<Utc>, secs: i64, value: f64) -> PointType {
+        PointType::Double(Point::new(0, "input", value, Status::Ok, Cot::Inf, start + chrono::Duration::seconds(secs)))
+    }
+    ///
+    ///
+    #[test]
+    fn test_samples() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_samples");
+        let start = Utc::now();
+        let test_data = [
+            // value, average, min, max, stddev over the last 3 samples
+            (2.0, 2.0, 2.0, 2.0, 0.0),
+            (4.0, 3.0, 2.0, 4.0, 1.0),
+            (6.0, 4.0, 2.0, 6.0, 1.63299),
+            (1.0, 3.66667, 1.0, 6.0, 2.05480),
+            (1.0, 2.66667, 1.0, 6.0, 2.35702),
+            (1.0, 1.0, 1.0, 1.0, 0.0),
+        ];
+        for op in [StatisticOp::Average, StatisticOp::Min, StatisticOp::Max, StatisticOp::StdDev] {
+            let input = init_each(0.0.to_point(0, "input"), FnConfPointType::Double);
+            let mut fnStatistic = FnStatistic::new("test", input.clone(), op, WindowSize::Samples(3), None);
+            for (step, (value, average, min, max, stddev)) in test_data.into_iter().enumerate() {
+                input.borrow_mut().add(point(start, step as i64, value));
+                let target = match op {
+                    StatisticOp::Average => average,
+                    StatisticOp::Min => min,
+                    StatisticOp::Max => max,
+                    StatisticOp::StdDev => stddev,
+                };
+                let state = fnStatistic.out().as_double().value;
+                debug!("{:?}   |   value: {:?}   |   state: {:?}", op, value, state);
+                assert!(state.aprox_eq(target, 4), "{:?}   |   step {}   |   result: {}, target: {}", op, step, state, target);
+                // evaluation with the same sample must not change the window
+                let state = fnStatistic.out().as_double().value;
+                assert!(state.aprox_eq(target, 4), "{:?}   |   step {} repeated   |   result: {}, target: {}", op, step, state, target);
+            }
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_duration_reset() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_duration_reset");
+        let start = Utc::now();
+        let input = init_each(0.0.to_point(0, "input"), FnConfPointType::Double);
+        let reset = init_each(false.to_point(0, "reset"), FnConfPointType::Bool);
+        let mut fnStatistic = FnStatistic::new("test", input.clone(), StatisticOp::Max, WindowSize::Duration(Duration::from_secs(10)), Some(reset.clone()));
+        let test_data = [
+            // secs, value, reset, max over the last 10 seconds
+            (0, 5.0, false, 5.0),
+            (4, 3.0, false, 5.0),
+            (10, 2.0, false, 5.0),
+            (11, 1.0, false, 3.0),
+            (15, 4.0, false, 4.0),
+            (16, 1.0, true, 1.0),
+            (17, 2.0, false, 2.0),
+            (30, 0.5, false, 0.5),
+        ];
+        for (secs, value, reset_value, target) in test_data {
+            input.borrow_mut().add(point(start, secs, value));
+            reset.borrow_mut().add(reset_value.to_point(0, "reset"));
+            let state = fnStatistic.out().as_double().value;
+            debug!("secs: {:?}   |   value: {:?}   |   reset: {:?}   |   state: {:?}", secs, value, reset_value, state);
+            assert!(state.aprox_eq(target, 4), "secs {}   |   result: {}, target: {}", secs, state, target);
+        }
+    }
+    ///
+    /// The placeholder of the input and the stale sample after reset are not added to the window
+    #[test]
+    fn test_placeholder_stale() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_placeholder_stale");
+        let start = Utc::now();
+        let input = init_each(0.0.to_point(0, "input"), FnConfPointType::Double);
+        let reset = init_each(false.to_point(0, "reset"), FnConfPointType::Bool);
+        let mut fnStatistic = FnStatistic::new("test", input.clone(), StatisticOp::Min, WindowSize::Samples(10), Some(reset.clone()));
+        // the placeholder of the input evaluated before the first sample, for example in the cyclic Task
+        assert_eq!(fnStatistic.out().as_double().value, 0.0);
+        assert_eq!(fnStatistic.out().as_double().value, 0.0);
+        let test_data = [
+            // secs, value, reset, min, new sample
+            (0, 5.0, false, 5.0, true),
+            (1, 3.0, false, 3.0, true),
+            (1, 3.0, true, 0.0, false),        // reset, the consumed sample is not repeated, the window is empty
+            (1, 3.0, false, 0.0, false),
+            (2, 7.0, false, 7.0, true),
+            (3, 6.0, false, 6.0, true),
+        ];
+        for (secs, value, reset_value, target, new_sample) in test_data {
+            if new_sample {
+                input.borrow_mut().add(point(start, secs, value));
+            }
+            reset.borrow_mut().add(reset_value.to_point(0, "reset"));
+            let state = fnStatistic.out().as_double().value;
+            debug!("secs: {:?}   |   value: {:?}   |   reset: {:?}   |   state: {:?}", secs, value, reset_value, state);
+            assert!(state.aprox_eq(target, 4), "secs {}   |   result: {}, target: {}", secs, state, target);
+        }
+        // reset of the function, the input returns to the placeholder
+        fnStatistic.reset();
+        assert_eq!(fnStatistic.out().as_double().value, 0.0);
+        input.borrow_mut().add(point(start, 4, 8.0));
+        assert_eq!(fnStatistic.out().as_double().value, 8.0);
+    }
+}
@@ -0,0 +1,149 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use indexmap::IndexMap;
+    use log::{debug, info};
+    use chrono::{DateTime, Utc};
+    use std::{sync::{Arc, Mutex, Once}, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::{fn_::fn_conf_keywd::FnConfPointType, point_config::name::Name, task_config::TaskConfig}, core_::{cot::cot::Cot, point::{point::Point, point_type::{PointType, ToPoint}}, status::status::Status, types::fn_in_out_ref::FnInOutRef},
+        services::{services::Services, task::task_nodes::TaskNodes},
+        services::task::nested_function::{
+            fn_::FnOut, fn_input::FnInput, fn_add::FnAdd, fn_clamp::FnClamp, fn_if::FnIf, fn_switch::FnSwitch,
+            fn_statistic::{FnStatistic, StatisticOp, WindowSize}, fn_status_ignore::FnStatusIgnore,
+        },
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    /// Returns Double point with the given status & timestamp in milliseconds from the [start]
+    fn point(start: DateTime<Utc>, millis: i64, value: f64, status: Status) -> PointType {
+        PointType::Double(Point::new(0, "input", value, status, Cot::Inf, start + chrono::Duration::milliseconds(millis)))
+    }
+    ///
+    ///
+    #[test]
+    fn test_propagate() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_propagate");
+        let start = Utc::now();
+        let a = init_each(0.0.to_point(0, "a"), FnConfPointType::Double);
+        let b = init_each(0.0.to_point(0, "b"), FnConfPointType::Double);
+        let mut fns: Vec<Box<dyn FnOut>> = vec![
+            Box::new(FnAdd::new("test", a.clone(), b.clone())),
+            Box::new(FnClamp::new("test", a.clone(), Some(b.clone()), None)),
+            Box::new(FnStatistic::new("test", a.clone(), StatisticOp::Average, WindowSize::Samples(3), Some(b.clone()))),
+            Box::new(FnIf::new("test", b.clone(), a.clone(), a.clone())),
+            Box::new(FnSwitch::new("test", b.clone(), IndexMap::from([(0, a.clone())]), None)),
+        ];
+        let test_data = [
+            // a millis, a status, b millis, b status, target millis, target status
+            (0, Status::Ok, 0, Status::Ok, 0, Status::Ok),
+            (100, Status::Obsolete, 200, Status::Ok, 200, Status::Obsolete),
+            (300, Status::Ok, 250, Status::Invalid, 300, Status::Invalid),
+            (400, Status::TimeInvalid, 400, Status::Obsolete, 400, Status::TimeInvalid),
+            (500, Status::Invalid, 550, Status::TimeInvalid, 550, Status::Invalid),
+            (600, Status::Ok, 580, Status::Ok, 600, Status::Ok),
+        ];
+        for (a_millis, a_status, b_millis, b_status, target_millis, target_status) in test_data {
+            a.borrow_mut().add(point(start, a_millis, 1.0, a_status));
+            b.borrow_mut().add(point(start, b_millis, 0.0, b_status));
+            for fn_ in &mut fns {
+                let state = fn_.out();
+                debug!("{}   |   state: {:?}", fn_.id(), state);
+                assert_eq!(state.status(), target_status, "{}   |   state: {:?}", fn_.id(), state);
+                assert_eq!(state.timestamp(), start + chrono::Duration::milliseconds(target_millis), "{}   |   state: {:?}", fn_.id(), state);
+            }
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_status_ignore() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_status_ignore");
+        let start = Utc::now();
+        let a = init_each(0.0.to_point(0, "a"), FnConfPointType::Double);
+        let b = init_each(0.0.to_point(0, "b"), FnConfPointType::Double);
+        let add: FnInOutRef = Rc::new(RefCell::new(Box::new(FnAdd::new("test", a.clone(), b.clone()))));
+        let mut fnStatusIgnore = FnStatusIgnore::new("test", add);
+        let test_data = [
+            // a millis, a status, b millis, b status, target value
+            (0, Status::Ok, 0, Status::Ok, 3.0),
+            (100, Status::Invalid, 50, Status::Ok, 3.0),
+            (200, Status::Ok, 250, Status::Obsolete, 3.0),
+        ];
+        for (a_millis, a_status, b_millis, b_status, target) in test_data {
+            a.borrow_mut().add(point(start, a_millis, 1.0, a_status));
+            b.borrow_mut().add(point(start, b_millis, 2.0, b_status));
+            let state = fnStatusIgnore.out();
+            debug!("state: {:?}", state);
+            assert_eq!(state.as_double().value, target);
+            assert_eq!(state.status(), Status::Ok);
+            assert_eq!(state.timestamp(), start + chrono::Duration::milliseconds(a_millis.max(b_millis)));
+        }
+    }
+    ///
+    /// 'status: ignore' drops the statuses of the inputs, but keeps the own failure of the function
+    #[test]
+    fn test_status_ignore_conf() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_status_ignore_conf");
+        let self_id = "test_status_ignore_conf";
+        let parent = Name::new("", self_id);
+        let conf = serde_yaml::from_str(r#"
+            service Task StatusIgnoreTask:
+                cycle: 10 ms
+                in queue recv-queue:
+                    max-length: 10000
+                fn div:
+                    status: ignore
+                    input1: point double '/path/A'
+                    input2: point double '/path/B'
+        "#).unwrap();
+        let conf = TaskConfig::from_yaml(&parent, &conf);
+        let services = Arc::new(Mutex::new(Services::new(self_id)));
+        let mut task_nodes = TaskNodes::new(self_id);
+        task_nodes.buildNodes(&parent, conf, services);
+        let start = Utc::now();
+        let test_data = [
+            // a value, a status, b value, b status, target value, target status
+            (6.0, Status::Invalid, 2.0, Status::Ok, 3.0, Status::Ok),
+            (6.0, Status::Ok, 3.0, Status::Obsolete, 2.0, Status::Ok),
+            (6.0, Status::Invalid, 0.0, Status::Ok, 0.0, Status::Invalid),
+        ];
+        for (i, (a, a_status, b, b_status, target, target_status)) in test_data.into_iter().enumerate() {
+            let timestamp = start + chrono::Duration::milliseconds(i as i64);
+            task_nodes.eval(PointType::Double(Point::new(0, "/path/A", a, a_status, Cot::Inf, timestamp)));
+            task_nodes.eval(PointType::Double(Point::new(0, "/path/B", b, b_status, Cot::Inf, timestamp)));
+            let out = task_nodes.getEvalNode("/path/B").unwrap().getOuts().first().unwrap().clone();
+            let state = out.borrow_mut().out();
+            debug!("state: {:?}", state);
+            assert_eq!(state.as_double().value, target);
+            assert_eq!(state.status(), target_status);
+        }
+    }
+}
@@ -0,0 +1,88 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, status::status::Status, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_sub::FnSub, fn_input::FnInput}
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_int() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_int");
+        let input1 = init_each(0.to_point(0, "int"), FnConfPointType::Int);
+        let input2 = init_each(0.to_point(0, "int"), FnConfPointType::Int);
+        let mut fnSub = FnSub::new(
+            "test",
+            input1.clone(),
+            input2.clone(),
+        );
+        let test_data = vec![
+            (1, 1, 0, Status::Ok),
+            (2, 1, 1, Status::Ok),
+            (5, 7, -2, Status::Ok),
+            (-1, 1, -2, Status::Ok),
+            (-5, -1, -4, Status::Ok),
+            (0, 0, 0, Status::Ok),
+            (i64::MAX, 0, i64::MAX, Status::Ok),
+            (0, i64::MAX, -i64::MAX, Status::Ok),
+            (i64::MIN, 1, 0, Status::Invalid),
+        ];
+        for (value1, value2, target, status) in test_data {
+            input1.borrow_mut().add(value1.to_point(0, "test"));
+            input2.borrow_mut().add(value2.to_point(0, "test"));
+            let state = fnSub.out();
+            debug!("value1: {:?}, value2: {:?}   |   state: {:?}", value1, value2, state);
+            assert_eq!(state.as_int().value, target);
+            assert_eq!(state.status(), status);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_promotion() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_promotion");
+        let test_data = vec![
+            (3.to_point(0, "int"), 1.5f32.to_point(0, "real"), 1.5f32.to_point(0, "real")),
+            (3.5f32.to_point(0, "real"), 1.to_point(0, "int"), 2.5f32.to_point(0, "real")),
+            (3.to_point(0, "int"), 0.5f64.to_point(0, "double"), 2.5f64.to_point(0, "double")),
+            (0.5f32.to_point(0, "real"), 0.25f64.to_point(0, "double"), 0.25f64.to_point(0, "double")),
+            (true.to_point(0, "bool"), 1.to_point(0, "int"), 0.to_point(0, "int")),
+        ];
+        for (value1, value2, target) in test_data {
+            let input1 = init_each(value1.clone(), FnConfPointType::Any);
+            let input2 = init_each(value2.clone(), FnConfPointType::Any);
+            let mut fnSub = FnSub::new("test", input1, input2);
+            let state = fnSub.out();
+            debug!("value1: {:?}, value2: {:?}   |   state: {:?}", value1, value2, state);
+            assert_eq!(state.value(), target.value());
+        }
+    }
+}
@@ -0,0 +1,90 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use indexmap::IndexMap;
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, status::status::Status, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_switch::FnSwitch, fn_input::FnInput}
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_cases() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_cases");
+        let selector = init_each(0.to_point(0, "selector"), FnConfPointType::Int);
+        let cases: IndexMap<i64, FnInOutRef> = (0..3).map(|index| {
+            (index, init_each((index * 10).to_point(0, &format!("case{}", index)), FnConfPointType::Int))
+        }).collect();
+        let default = init_each((-1).to_point(0, "default"), FnConfPointType::Int);
+        let mut fnSwitch = FnSwitch::new("test", selector.clone(), cases, Some(default));
+        let test_data = vec![
+            (0.to_point(0, "selector"), 0),
+            (1.to_point(0, "selector"), 10),
+            (2.to_point(0, "selector"), 20),
+            (3.to_point(0, "selector"), -1),
+            ((-1).to_point(0, "selector"), -1),
+            (true.to_point(0, "selector"), 10),
+            (false.to_point(0, "selector"), 0),
+            (1.6f32.to_point(0, "selector"), 20),
+            (0.9f64.to_point(0, "selector"), 10),
+        ];
+        for (value, target) in test_data {
+            selector.borrow_mut().add(value.clone());
+            let state = fnSwitch.out();
+            debug!("selector: {:?}   |   state: {:?}", value, state);
+            assert_eq!(state.as_int().value, target);
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_no_default() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_no_default");
+        let selector = init_each(0.to_point(0, "selector"), FnConfPointType::Int);
+        let cases: IndexMap<i64, FnInOutRef> = [1, 3].into_iter().map(|index| {
+            (index, init_each((index * 10).to_point(0, &format!("case{}", index)), FnConfPointType::Int))
+        }).collect();
+        let mut fnSwitch = FnSwitch::new("test", selector.clone(), cases, None);
+        let test_data = vec![
+            (1, 10, Status::Ok),
+            (3, 30, Status::Ok),
+            (0, 10, Status::Invalid),
+            (2, 10, Status::Invalid),
+            (3, 30, Status::Ok),
+        ];
+        for (value, target, target_status) in test_data {
+            selector.borrow_mut().add(value.to_point(0, "selector"));
+            let state = fnSwitch.out();
+            debug!("selector: {:?}   |   state: {:?}", value, state);
+            assert_eq!(state.as_int().value, target);
+            assert_eq!(state.status(), target_status);
+        }
+    }
+}
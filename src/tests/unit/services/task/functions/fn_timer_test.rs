@@ -3,11 +3,12 @@
 
 mod fn_timer {
     use log::{debug, info};
-    use std::{sync::Once, time::{Instant, Duration}, thread,rc::Rc, cell::RefCell};
+    use std::{cmp::Ordering, sync::{Arc, Mutex, Once}, time::{Instant, Duration}, thread,rc::Rc, cell::RefCell};
     use debugging::session::debug_session::{Backtrace, DebugSession, LogLevel};
     use crate::{
-        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{aprox_eq::aprox_eq::AproxEq, point::point_type::{PointType, ToPoint},
-        types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_input::FnInput, fn_timer::FnTimer}
+        conf::{fn_::fn_conf_keywd::FnConfPointType, point_config::name::Name, task_config::TaskConfig}, core_::{aprox_eq::aprox_eq::AproxEq, point::point_type::{PointType, ToPoint},
+        types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_input::FnInput, fn_timer::FnTimer},
+        services::{services::Services, task::task_nodes::TaskNodes},
     };
     ///
     ///
@@ -42,6 +43,7 @@ mod fn_timer {
             0,
             input.clone(),
             false,
+            None,
         );
         let test_data = vec![
             (false, 0),
@@ -106,6 +108,7 @@ mod fn_timer {
             0,
             input.clone(),
             true,
+            None,
         );
         let test_data = vec![
             (false, 0),
@@ -165,6 +168,7 @@ mod fn_timer {
             0,
             input.clone(),
             true,
+            None,
         );
         let test_data = vec![
             (false, 0, false),
@@ -232,6 +236,7 @@ mod fn_timer {
             initial,
             input.clone(),
             true,
+            None,
         );
         let test_data = vec![
             (false, 0),
@@ -278,4 +283,55 @@ mod fn_timer {
             thread::sleep(Duration::from_secs_f64(0.1));
         }
     }
+    ///
+    /// The 'initial', 'repeat' & 'reset' configured in the Task
+    #[test]
+    fn conf_initial_repeat_reset() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_conf_initial_repeat_reset");
+        let self_id = "test_timer_conf";
+        let parent = Name::new("", self_id);
+        let conf = serde_yaml::from_str(r#"
+            service Task TimerTask:
+                cycle: 10 ms
+                in queue recv-queue:
+                    max-length: 10000
+                fn timer:
+                    initial: 10.0
+                    repeat: false
+                    input: point bool '/path/Run'
+                    reset: point bool '/path/Reset'
+        "#).unwrap();
+        let conf = TaskConfig::from_yaml(&parent, &conf);
+        let services = Arc::new(Mutex::new(Services::new(self_id)));
+        let mut task_nodes = TaskNodes::new(self_id);
+        task_nodes.buildNodes(&parent, conf, services);
+        // the elapsed depends on the wall clock, so it is checked by the order & within the wide range,
+        // Equal / Less / Greater - the elapsed relative to the previous step
+        let test_data = vec![
+            // input name, value, sleep before ms, relative to previous, min elapsed, max elapsed
+            ("/path/Run", true, 0, None, 10.0, 11.0),
+            ("/path/Run", false, 100, Some(Ordering::Greater), 10.1, 11.0),
+            ("/path/Run", true, 50, Some(Ordering::Equal), 10.1, 11.0),     // repeat: false - stopped after the first period
+            ("/path/Run", false, 50, Some(Ordering::Equal), 10.1, 11.0),
+            ("/path/Reset", true, 0, Some(Ordering::Less), 10.0, 10.0),
+            ("/path/Reset", false, 0, Some(Ordering::Equal), 10.0, 10.0),
+            ("/path/Run", true, 0, None, 10.0, 11.0),                      // counts again after reset
+            ("/path/Run", false, 100, Some(Ordering::Greater), 10.1, 11.0),
+        ];
+        let mut prev = None;
+        for (name, value, sleep, relation, min, max) in test_data {
+            thread::sleep(Duration::from_millis(sleep));
+            task_nodes.eval(value.to_point(0, name));
+            let out = task_nodes.getEvalNode(name).unwrap().getOuts().first().unwrap().clone();
+            let elapsed = out.borrow_mut().out().as_double().value;
+            debug!("{}: {:?}   |   elapsed: {:?}", name, value, elapsed);
+            assert!(min <= elapsed && elapsed <= max, "{}: {:?}   |   current '{}' out of range [{}, {}]", name, value, elapsed, min, max);
+            if let (Some(relation), Some(prev)) = (relation, prev) {
+                assert_eq!(elapsed.partial_cmp(&prev), Some(relation), "{}: {:?}   |   current '{}', previous '{}'", name, value, elapsed, prev);
+            }
+            prev = Some(elapsed);
+        }
+    }
 }
@@ -0,0 +1,160 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::{mpsc, Once}, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::{fn_::fn_conf_keywd::FnConfPointType, point_config::{name::Name, point_config::PointConfig}}, core_::{cot::cot::Cot, point::point_type::{PointType, ToPoint}, status::status::Status, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{export::fn_to_multi_queue::FnToMultiQueue, fn_::FnOut, fn_input::FnInput, fn_point::FnPoint}
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_send() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_send");
+        let tx_id = 17;
+        let parent = Name::from("/App/Task");
+        let conf1 = PointConfig::from_yaml(&parent, &serde_yaml::from_str(r#"
+            point Point.Real:
+                type: 'Real'
+        "#).unwrap());
+        let conf2 = PointConfig::from_yaml(&parent, &serde_yaml::from_str(r#"
+            point Point.Int:
+                type: 'Int'
+        "#).unwrap());
+        let input1 = init_each(0.to_point(0, "int"), FnConfPointType::Int);
+        let input2 = init_each("".to_point(0, "string"), FnConfPointType::String);
+        let point1: FnInOutRef = Rc::new(RefCell::new(Box::new(FnPoint::new("test", tx_id, conf1, Cot::Act, input1.clone()))));
+        let point2: FnInOutRef = Rc::new(RefCell::new(Box::new(FnPoint::new("test", tx_id, conf2, Cot::Act, input2.clone()))));
+        let (send, recv) = mpsc::channel();
+        let mut fnToMultiQueue = FnToMultiQueue::new("test", vec![point1, point2], None, send);
+        let test_data = vec![
+            (3, "12", 3.0, 12, Status::Ok),
+            (-5, "-7", -5.0, -7, Status::Ok),
+            (0, "x", 0.0, 0, Status::Invalid),
+        ];
+        for (value1, value2, target1, target2, status2) in test_data {
+            input1.borrow_mut().add(value1.to_point(0, "test"));
+            input2.borrow_mut().add(value2.to_point(0, "test"));
+            fnToMultiQueue.out();
+            let result1 = recv.try_recv().unwrap();
+            let result2 = recv.try_recv().unwrap();
+            debug!("result1: {:?}   |   result2: {:?}", result1, result2);
+            assert_eq!(result1.name(), "/App/Task/Point.Real");
+            assert_eq!(result1.as_real().value, target1);
+            assert_eq!(result1.status(), Status::Ok);
+            assert_eq!(result2.name(), "/App/Task/Point.Int");
+            assert_eq!(result2.as_int().value, target2);
+            assert_eq!(result2.status(), status2);
+            for result in [result1, result2] {
+                assert_eq!(*result.tx_id(), tx_id);
+                assert_eq!(result.cot(), Cot::Act);
+            }
+            assert!(recv.try_recv().is_err());
+        }
+    }
+    ///
+    ///
+    #[test]
+    fn test_enable() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_enable");
+        let parent = Name::from("/App/Task");
+        let conf = PointConfig::from_yaml(&parent, &serde_yaml::from_str(r#"
+            point Point.Int:
+                type: 'Int'
+        "#).unwrap());
+        let input = init_each(0.to_point(0, "int"), FnConfPointType::Int);
+        let enable = init_each(false.to_point(0, "bool"), FnConfPointType::Bool);
+        let point: FnInOutRef = Rc::new(RefCell::new(Box::new(FnPoint::new("test", 0, conf, Cot::Inf, input.clone()))));
+        let (send, recv) = mpsc::channel();
+        let mut fnToMultiQueue = FnToMultiQueue::new("test", vec![point], Some(enable.clone()), send);
+        let test_data = vec![
+            (1, false, None),
+            (2, true, Some(2)),
+            (3, false, None),
+            (4, true, Some(4)),
+            (5, true, Some(5)),
+        ];
+        for (value, enabled, target) in test_data {
+            input.borrow_mut().add(value.to_point(0, "test"));
+            enable.borrow_mut().add(enabled.to_point(0, "test"));
+            fnToMultiQueue.out();
+            let result = recv.try_recv().ok().map(|point| point.as_int().value);
+            debug!("value: {:?}, enable: {:?}   |   result: {:?}", value, enabled, result);
+            assert_eq!(result, target);
+        }
+    }
+    ///
+    /// Without enable input only the changed points are sent
+    #[test]
+    fn test_changed() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_changed");
+        let parent = Name::from("/App/Task");
+        let conf1 = PointConfig::from_yaml(&parent, &serde_yaml::from_str(r#"
+            point Point.Int1:
+                type: 'Int'
+        "#).unwrap());
+        let conf2 = PointConfig::from_yaml(&parent, &serde_yaml::from_str(r#"
+            point Point.Int2:
+                type: 'Int'
+        "#).unwrap());
+        let input1 = init_each(0.to_point(0, "int"), FnConfPointType::Int);
+        let input2 = init_each(0.to_point(0, "int"), FnConfPointType::Int);
+        let point1: FnInOutRef = Rc::new(RefCell::new(Box::new(FnPoint::new("test", 0, conf1, Cot::Inf, input1.clone()))));
+        let point2: FnInOutRef = Rc::new(RefCell::new(Box::new(FnPoint::new("test", 0, conf2, Cot::Inf, input2.clone()))));
+        let (send, recv) = mpsc::channel();
+        let mut fnToMultiQueue = FnToMultiQueue::new("test", vec![point1, point2], None, send);
+        fnToMultiQueue.out();
+        let result: Vec<String> = recv.try_iter().map(|point| point.name()).collect();
+        assert_eq!(result, vec!["/App/Task/Point.Int1", "/App/Task/Point.Int2"]);
+        let test_data = vec![
+            // input1, input2, sent
+            (None, None, vec![]),
+            (Some(1), None, vec![("/App/Task/Point.Int1", 1)]),
+            (None, None, vec![]),
+            (None, Some(2), vec![("/App/Task/Point.Int2", 2)]),
+            (Some(3), Some(4), vec![("/App/Task/Point.Int1", 3), ("/App/Task/Point.Int2", 4)]),
+            (None, None, vec![]),
+        ];
+        for (value1, value2, target) in test_data {
+            if let Some(value1) = value1 {
+                input1.borrow_mut().add(value1.to_point(0, "test"));
+            }
+            if let Some(value2) = value2 {
+                input2.borrow_mut().add(value2.to_point(0, "test"));
+            }
+            fnToMultiQueue.out();
+            let result: Vec<(String, i64)> = recv.try_iter().map(|point| (point.name(), point.as_int().value)).collect();
+            debug!("value1: {:?}, value2: {:?}   |   result: {:?}", value1, value2, result);
+            let target: Vec<(String, i64)> = target.into_iter().map(|(name, value)| (name.to_owned(), value)).collect();
+            assert_eq!(result, target);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+#![allow(non_snake_case)]
+#[cfg(test)]
+
+mod tests {
+    use log::{debug, info};
+    use std::{sync::Once, rc::Rc, cell::RefCell};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::fn_::fn_conf_keywd::FnConfPointType, core_::{point::point_type::{PointType, ToPoint}, types::fn_in_out_ref::FnInOutRef}, services::task::nested_function::{fn_::FnOut, fn_xor::FnXor, fn_input::FnInput}
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each(initial: PointType, type_: FnConfPointType) -> FnInOutRef {
+        Rc::new(RefCell::new(
+            Box::new(
+                FnInput::new("test", initial, type_)
+            )
+        ))
+    }
+    ///
+    ///
+    #[test]
+    fn test_inputs() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        info!("test_inputs");
+        let test_data = vec![
+            (vec![true.to_point(0, "bool"), true.to_point(0, "bool")], false),
+            (vec![true.to_point(0, "bool"), false.to_point(0, "bool")], true),
+            (vec![false.to_point(0, "bool"), false.to_point(0, "bool")], false),
+            (vec![true.to_point(0, "bool"), true.to_point(0, "bool"), true.to_point(0, "bool")], true),
+            (vec![true.to_point(0, "bool"), true.to_point(0, "bool"), false.to_point(0, "bool")], false),
+            (vec![5.to_point(0, "int"), 0.to_point(0, "int")], true),
+            (vec![0.1f32.to_point(0, "real"), 3.to_point(0, "int")], false),
+        ];
+        for (values, target) in test_data {
+            let inputs: Vec<FnInOutRef> = values.iter().map(|value: &PointType| {
+                init_each(value.clone(), FnConfPointType::Any)
+            }).collect();
+            let mut fnXor = FnXor::new("test", inputs);
+            let state = fnXor.out();
+            debug!("values: {:?}   |   state: {:?}", values, state);
+            assert_eq!(state.as_bool().value.0, target);
+        }
+    }
+}
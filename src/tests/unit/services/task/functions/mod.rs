@@ -11,4 +11,58 @@ pub mod fn_var_test;
 
 pub mod fn_add_test;
 
-pub mod fn_point_id_test;
\ No newline at end of file
+pub mod fn_point_id_test;
+
+pub mod fn_sub_test;
+
+pub mod fn_div_test;
+
+pub mod fn_pow_test;
+
+pub mod fn_lt_test;
+
+pub mod fn_ge_test;
+
+pub mod fn_eq_test;
+
+pub mod fn_and_test;
+
+pub mod fn_xor_test;
+
+pub mod fn_to_multi_queue_test;
+
+pub mod fn_pulse_count_test;
+
+pub mod fn_edge_test;
+
+pub mod fn_any_edge_test;
+
+pub mod fn_iec_timer_test;
+
+pub mod fn_latch_test;
+
+pub mod fn_if_test;
+
+pub mod fn_switch_test;
+
+pub mod fn_hysteresis_test;
+
+pub mod fn_statistic_test;
+
+pub mod fn_integral_test;
+
+pub mod fn_scale_test;
+
+pub mod fn_interpolate_test;
+
+pub mod fn_conversion_test;
+
+pub mod fn_format_test;
+
+pub mod fn_expr_test;
+
+pub mod fn_operating_cycle_test;
+
+pub mod fn_fault_recorder_test;
+
+pub mod fn_status_test;
@@ -10,4 +10,6 @@ pub mod task_test;
 
 pub mod task_points_test;
 
-pub mod  task_test_point_any;
\ No newline at end of file
+pub mod  task_test_point_any;
+
+pub mod task_mode_test;
\ No newline at end of file
@@ -0,0 +1,155 @@
+#[cfg(test)]
+
+mod task_mode {
+    use log::{debug, info};
+    use std::{sync::{Arc, Mutex, Once}, thread, time::Duration};
+    use testing::{entities::test_value::Value, stuff::{max_test_duration::TestDuration, wait::WaitTread}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::{point_config::name::Name, task_config::TaskConfig},
+        core_::point::point_type::ToPoint,
+        services::{service::service::Service, services::Services, task::{task::Task, task_nodes::TaskNodes, task_test_producer::TaskTestProducer, task_test_receiver::TaskTestReceiver}},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Builds the TaskNodes from the [conf]
+    fn build(self_id: &str, parent: &Name, conf: &str) -> TaskNodes {
+        let conf = serde_yaml::from_str(conf).unwrap();
+        let conf = TaskConfig::from_yaml(parent, &conf);
+        let services = Arc::new(Mutex::new(Services::new(self_id)));
+        let mut task_nodes = TaskNodes::new(self_id);
+        task_nodes.buildNodes(parent, conf, services);
+        task_nodes
+    }
+    ///
+    /// The time dependent function updated by the evalAll without new input points,
+    /// the eval evaluates the nodes only on the input point
+    #[test]
+    fn eval_all() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_id = "task_mode_test";
+        let parent = Name::new("", self_id);
+        let conf = r#"
+            service Task ModeTask:
+                cycle: 10 ms
+                mode: cyclic
+                in queue in-queue:
+                    max-length: 10000
+                let Lost:
+                    input fn ton:
+                        preset: 100 ms
+                        input: point bool '/path/Heartbeat'
+        "#;
+        // cyclic
+        let mut task_nodes = build(self_id, &parent, conf);
+        task_nodes.add(true.to_point(0, "/path/Heartbeat"));
+        task_nodes.evalAll();
+        let result = task_nodes.getVar("Lost").unwrap().borrow_mut().out().as_bool().value.0;
+        assert!(!result, "\nresult: {:?}\ntarget: {:?}", result, false);
+        thread::sleep(Duration::from_millis(150));
+        task_nodes.evalAll();
+        let result = task_nodes.getVar("Lost").unwrap().borrow_mut().out().as_bool().value.0;
+        assert!(result, "\nresult: {:?}\ntarget: {:?}", result, true);
+        // on event
+        let mut task_nodes = build(self_id, &parent, conf);
+        task_nodes.eval(true.to_point(0, "/path/Heartbeat"));
+        let result = task_nodes.getVar("Lost").unwrap().borrow_mut().out().as_bool().value.0;
+        assert!(!result, "\nresult: {:?}\ntarget: {:?}", result, false);
+        thread::sleep(Duration::from_millis(150));
+        let result = task_nodes.getVar("Lost").unwrap().borrow_mut().out().as_bool().value.0;
+        assert!(!result, "\nresult: {:?}\ntarget: {:?}", result, false);
+    }
+    ///
+    /// Runs the Task in the given [mode], sends single input point,
+    /// returns the values of the exported points
+    fn run(self_id: &str, mode: &str) -> Vec<bool> {
+        let self_name = Name::new("", self_id);
+        let conf = serde_yaml::from_str(&format!(r#"
+            service Task ModeTask:
+                cycle: 10 ms
+                mode: {}
+                in queue in-queue:
+                    max-length: 10000
+                fn ToMultiQueue:
+                    queue: /{}/TaskTestReceiver.in-queue
+                    in1 point Heartbeat.Lost:
+                        type: 'Bool'
+                        input fn ton:
+                            preset: 100 ms
+                            input: point bool '/path/Point.Name'
+        "#, mode, self_id)).unwrap();
+        let conf = TaskConfig::from_yaml(&self_name, &conf);
+        let services = Arc::new(Mutex::new(Services::new(self_id)));
+        let receiver = Arc::new(Mutex::new(TaskTestReceiver::new(self_id, "", "in-queue", usize::MAX)));
+        services.lock().unwrap().insert(receiver.clone());
+        let producer = Arc::new(Mutex::new(TaskTestProducer::new(
+            self_id,
+            &format!("/{}/ModeTask.in-queue", self_id),
+            Duration::ZERO,
+            services.clone(),
+            vec![Value::Bool(true)],
+        )));
+        let task = Arc::new(Mutex::new(Task::new(conf, services.clone())));
+        services.lock().unwrap().insert(task.clone());
+        receiver.lock().unwrap().run().unwrap();
+        let task_handle = task.lock().unwrap().run().unwrap();
+        thread::sleep(Duration::from_millis(100));
+        let producer_handle = producer.lock().unwrap().run().unwrap();
+        producer_handle.wait().unwrap();
+        thread::sleep(Duration::from_millis(400));
+        task.lock().unwrap().exit();
+        task_handle.wait().unwrap();
+        receiver.lock().unwrap().exit();
+        let received = receiver.lock().unwrap().received();
+        let received: Vec<bool> = received.lock().unwrap().iter().map(|point| point.as_bool().value.0).collect();
+        debug!("{} | received: {:?}", self_id, received);
+        received
+    }
+    ///
+    /// The Task in the cyclic mode exports the timer updated without new input points
+    #[test]
+    fn cyclic() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_id = "task_mode_cyclic_test";
+        info!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(5));
+        test_duration.run().unwrap();
+        let received = run(self_id, "cyclic");
+        assert!(received.len() > 1, "\nresult: {:?}\ntarget: {:?}", received.len(), "> 1");
+        let result = *received.last().unwrap();
+        assert!(result, "\nresult: {:?}\ntarget: {:?}", result, true);
+        test_duration.exit();
+    }
+    ///
+    /// The Task in the on-event mode evaluates only on the input point
+    #[test]
+    fn on_event() {
+        DebugSession::init(LogLevel::Info, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_id = "task_mode_on_event_test";
+        info!("{}", self_id);
+        let test_duration = TestDuration::new(self_id, Duration::from_secs(5));
+        test_duration.run().unwrap();
+        let received = run(self_id, "on-event");
+        assert!(received == vec![false], "\nresult: {:?}\ntarget: {:?}", received, vec![false]);
+        test_duration.exit();
+    }
+}
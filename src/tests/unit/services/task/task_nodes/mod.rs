@@ -1 +1,2 @@
-pub mod task_nodes_test;
\ No newline at end of file
+pub mod task_nodes_test;
+pub mod task_nodes_retain_test;
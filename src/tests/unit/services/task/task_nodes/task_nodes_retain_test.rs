@@ -0,0 +1,176 @@
+#[cfg(test)]
+
+mod task_nodes_retain {
+    use log::debug;
+    use std::{env, fs, sync::{Arc, Mutex, Once}};
+    use debugging::session::debug_session::{DebugSession, LogLevel, Backtrace};
+    use crate::{
+        conf::{point_config::name::Name, task_config::TaskConfig},
+        core_::point::point_type::{PointType, ToPoint},
+        services::{services::Services, task::task_nodes::TaskNodes},
+    };
+    ///
+    ///
+    static INIT: Once = Once::new();
+    ///
+    /// once called initialisation
+    fn init_once() {
+        INIT.call_once(|| {
+            // implement your initialisation code to be called only once for current test file
+        })
+    }
+    ///
+    /// returns:
+    ///  - ...
+    fn init_each() -> () {}
+    ///
+    /// Builds the TaskNodes from the [conf], the state of the retained functions restored
+    fn build(self_id: &str, parent: &Name, conf: &str) -> TaskNodes {
+        let conf = serde_yaml::from_str(conf).unwrap();
+        let conf = TaskConfig::from_yaml(parent, &conf);
+        let services = Arc::new(Mutex::new(Services::new(self_id)));
+        let mut task_nodes = TaskNodes::new(self_id);
+        task_nodes.buildNodes(parent, conf, services);
+        task_nodes
+    }
+    ///
+    /// Returns the retained state of the single out of the [input]
+    fn retained(task_nodes: &mut TaskNodes, input: &str) -> PointType {
+        let eval_node = task_nodes.getEvalNode(input).unwrap();
+        let out = eval_node.getOuts().first().unwrap().clone();
+        let state = out.borrow().retained();
+        state.unwrap()
+    }
+    ///
+    /// Testing the state of the functions restored after restart,
+    /// the 'retain' parameter of the function has priority over the Task 'retain'
+    #[test]
+    fn retain() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_id = "task_nodes_retain_test";
+        let parent = Name::new("/test", "TaskNodesRetainTest");
+        let dir = env::current_dir().unwrap().join("assets/retain/test/TaskNodesRetainTest");
+        let _ = fs::remove_dir_all(&dir);
+        let conf = r#"
+            service Task RetainTask:
+                cycle: 10 ms
+                retain: true
+                in queue recv-queue:
+                    max-length: 10000
+                fn pulseCount:
+                    input: point bool '/path/Pulse'
+                fn count:
+                    retain: false
+                    input: point int '/path/Count'
+                fn latchSet:
+                    set: point bool '/path/Set'
+                    reset: point bool '/path/Reset'
+        "#;
+        let mut task_nodes = build(self_id, &parent, conf);
+        for value in [true, false, true] {
+            task_nodes.eval(value.to_point(0, "/path/Pulse"));
+        }
+        for value in [5, 7] {
+            task_nodes.eval(value.to_point(0, "/path/Count"));
+        }
+        let result = retained(&mut task_nodes, "/path/Pulse").as_int().value;
+        assert!(result == 2, "\nresult: {:?}\ntarget: {:?}", result, 2);
+        let result = retained(&mut task_nodes, "/path/Count").as_double().value;
+        assert!(result == 12.0, "\nresult: {:?}\ntarget: {:?}", result, 12.0);
+        task_nodes.eval(true.to_point(0, "/path/Set"));
+        let result = retained(&mut task_nodes, "/path/Set").as_bool().value.0;
+        assert!(result, "\nresult: {:?}\ntarget: {:?}", result, true);
+        task_nodes.storeRetained();
+        // restart
+        let mut task_nodes = build(self_id, &parent, conf);
+        let result = retained(&mut task_nodes, "/path/Pulse").as_int().value;
+        debug!("restored pulseCount: {:?}", result);
+        assert!(result == 2, "\nresult: {:?}\ntarget: {:?}", result, 2);
+        let result = retained(&mut task_nodes, "/path/Count").as_double().value;
+        assert!(result == 0.0, "\nresult: {:?}\ntarget: {:?}", result, 0.0);
+        let result = retained(&mut task_nodes, "/path/Set").as_bool().value.0;
+        assert!(result, "\nresult: {:?}\ntarget: {:?}", result, true);
+        task_nodes.eval(true.to_point(0, "/path/Pulse"));
+        let result = retained(&mut task_nodes, "/path/Pulse").as_int().value;
+        assert!(result == 3, "\nresult: {:?}\ntarget: {:?}", result, 3);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    ///
+    /// Testing the keys of the retained state:
+    /// - the path of the function in the Task, so the identical functions doesn't overwrite each other
+    /// - the 'retain' parameter of the var applied to the function, the var declared with
+    #[test]
+    fn retain_keys() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_id = "task_nodes_retain_test";
+        let parent = Name::new("/test", "TaskNodesRetainKeysTest");
+        let dir = env::current_dir().unwrap().join("assets/retain/test/TaskNodesRetainKeysTest");
+        let _ = fs::remove_dir_all(&dir);
+        let conf = r#"
+            service Task RetainKeysTask:
+                cycle: 10 ms
+                in queue recv-queue:
+                    max-length: 10000
+                let Sum:
+                    input fn add:
+                        input1 fn count:
+                            retain: true
+                            input: point int '/path/Count'
+                        input2 fn count:
+                            retain: true
+                            input: point int '/path/Count'
+                let Starts:
+                    retain: true
+                    input fn count:
+                        input: point int '/path/Starts'
+        "#;
+        let mut task_nodes = build(self_id, &parent, conf);
+        for value in [5, 7] {
+            task_nodes.eval(value.to_point(0, "/path/Count"));
+            task_nodes.eval(value.to_point(0, "/path/Starts"));
+        }
+        task_nodes.storeRetained();
+        for key in ["Sum.input.add.input1.count", "Sum.input.add.input2.count", "Starts.input.count"] {
+            let path = dir.join(format!("{}.json", key));
+            assert!(path.is_file(), "\nresult: {:?} - not stored", path);
+        }
+        // restart
+        let mut task_nodes = build(self_id, &parent, conf);
+        task_nodes.eval(3.to_point(0, "/path/Count"));
+        let result = task_nodes.getVar("Sum").unwrap().borrow_mut().out().as_double().value;
+        assert!(result == 30.0, "\nresult: {:?}\ntarget: {:?}", result, 30.0);
+        task_nodes.eval(3.to_point(0, "/path/Starts"));
+        let result = task_nodes.getVar("Starts").unwrap().borrow_mut().out().as_double().value;
+        assert!(result == 15.0, "\nresult: {:?}\ntarget: {:?}", result, 15.0);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+    ///
+    /// Testing the duplicated key of the retained state rejected
+    #[test]
+    #[should_panic]
+    fn retain_duplicated_key() {
+        DebugSession::init(LogLevel::Debug, Backtrace::Short);
+        init_once();
+        init_each();
+        let self_id = "task_nodes_retain_test";
+        let parent = Name::new("/test", "TaskNodesRetainDuplicatedTest");
+        let conf = r#"
+            service Task RetainDuplicatedTask:
+                cycle: 10 ms
+                in queue recv-queue:
+                    max-length: 10000
+                fn add:
+                    input1 fn count:
+                        retain: Starts
+                        input: point int '/path/Count1'
+                    input2 fn count:
+                        retain: Starts
+                        input: point int '/path/Count2'
+        "#;
+        build(self_id, &parent, conf);
+    }
+}